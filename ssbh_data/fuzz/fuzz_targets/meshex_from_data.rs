@@ -3,5 +3,5 @@ use libfuzzer_sys::fuzz_target;
 use std::convert::TryFrom;
 
 fuzz_target!(|data: ssbh_data::meshex_data::MeshExData| {
-    ssbh_lib::formats::meshex::MeshEx::from(data);
+    let _ = ssbh_lib::formats::meshex::MeshEx::try_from(&data);
 });
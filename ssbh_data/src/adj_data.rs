@@ -15,7 +15,7 @@ for entry in adj.entries {
 # Ok(()) }
 ```
  */
-use crate::mesh_data::{MeshObjectData, VectorData};
+use crate::mesh_data::{position_key, MeshObjectData, VectorData};
 use itertools::Itertools;
 use ssbh_lib::formats::adj::{Adj, AdjEntry};
 use std::convert::TryFrom;
@@ -23,22 +23,25 @@ use std::convert::TryFrom;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// The number of adjacency entries for each vertex used by [AdjEntryData] constructors.
 // The shared vertex is ommitted.
 // For triangle faces this works out to at most 9 adjacent faces.
-const MAX_ADJACENT_VERTICES: usize = 18;
+pub const MAX_ADJACENT_VERTICES: usize = 18;
 
 pub mod error {
     use thiserror::Error;
 
-    /// Errors while creating an [Adj](super::Adj) from [AdjData](super::AdjData).
+    /// Errors while converting adjacency data or calculating vertex adjacency.
     #[derive(Debug, Error)]
     pub enum Error {
         /// An error occurred while writing data to a buffer.
         #[error(transparent)]
         Io(#[from] std::io::Error),
 
+        /// The byte offsets for an entry's adjacency data are past the end of the index buffer.
         #[error(
-            "Byte offset range {}..{} is out of range for a buffer of size {}.",
+            "Byte offset range {}..{} is out of range for an index buffer of {} bytes. \
+            Check that each entry's index buffer offset is within the index buffer.",
             start,
             end,
             buffer_size
@@ -48,6 +51,98 @@ pub mod error {
             end: usize,
             buffer_size: usize,
         },
+
+        /// The byte offset for an entry is smaller than the offset of the previous entry.
+        #[error(
+            "Byte offset {} is less than the previous entry's byte offset {}. \
+            Index buffer offsets must be non decreasing.",
+            offset,
+            previous_offset
+        )]
+        NonIncreasingBufferOffset {
+            offset: usize,
+            previous_offset: usize,
+        },
+
+        /// A vertex index refers to a vertex that does not exist.
+        #[error(
+            "Vertex index {} is out of range for a vertex count of {}.",
+            vertex_index,
+            vertex_count
+        )]
+        VertexIndexOutOfRange {
+            vertex_index: u32,
+            vertex_count: usize,
+        },
+
+        /// The vertex indices do not form complete triangle faces.
+        #[error(
+            "Vertex index count {} is not a multiple of 3. Found {} remaining indices.",
+            vertex_index_count,
+            vertex_index_count % 3
+        )]
+        IncompleteTriangleFaces { vertex_index_count: usize },
+
+        /// A vertex index is too large to be stored in the signed 16 bit adjacency indices.
+        #[error(
+            "Vertex index {} exceeds the maximum adjacency index of {}. \
+            Adj files only support 16 bit vertex indices.",
+            index,
+            i16::MAX
+        )]
+        VertexIndexExceedsI16 { index: u32 },
+
+        /// The mesh object does not have a position attribute with the given name.
+        #[error("Position attribute {:?} not found.", name)]
+        MissingPositionAttribute { name: String },
+
+        /// The mesh object index mapping does not assign a unique new index to each mesh object.
+        #[error(
+            "The mapping is not a permutation of the {} mesh object indices.",
+            mesh_object_count
+        )]
+        InvalidMeshObjectMapping { mesh_object_count: usize },
+
+        /// An entry refers to a mesh object that is not included in the mapping.
+        #[error(
+            "Mesh object index {} is out of range for a mapping of {} mesh objects.",
+            mesh_object_index,
+            mesh_object_count
+        )]
+        MeshObjectIndexOutOfRange {
+            mesh_object_index: usize,
+            mesh_object_count: usize,
+        },
+    }
+
+    /// Errors for invalid mesh object references in [AdjEntryData](super::AdjEntryData).
+    #[derive(Debug, Error, PartialEq, Eq, Clone)]
+    pub enum AdjValidationError {
+        /// The mesh object index does not match any of the mesh objects.
+        #[error(
+            "Entry {} references the mesh object index {}, which is out of range for {} mesh objects.",
+            entry_index,
+            mesh_object_index,
+            mesh_object_count
+        )]
+        MeshObjectIndexOutOfRange {
+            entry_index: usize,
+            mesh_object_index: usize,
+            mesh_object_count: usize,
+        },
+
+        /// The mesh object index is already used by a previous entry.
+        #[error(
+            "Entry {} references the mesh object index {}, which is already used by entry {}.",
+            entry_index,
+            mesh_object_index,
+            previous_entry_index
+        )]
+        DuplicateMeshObjectIndex {
+            entry_index: usize,
+            mesh_object_index: usize,
+            previous_entry_index: usize,
+        },
     }
 }
 
@@ -62,7 +157,7 @@ pub struct AdjData {
 /// Adjacency data for a mesh object.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct AdjEntryData {
     /// The index of the corresponding mesh object.
     pub mesh_object_index: usize,
@@ -73,37 +168,73 @@ pub struct AdjEntryData {
     pub vertex_adjacency: Vec<i16>,
 }
 
+impl AdjData {
+    /// Computes the vertex adjacency information for each of the given [MeshObjectData].
+    /// The [mesh_object_index](struct.AdjEntryData.html#structfield.mesh_object_index) is the index in `objects`.
+    /// Objects without any position attributes are skipped.
+    pub fn from_mesh_objects(objects: &[MeshObjectData]) -> Result<Self, error::Error> {
+        objects
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| !o.positions.is_empty())
+            .map(|(i, o)| AdjEntryData::from_mesh_object(i, o))
+            .collect()
+    }
+}
+
 impl AdjEntryData {
     /// Computes the vertex adjacency information from triangle faces.
-    /// `vertex_indices.len()` should be a multiple of 3.
+    /// `vertex_indices.len()` must be a multiple of 3,
+    /// and all indices must be in range for `vertex_positions`.
     pub fn from_triangle_faces<T: PartialEq>(
         mesh_object_index: usize,
         vertex_positions: &[T],
         vertex_indices: &[u32],
-    ) -> Self {
-        Self {
+    ) -> Result<Self, error::Error> {
+        Ok(Self {
             mesh_object_index,
             vertex_adjacency: triangle_adjacency(
                 vertex_indices,
                 vertex_positions,
                 MAX_ADJACENT_VERTICES,
-            ),
-        }
+            )?,
+        })
     }
 
     /// Computes the vertex adjacency information from triangle faces from the given [MeshObjectData].
     // TODO: Show an example.
-    pub fn from_mesh_object(mesh_object_index: usize, object: &MeshObjectData) -> Self {
+    pub fn from_mesh_object(
+        mesh_object_index: usize,
+        object: &MeshObjectData,
+    ) -> Result<Self, error::Error> {
         object
             .positions
             .first()
             .map(|position| {
                 Self::from_vector_data(mesh_object_index, &position.data, &object.vertex_indices)
             })
-            .unwrap_or(Self {
+            .unwrap_or(Ok(Self {
                 mesh_object_index,
                 vertex_adjacency: Vec::new(),
-            })
+            }))
+    }
+
+    /// Computes the vertex adjacency information from triangle faces from the given [MeshObjectData]
+    /// using the position attribute with the given name.
+    /// This is useful for objects with multiple position attributes.
+    pub fn from_mesh_object_with_positions(
+        mesh_object_index: usize,
+        object: &MeshObjectData,
+        attribute_name: &str,
+    ) -> Result<Self, error::Error> {
+        let position = object
+            .positions
+            .iter()
+            .find(|p| p.name == attribute_name)
+            .ok_or_else(|| error::Error::MissingPositionAttribute {
+                name: attribute_name.to_string(),
+            })?;
+        Self::from_vector_data(mesh_object_index, &position.data, &object.vertex_indices)
     }
 
     /// Computes the vertex adjacency information from triangle faces from the given [VectorData].
@@ -111,20 +242,188 @@ impl AdjEntryData {
         mesh_object_index: usize,
         vertex_positions: &VectorData,
         vertex_indices: &[u32],
-    ) -> Self {
-        Self {
+    ) -> Result<Self, error::Error> {
+        Ok(Self {
             mesh_object_index,
+            // Compare keys to use the same duplicate vertex semantics as position_key.
             vertex_adjacency: match vertex_positions {
                 crate::mesh_data::VectorData::Vector2(v) => {
-                    triangle_adjacency(vertex_indices, v, MAX_ADJACENT_VERTICES)
+                    triangle_adjacency(vertex_indices, &position_keys(v), MAX_ADJACENT_VERTICES)
                 }
                 crate::mesh_data::VectorData::Vector3(v) => {
-                    triangle_adjacency(vertex_indices, v, MAX_ADJACENT_VERTICES)
+                    triangle_adjacency(vertex_indices, &position_keys(v), MAX_ADJACENT_VERTICES)
                 }
                 crate::mesh_data::VectorData::Vector4(v) => {
-                    triangle_adjacency(vertex_indices, v, MAX_ADJACENT_VERTICES)
+                    triangle_adjacency(vertex_indices, &position_keys(v), MAX_ADJACENT_VERTICES)
                 }
-            },
+            }?,
+        })
+    }
+
+    /// Computes the adjacent vertex indices for each vertex from triangle faces
+    /// without truncating or padding each list to [MAX_ADJACENT_VERTICES].
+    /// This is useful for inspecting adjacency before applying the padding used for [Adj] files.
+    /// Use [AdjEntryData::from_vector_data] for the padded representation used for writing.
+    /**
+    ```rust
+    # use ssbh_data::adj_data::AdjEntryData;
+    # use ssbh_data::mesh_data::VectorData;
+    let positions = VectorData::Vector2(vec![[0.0, 0.0], [0.5, 0.0], [1.0, 0.0]]);
+    let adjacency = AdjEntryData::from_vector_data_unpadded(&positions, &[0, 1, 2]).unwrap();
+    assert_eq!(vec![vec![1, 2], vec![2, 0], vec![0, 1]], adjacency);
+    ```
+     */
+    pub fn from_vector_data_unpadded(
+        vertex_positions: &VectorData,
+        vertex_indices: &[u32],
+    ) -> Result<Vec<Vec<i16>>, error::Error> {
+        // Compare keys to use the same duplicate vertex semantics as position_key.
+        match vertex_positions {
+            crate::mesh_data::VectorData::Vector2(v) => {
+                unpadded_triangle_adjacency(vertex_indices, &position_keys(v))
+            }
+            crate::mesh_data::VectorData::Vector3(v) => {
+                unpadded_triangle_adjacency(vertex_indices, &position_keys(v))
+            }
+            crate::mesh_data::VectorData::Vector4(v) => {
+                unpadded_triangle_adjacency(vertex_indices, &position_keys(v))
+            }
+        }
+    }
+
+    /// Returns the number of vertices described by [vertex_adjacency](#structfield.vertex_adjacency)
+    /// assuming `max_adjacent` entries per vertex
+    /// or `None` if the length is not a multiple of `max_adjacent`.
+    /**
+    ```rust
+    # use ssbh_data::adj_data::{AdjEntryData, MAX_ADJACENT_VERTICES};
+
+    let entry = AdjEntryData {
+        mesh_object_index: 0,
+        vertex_adjacency: vec![-1; 3 * MAX_ADJACENT_VERTICES],
+    };
+    assert_eq!(Some(3), entry.vertex_count(MAX_ADJACENT_VERTICES));
+    ```
+    */
+    pub fn vertex_count(&self, max_adjacent: usize) -> Option<usize> {
+        match self.vertex_adjacency.len().checked_rem(max_adjacent)? {
+            0 => Some(self.vertex_adjacency.len() / max_adjacent),
+            _ => None,
+        }
+    }
+}
+
+impl AdjData {
+    /// Checks that each entry references a unique mesh object index less than `mesh_object_count`.
+    /// All invalid entries are returned in order.
+    ///
+    /// Adj files generated separately from the mesh can reference mesh objects that do not exist,
+    /// which may cause crashes in game.
+    /**
+    ```rust
+    # use ssbh_data::adj_data::{AdjData, AdjEntryData};
+    # fn validate(adj: &AdjData) {
+    for error in adj.validate(3).err().unwrap_or_default() {
+        println!("{}", error);
+    }
+    # }
+    ```
+     */
+    pub fn validate(&self, mesh_object_count: usize) -> Result<(), Vec<error::AdjValidationError>> {
+        let mut errors = Vec::new();
+        let mut entry_indices = std::collections::HashMap::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            if entry.mesh_object_index >= mesh_object_count {
+                errors.push(error::AdjValidationError::MeshObjectIndexOutOfRange {
+                    entry_index: i,
+                    mesh_object_index: entry.mesh_object_index,
+                    mesh_object_count,
+                });
+            }
+
+            // Report duplicates relative to the first entry with this index.
+            let previous = *entry_indices.entry(entry.mesh_object_index).or_insert(i);
+            if previous != i {
+                errors.push(error::AdjValidationError::DuplicateMeshObjectIndex {
+                    entry_index: i,
+                    mesh_object_index: entry.mesh_object_index,
+                    previous_entry_index: previous,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Updates each entry's [mesh_object_index](struct.AdjEntryData.html#structfield.mesh_object_index)
+    /// after reordering the mesh objects in the corresponding mesh,
+    /// where `mapping[old_index]` is the new index for the mesh object.
+    /// Entries are sorted by their new mesh object index.
+    /// This should be used with [MeshExData::reorder_groups](crate::meshex_data::MeshExData::reorder_groups)
+    /// to keep all the files for a model consistent.
+    /**
+    ```rust
+    # use ssbh_data::adj_data::{AdjData, AdjEntryData};
+    let mut adj = AdjData {
+        entries: vec![
+            AdjEntryData { mesh_object_index: 0, vertex_adjacency: Vec::new() },
+            AdjEntryData { mesh_object_index: 1, vertex_adjacency: Vec::new() },
+        ],
+    };
+    adj.remap_mesh_object_indices(&[1, 0]).unwrap();
+    assert_eq!(0, adj.entries[0].mesh_object_index);
+    ```
+     */
+    pub fn remap_mesh_object_indices(&mut self, mapping: &[usize]) -> Result<(), error::Error> {
+        if !crate::is_permutation(mapping) {
+            return Err(error::Error::InvalidMeshObjectMapping {
+                mesh_object_count: mapping.len(),
+            });
+        }
+
+        for entry in &self.entries {
+            if entry.mesh_object_index >= mapping.len() {
+                return Err(error::Error::MeshObjectIndexOutOfRange {
+                    mesh_object_index: entry.mesh_object_index,
+                    mesh_object_count: mapping.len(),
+                });
+            }
+        }
+
+        for entry in &mut self.entries {
+            entry.mesh_object_index = mapping[entry.mesh_object_index];
+        }
+        self.entries.sort_by_key(|e| e.mesh_object_index);
+        Ok(())
+    }
+}
+
+impl IntoIterator for AdjData {
+    type Item = AdjEntryData;
+    type IntoIter = std::vec::IntoIter<AdjEntryData>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a AdjData {
+    type Item = &'a AdjEntryData;
+    type IntoIter = std::slice::Iter<'a, AdjEntryData>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl FromIterator<AdjEntryData> for AdjData {
+    fn from_iter<I: IntoIterator<Item = AdjEntryData>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
         }
     }
 }
@@ -133,17 +432,15 @@ impl TryFrom<&AdjData> for Adj {
     type Error = std::io::Error;
 
     fn try_from(data: &AdjData) -> Result<Self, Self::Error> {
+        let offsets = index_buffer_offsets(data.entries.iter().map(|e| e.vertex_adjacency.len()))?;
         Ok(Adj {
             entries: data
                 .entries
                 .iter()
-                .scan(0, |offset, e| {
-                    let entry = AdjEntry {
-                        mesh_object_index: e.mesh_object_index as u32,
-                        index_buffer_offset: *offset as u32,
-                    };
-                    *offset += e.vertex_adjacency.len() * std::mem::size_of::<i16>();
-                    Some(entry)
+                .zip(offsets)
+                .map(|(e, offset)| AdjEntry {
+                    mesh_object_index: e.mesh_object_index as u32,
+                    index_buffer_offset: offset,
                 })
                 .collect(),
             index_buffer: data
@@ -155,6 +452,36 @@ impl TryFrom<&AdjData> for Adj {
     }
 }
 
+// Calculate the byte offset of each entry's adjacency data in the combined index buffer.
+fn index_buffer_offsets<I: Iterator<Item = usize>>(
+    adjacency_lengths: I,
+) -> Result<Vec<u32>, std::io::Error> {
+    let mut offsets = Vec::new();
+    let mut offset = 0usize;
+    for length in adjacency_lengths {
+        // Offsets are stored as u32, so check for overflow instead of truncating.
+        let entry_offset = u32::try_from(offset).map_err(|_| offset_overflow_error(offset))?;
+        offsets.push(entry_offset);
+
+        offset = length
+            .checked_mul(std::mem::size_of::<i16>())
+            .and_then(|size| offset.checked_add(size))
+            .ok_or_else(|| offset_overflow_error(offset))?;
+    }
+    Ok(offsets)
+}
+
+fn offset_overflow_error(offset: usize) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!(
+            "Index buffer offset {} exceeds the maximum offset of {}.",
+            offset,
+            u32::MAX
+        ),
+    )
+}
+
 impl TryFrom<AdjData> for Adj {
     type Error = std::io::Error;
 
@@ -177,7 +504,13 @@ impl TryFrom<&Adj> for AdjData {
             entries.push(AdjEntryData {
                 mesh_object_index: entry.mesh_object_index as usize,
                 vertex_adjacency: if let Some(next_entry) = entries_iter.peek() {
-                    // TODO: Handle edge cases like start > end.
+                    if next_entry.index_buffer_offset < entry.index_buffer_offset {
+                        return Err(error::Error::NonIncreasingBufferOffset {
+                            offset: next_entry.index_buffer_offset as usize,
+                            previous_offset: entry.index_buffer_offset as usize,
+                        });
+                    }
+
                     let start = offset_to_index(entry.index_buffer_offset);
                     let end = offset_to_index(next_entry.index_buffer_offset);
                     adj.index_buffer
@@ -214,15 +547,55 @@ impl TryFrom<Adj> for AdjData {
     }
 }
 
+fn position_keys<const N: usize>(positions: &[[f32; N]]) -> Vec<[u32; N]> {
+    positions.iter().copied().map(position_key).collect()
+}
+
 fn triangle_adjacency<T: PartialEq>(
     vertex_indices: &[u32],
     vertex_positions: &[T],
     padding_size: usize,
-) -> Vec<i16> {
+) -> Result<Vec<i16>, error::Error> {
+    // Smash Ultimate adjb files limit the number of adjacent vertices per vertex.
+    // The special value of -1 is used for unused entries.
+    // TODO: Is a fixed count per vertex required?
+    Ok(
+        unpadded_triangle_adjacency(vertex_indices, vertex_positions)?
+            .into_iter()
+            .flat_map(|mut a| {
+                a.resize(padding_size, -1);
+                a
+            })
+            .collect(),
+    )
+}
+
+fn unpadded_triangle_adjacency<T: PartialEq>(
+    vertex_indices: &[u32],
+    vertex_positions: &[T],
+) -> Result<Vec<Vec<i16>>, error::Error> {
     // TODO: It should be doable to do this in fewer allocations.
     // TODO: This could be done with tinyvec or maintaining a separate count list.
-    // TODO: Return an error for out of range vertices?
-    // TODO: Should there be an error if there is a remainder?
+    if !vertex_indices.chunks_exact(3).remainder().is_empty() {
+        return Err(error::Error::IncompleteTriangleFaces {
+            vertex_index_count: vertex_indices.len(),
+        });
+    }
+
+    if let Some(vertex_index) = vertex_indices
+        .iter()
+        .find(|i| **i as usize >= vertex_positions.len())
+    {
+        return Err(error::Error::VertexIndexOutOfRange {
+            vertex_index: *vertex_index,
+            vertex_count: vertex_positions.len(),
+        });
+    }
+
+    // Adjacency indices are signed 16 bit, so larger indices would be truncated.
+    if let Some(index) = vertex_indices.iter().find(|i| **i > i16::MAX as u32) {
+        return Err(error::Error::VertexIndexExceedsI16 { index: *index });
+    }
 
     // Find the vertex indices from the all adjacent faces for each vertex.
     // We'll assume each face is a triangle with 3 distinct vertex indices.
@@ -262,23 +635,68 @@ fn triangle_adjacency<T: PartialEq>(
         }
     }
 
-    // Smash Ultimate adjb files limit the number of adjacent vertices per vertex.
-    // The special value of -1 is used for unused entries.
-    // TODO: Is a fixed count per vertex required?
-    adjacent_vertices_with_seams
-        .into_iter()
-        .flat_map(|mut a| {
-            a.resize(padding_size, -1);
-            a
-        })
-        .collect()
+    Ok(adjacent_vertices_with_seams)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mesh_data::AttributeData;
     use ssbh_lib::formats::adj::AdjEntry;
 
+    #[test]
+    fn adj_entries_hash_set_duplicates() {
+        let entry = |mesh_object_index| AdjEntryData {
+            mesh_object_index,
+            vertex_adjacency: vec![0, 1, -1],
+        };
+        let entries: std::collections::HashSet<_> =
+            [entry(0), entry(1), entry(0)].into_iter().collect();
+        assert_eq!(2, entries.len());
+    }
+
+    #[test]
+    fn remap_mesh_object_indices_swap() {
+        let entry = |mesh_object_index, vertex_adjacency| AdjEntryData {
+            mesh_object_index,
+            vertex_adjacency,
+        };
+        let mut adj = AdjData {
+            entries: vec![entry(0, vec![1, -1]), entry(2, vec![2, -1])],
+        };
+
+        adj.remap_mesh_object_indices(&[2, 1, 0]).unwrap();
+        assert_eq!(
+            vec![entry(0, vec![2, -1]), entry(2, vec![1, -1])],
+            adj.entries
+        );
+    }
+
+    #[test]
+    fn remap_mesh_object_indices_invalid() {
+        let mut adj = AdjData {
+            entries: vec![AdjEntryData {
+                mesh_object_index: 2,
+                vertex_adjacency: Vec::new(),
+            }],
+        };
+
+        assert!(matches!(
+            adj.remap_mesh_object_indices(&[0, 0]),
+            Err(error::Error::InvalidMeshObjectMapping {
+                mesh_object_count: 2
+            })
+        ));
+        assert!(matches!(
+            adj.remap_mesh_object_indices(&[1, 0]),
+            Err(error::Error::MeshObjectIndexOutOfRange {
+                mesh_object_index: 2,
+                mesh_object_count: 2
+            })
+        ));
+        assert_eq!(2, adj.entries[0].mesh_object_index);
+    }
+
     #[test]
     fn convert_adj_empty() {
         let adj = Adj {
@@ -402,36 +820,334 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn create_adj_data_decreasing_offsets() {
+        let adj = Adj {
+            entries: vec![
+                AdjEntry {
+                    mesh_object_index: 0,
+                    index_buffer_offset: 4,
+                },
+                AdjEntry {
+                    mesh_object_index: 1,
+                    index_buffer_offset: 2,
+                },
+            ],
+            index_buffer: vec![2, 3, 4, 5],
+        };
+        let result = AdjData::try_from(&adj);
+        assert!(matches!(
+            result,
+            Err(error::Error::NonIncreasingBufferOffset {
+                offset: 2,
+                previous_offset: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn error_display() {
+        let message = error::Error::BufferOffsetOutOfRange {
+            start: 12,
+            end: 24,
+            buffer_size: 8,
+        }
+        .to_string();
+        assert!(message.contains("12..24"));
+        assert!(message.contains('8'));
+
+        let message = error::Error::NonIncreasingBufferOffset {
+            offset: 2,
+            previous_offset: 4,
+        }
+        .to_string();
+        assert!(message.contains('2'));
+        assert!(message.contains('4'));
+
+        let message = error::Error::VertexIndexOutOfRange {
+            vertex_index: 37,
+            vertex_count: 16,
+        }
+        .to_string();
+        assert!(message.contains("37"));
+        assert!(message.contains("16"));
+
+        let message = error::Error::IncompleteTriangleFaces {
+            vertex_index_count: 7,
+        }
+        .to_string();
+        assert!(message.contains('7'));
+        assert!(message.contains('1'));
+
+        let message = error::Error::VertexIndexExceedsI16 { index: 40000 }.to_string();
+        assert!(message.contains("40000"));
+        assert!(message.contains("32767"));
+
+        let message = error::Error::from(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "write failed",
+        ))
+        .to_string();
+        assert!(message.contains("write failed"));
+    }
+
+    fn triangle_object(positions: Vec<[f32; 3]>) -> MeshObjectData {
+        MeshObjectData {
+            vertex_indices: vec![0, 1, 2],
+            positions: vec![AttributeData {
+                name: "Position0".to_string(),
+                data: VectorData::Vector3(positions),
+            }],
+            ..MeshObjectData::default()
+        }
+    }
+
+    #[test]
+    fn index_buffer_offsets_multiple_entries() {
+        assert_eq!(
+            vec![0, 6, 6, 10],
+            index_buffer_offsets([3, 0, 2, 5].into_iter()).unwrap()
+        );
+    }
+
+    #[test]
+    fn index_buffer_offsets_max_offset() {
+        // The final entry's offset fits in u32.
+        let length = u32::MAX as usize / 2;
+        assert_eq!(
+            vec![0, u32::MAX - 1],
+            index_buffer_offsets([length, 1].into_iter()).unwrap()
+        );
+    }
+
+    #[test]
+    fn index_buffer_offsets_overflow() {
+        // The second entry's offset is too large for u32.
+        let length = u32::MAX as usize / 2 + 1;
+        let result = index_buffer_offsets([length, 1].into_iter());
+        assert_eq!(std::io::ErrorKind::InvalidData, result.unwrap_err().kind());
+    }
+
+    #[test]
+    fn index_buffer_offsets_overflow_usize() {
+        let result = index_buffer_offsets([usize::MAX, 1].into_iter());
+        assert_eq!(std::io::ErrorKind::InvalidData, result.unwrap_err().kind());
+    }
+
+    #[test]
+    fn vertex_count_padded() {
+        let entry = AdjEntryData {
+            mesh_object_index: 0,
+            vertex_adjacency: vec![0, 1, -1, -1, 2, 3, 4, 5],
+        };
+        assert_eq!(Some(2), entry.vertex_count(4));
+        assert_eq!(Some(1), entry.vertex_count(8));
+    }
+
+    #[test]
+    fn vertex_count_not_padded() {
+        let entry = AdjEntryData {
+            mesh_object_index: 0,
+            vertex_adjacency: vec![0, 1, 2],
+        };
+        assert_eq!(None, entry.vertex_count(2));
+        assert_eq!(None, entry.vertex_count(0));
+    }
+
+    #[test]
+    fn vertex_count_empty() {
+        let entry = AdjEntryData {
+            mesh_object_index: 0,
+            vertex_adjacency: Vec::new(),
+        };
+        assert_eq!(Some(0), entry.vertex_count(MAX_ADJACENT_VERTICES));
+    }
+
+    #[test]
+    fn adj_data_from_mesh_objects() {
+        let objects = vec![
+            triangle_object(vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+            triangle_object(vec![[0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]),
+        ];
+
+        let data = AdjData::from_mesh_objects(&objects).unwrap();
+        assert_eq!(
+            vec![
+                AdjEntryData::from_mesh_object(0, &objects[0]).unwrap(),
+                AdjEntryData::from_mesh_object(1, &objects[1]).unwrap()
+            ],
+            data.entries
+        );
+        assert_eq!(
+            3 * MAX_ADJACENT_VERTICES,
+            data.entries[1].vertex_adjacency.len()
+        );
+    }
+
+    #[test]
+    fn adj_data_from_mesh_objects_skip_empty_positions() {
+        let objects = vec![
+            MeshObjectData::default(),
+            triangle_object(vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+        ];
+
+        let data = AdjData::from_mesh_objects(&objects).unwrap();
+        assert_eq!(1, data.entries.len());
+        assert_eq!(1, data.entries[0].mesh_object_index);
+    }
+
+    #[test]
+    fn adj_entry_from_mesh_object_with_positions() {
+        // The first attribute has two overlapping vertices.
+        let object = MeshObjectData {
+            vertex_indices: vec![0, 1, 2],
+            positions: vec![
+                AttributeData {
+                    name: "Position0".to_string(),
+                    data: VectorData::Vector3(vec![[0.0; 3], [0.0; 3], [1.0; 3]]),
+                },
+                AttributeData {
+                    name: "Position1".to_string(),
+                    data: VectorData::Vector3(vec![[0.0; 3], [1.0; 3], [2.0; 3]]),
+                },
+            ],
+            ..MeshObjectData::default()
+        };
+
+        let entry = AdjEntryData::from_mesh_object_with_positions(3, &object, "Position1").unwrap();
+        assert_eq!(
+            AdjEntryData::from_vector_data(3, &object.positions[1].data, &object.vertex_indices)
+                .unwrap(),
+            entry
+        );
+        assert_ne!(AdjEntryData::from_mesh_object(3, &object).unwrap(), entry);
+    }
+
+    #[test]
+    fn adj_entry_from_mesh_object_with_missing_positions() {
+        let result = AdjEntryData::from_mesh_object_with_positions(
+            0,
+            &triangle_object(vec![[0.0; 3]; 3]),
+            "Position1",
+        );
+        assert!(matches!(
+            result,
+            Err(error::Error::MissingPositionAttribute { name }) if name == "Position1"
+        ));
+    }
+
+    #[test]
+    fn adj_data_into_iter_collect() {
+        let data = AdjData {
+            entries: vec![
+                AdjEntryData {
+                    mesh_object_index: 0,
+                    vertex_adjacency: vec![1, 2],
+                },
+                AdjEntryData {
+                    mesh_object_index: 1,
+                    vertex_adjacency: vec![3, 4],
+                },
+                AdjEntryData {
+                    mesh_object_index: 2,
+                    vertex_adjacency: vec![5, 6],
+                },
+            ],
+        };
+
+        assert_eq!(
+            vec![0, 1, 2],
+            (&data)
+                .into_iter()
+                .map(|e| e.mesh_object_index)
+                .collect::<Vec<_>>()
+        );
+
+        let filtered: AdjData = data
+            .into_iter()
+            .filter(|e| e.mesh_object_index != 1)
+            .collect();
+        assert_eq!(
+            AdjData {
+                entries: vec![
+                    AdjEntryData {
+                        mesh_object_index: 0,
+                        vertex_adjacency: vec![1, 2],
+                    },
+                    AdjEntryData {
+                        mesh_object_index: 2,
+                        vertex_adjacency: vec![5, 6],
+                    },
+                ],
+            },
+            filtered
+        );
+    }
+
     fn flatten<T, const N: usize>(x: Vec<[T; N]>) -> Vec<T> {
         // Allow for visually grouping indices.
         x.into_iter().flatten().collect()
     }
 
+    #[test]
+    fn from_vector_data_unpadded_fully_connected_vertex() {
+        // Vertex 0 is shared by 9 triangles in a fan, so all 18 entries are used.
+        let positions: Vec<_> = (0..10).map(|i| [i as f32, 0.0, 0.0]).collect();
+        let vertex_indices: Vec<_> = (1..10).flat_map(|i| [0, i, i % 9 + 1]).collect();
+
+        let adjacency = AdjEntryData::from_vector_data_unpadded(
+            &VectorData::Vector3(positions),
+            &vertex_indices,
+        )
+        .unwrap();
+
+        assert_eq!(10, adjacency.len());
+        assert_eq!(MAX_ADJACENT_VERTICES, adjacency[0].len());
+        assert!(!adjacency[0].contains(&-1));
+        assert_eq!(4, adjacency[1].len());
+    }
+
     #[test]
     fn triangle_adjacency_empty() {
-        assert!(triangle_adjacency(&[], &[0.0; 0], MAX_ADJACENT_VERTICES).is_empty());
+        assert!(triangle_adjacency(&[], &[0.0; 0], MAX_ADJACENT_VERTICES)
+            .unwrap()
+            .is_empty());
     }
 
     #[test]
     fn triangle_adjacency_single_vertex_none_adjacent() {
         assert_eq!(
             vec![-1; 18],
-            triangle_adjacency(&[], &[0.0], MAX_ADJACENT_VERTICES)
+            triangle_adjacency(&[], &[0.0], MAX_ADJACENT_VERTICES).unwrap()
         );
     }
 
     #[test]
-    #[ignore]
     fn triangle_adjacency_single_face_single_vertex() {
-        // TODO: Should this be an error?
-        triangle_adjacency(&[0, 1, 2], &[0.0], 4);
+        assert!(matches!(
+            triangle_adjacency(&[0, 1, 2], &[0.0], 4),
+            Err(error::Error::VertexIndexOutOfRange {
+                vertex_index: 1,
+                vertex_count: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn triangle_adjacency_remainder() {
+        assert!(matches!(
+            triangle_adjacency(&[0, 1, 2, 0], &[0.0, 0.5, 1.0], 4),
+            Err(error::Error::IncompleteTriangleFaces {
+                vertex_index_count: 4
+            })
+        ));
     }
 
     #[test]
     fn triangle_adjacency_single_face() {
         assert_eq!(
             flatten(vec![[1, 2, -1], [2, 0, -1], [0, 1, -1]]),
-            triangle_adjacency(&[0, 1, 2], &[0.0, 0.5, 1.0], 3)
+            triangle_adjacency(&[0, 1, 2], &[0.0, 0.5, 1.0], 3).unwrap()
         );
     }
 
@@ -443,7 +1159,7 @@ mod tests {
                 [2, 0, 2, 0, 0, 2, -1],
                 [0, 1, 0, 1, 1, 0, -1]
             ]),
-            triangle_adjacency(&[0, 1, 2, 2, 0, 1, 1, 0, 2], &[0.0, 0.5, 1.0], 7)
+            triangle_adjacency(&[0, 1, 2, 2, 0, 1, 1, 0, 2], &[0.0, 0.5, 1.0], 7).unwrap()
         );
     }
 
@@ -460,7 +1176,91 @@ mod tests {
                 [5, 3, -1, -1, -1],
                 [3, 4, -1, -1, -1],
             ]),
-            triangle_adjacency(&[0, 1, 2, 3, 4, 5], &[0.0, 0.5, 1.0, 0.0, 1.5, 2.0], 5)
+            triangle_adjacency(&[0, 1, 2, 3, 4, 5], &[0.0, 0.5, 1.0, 0.0, 1.5, 2.0], 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn adj_entry_from_vector_data_split_vertex_signed_zero() {
+        // Vertex 0 and vertex 3 are the same despite the different signs for zero.
+        let entry = AdjEntryData::from_vector_data(
+            0,
+            &VectorData::Vector2(vec![
+                [0.0, 0.0],
+                [0.5, 0.0],
+                [1.0, 0.0],
+                [-0.0, -0.0],
+                [1.5, 0.0],
+                [2.0, 0.0],
+            ]),
+            &[0, 1, 2, 3, 4, 5],
+        )
+        .unwrap();
+        assert_eq!(&[1, 2, 4, 5], &entry.vertex_adjacency[..4]);
+        assert_eq!(
+            &[1, 2, 4, 5],
+            &entry.vertex_adjacency[3 * MAX_ADJACENT_VERTICES..3 * MAX_ADJACENT_VERTICES + 4]
+        );
+    }
+
+    #[test]
+    fn triangle_adjacency_index_exceeds_i16() {
+        let result = triangle_adjacency(&[0, 1, 32768], &[0.0f32; 32769], 18);
+        assert!(matches!(
+            result,
+            Err(error::Error::VertexIndexExceedsI16 { index: 32768 })
+        ));
+    }
+
+    #[test]
+    fn validate_adj_data() {
+        let adj = AdjData {
+            entries: vec![
+                AdjEntryData {
+                    mesh_object_index: 1,
+                    vertex_adjacency: Vec::new(),
+                },
+                AdjEntryData {
+                    mesh_object_index: 0,
+                    vertex_adjacency: Vec::new(),
+                },
+            ],
+        };
+        assert_eq!(Ok(()), adj.validate(2));
+    }
+
+    #[test]
+    fn validate_adj_data_out_of_range_and_duplicate() {
+        let adj = AdjData {
+            entries: vec![
+                AdjEntryData {
+                    mesh_object_index: 0,
+                    vertex_adjacency: Vec::new(),
+                },
+                AdjEntryData {
+                    mesh_object_index: 2,
+                    vertex_adjacency: Vec::new(),
+                },
+                AdjEntryData {
+                    mesh_object_index: 0,
+                    vertex_adjacency: Vec::new(),
+                },
+            ],
+        };
+        assert_eq!(
+            Err(vec![
+                error::AdjValidationError::MeshObjectIndexOutOfRange {
+                    entry_index: 1,
+                    mesh_object_index: 2,
+                    mesh_object_count: 2
+                },
+                error::AdjValidationError::DuplicateMeshObjectIndex {
+                    entry_index: 2,
+                    mesh_object_index: 0,
+                    previous_entry_index: 0
+                }
+            ]),
+            adj.validate(2)
         );
     }
 }
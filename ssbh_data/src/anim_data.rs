@@ -26,28 +26,37 @@ for group in anim.groups {
 //! When converting to [Anim], compression is enabled for a track if compression would save space.
 //! This may produce differences with the original due to compression differences.
 //! These errors are small in practice but may cause gameplay differences such as online desyncs.
+//! Use [AnimData::from_anim_preserving_compression] to write unmodified tracks using their original data.
 use binrw::io::{Cursor, Seek, Write};
-use binrw::{BinRead, BinReaderExt};
+use binrw::{BinRead, BinReaderExt, BinWrite};
+use itertools::Itertools;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-pub use ssbh_lib::formats::anim::GroupType;
+pub use ssbh_lib::formats::anim::{CompressionType, GroupType, TrackTypeV2};
 use ssbh_lib::{
     formats::anim::{
-        Anim, CompressionType, Group, Node, TrackFlags, TrackTypeV2, TrackV2,
-        TransformFlags as AnimTransformFlags, UnkData,
+        Anim, Group, Node, TrackFlags, TrackV2, TransformFlags as AnimTransformFlags, UnkData,
     },
-    SsbhArray, Vector3, Vector4, Version,
+    Matrix4x4, SsbhArray, SsbhString, Vector3, Vector4, Version,
 };
 use ssbh_write::SsbhWrite;
 use std::{
+    collections::HashMap,
     convert::{TryFrom, TryInto},
     error::Error,
 };
 
 mod buffers;
 use buffers::*;
+pub use buffers::{
+    read_compression_default, read_track_values_with_endian, read_uniform_scale, roundtrip_bytes,
+    track_value_frames, write_compressed_floats, write_compressed_vector4s,
+};
 mod bitutils;
 mod compression;
+#[cfg(feature = "half_compression")]
+pub use buffers::{read_compressed_half_floats, write_compressed_half_floats};
+pub use compression::{CompressedTrackData, CompressionFlags, F32Compression, Vector4Compression};
 
 /// Data associated with an [Anim] file.
 /// Supported versions are 2.0 and 2.1.
@@ -69,6 +78,186 @@ pub struct AnimData {
     pub groups: Vec<GroupData>,
 }
 
+impl AnimData {
+    /// The `(major_version, minor_version)` pairs supported for reading and writing.
+    pub fn supported_versions() -> &'static [(u16, u16)] {
+        &[(2, 0), (2, 1)]
+    }
+
+    /// Samples every [TrackValues::Transform] track at `frame` by node name.
+    /// Tracks for other value types are ignored.
+    ///
+    /// Tracks with a single frame are constant and use their only value for every frame.
+    /// Frames past the end of a track use the track's final value.
+    /// Empty tracks are skipped.
+    /// If multiple transform tracks share a node name, the last track is used.
+    /**
+    ```rust no_run
+    # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    use ssbh_data::prelude::*;
+
+    let anim = AnimData::from_file("a00wait1.nuanmb")?;
+    for (name, transform) in anim.pose_at_frame(0) {
+        println!("{}: {:?}", name, transform.translation);
+    }
+    # Ok(()) }
+    ```
+     */
+    pub fn pose_at_frame(&self, frame: usize) -> HashMap<String, Transform> {
+        self.groups
+            .iter()
+            .flat_map(|g| g.nodes.iter())
+            .flat_map(|n| n.tracks.iter().map(move |t| (n, t)))
+            .filter_map(|(node, track)| match &track.values {
+                TrackValues::Transform(values) => {
+                    let value = values.get(frame).or_else(|| values.last())?;
+                    Some((node.name.clone(), *value))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Samples every track at each frame from `0` to [final_frame_index](#structfield.final_frame_index)
+    /// and returns the column names and the rows for each frame.
+    ///
+    /// Columns are named `node.track` for single values or `node.track.component` for vectors and transforms
+    /// like `Hip.Transform.translation.x`.
+    /// [TrackValues::Boolean] values are converted to `0.0` or `1.0`.
+    /// Frames past the end of a track use the track's final value like [AnimData::pose_at_frame].
+    /// Empty tracks are skipped.
+    /**
+    ```rust no_run
+    # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    use ssbh_data::prelude::*;
+
+    let anim = AnimData::from_file("a00wait1.nuanmb")?;
+    let (columns, rows) = anim.to_table();
+    println!("{}", columns.join(","));
+    for row in rows {
+        println!("{}", row.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(","));
+    }
+    # Ok(()) }
+    ```
+     */
+    pub fn to_table(&self) -> (Vec<String>, Vec<Vec<f32>>) {
+        let tracks: Vec<_> = self
+            .groups
+            .iter()
+            .flat_map(|g| g.nodes.iter())
+            .flat_map(|n| n.tracks.iter().map(move |t| (n, t)))
+            .filter(|(_, t)| !t.values.is_empty())
+            .collect();
+
+        let columns = tracks
+            .iter()
+            .flat_map(|(node, track)| {
+                let names = track.values.component_names();
+                names.iter().map(move |component| match component {
+                    Some(component) => format!("{}.{}.{}", node.name, track.name, component),
+                    None => format!("{}.{}", node.name, track.name),
+                })
+            })
+            .collect();
+
+        let frame_count = self.final_frame_index.max(0.0) as usize + 1;
+        let rows = (0..frame_count)
+            .map(|frame| {
+                tracks
+                    .iter()
+                    .flat_map(|(_, track)| track.values.components_at_frame(frame))
+                    .collect()
+            })
+            .collect();
+
+        (columns, rows)
+    }
+
+    /// Adds a track with default scale options and transform flags
+    /// to the node with `node_name` in the group with `group_type`.
+    /// The group and node are created if they don't already exist.
+    /// Returns the new track to allow editing the remaining fields.
+    ///
+    /// The [final_frame_index](#structfield.final_frame_index) is increased if needed to include all the frames in `values`.
+    /// The [CompressionType] for each track is chosen automatically when writing.
+    /// See [TrackValues::choose_compression].
+    /// # Examples
+    /**
+    ```rust no_run
+    # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    use ssbh_data::prelude::*;
+    use ssbh_data::anim_data::{GroupType, TrackValues, Transform};
+
+    let mut anim = AnimData {
+        major_version: 2,
+        minor_version: 0,
+        final_frame_index: 0.0,
+        groups: Vec::new(),
+    };
+    anim.add_track(
+        GroupType::Transform,
+        "Hip",
+        "Transform",
+        TrackValues::Transform(vec![Transform::IDENTITY; 10]),
+    );
+    anim.add_track(
+        GroupType::Visibility,
+        "Body",
+        "Visibility",
+        TrackValues::Boolean(vec![true]),
+    );
+    assert_eq!(9.0, anim.final_frame_index);
+
+    anim.write_to_file("anim.nuanmb")?;
+    # Ok(()) }
+    ```
+     */
+    pub fn add_track(
+        &mut self,
+        group_type: GroupType,
+        node_name: &str,
+        track_name: &str,
+        values: TrackValues,
+    ) -> &mut TrackData {
+        if let Some(final_frame) = values.len().checked_sub(1) {
+            self.final_frame_index = self.final_frame_index.max(final_frame as f32);
+        }
+
+        let group_index = match self.groups.iter().position(|g| g.group_type == group_type) {
+            Some(i) => i,
+            None => {
+                self.groups.push(GroupData {
+                    group_type,
+                    nodes: Vec::new(),
+                });
+                self.groups.len() - 1
+            }
+        };
+        let nodes = &mut self.groups[group_index].nodes;
+
+        let node_index = match nodes.iter().position(|n| n.name == node_name) {
+            Some(i) => i,
+            None => {
+                nodes.push(NodeData {
+                    name: node_name.to_string(),
+                    tracks: Vec::new(),
+                });
+                nodes.len() - 1
+            }
+        };
+        let tracks = &mut nodes[node_index].tracks;
+
+        tracks.push(TrackData {
+            name: track_name.to_string(),
+            scale_options: ScaleOptions::default(),
+            transform_flags: TransformFlags::default(),
+            values,
+            metadata: None,
+        });
+        tracks.last_mut().unwrap()
+    }
+}
+
 // TODO: Test these conversions.
 impl TryFrom<Anim> for AnimData {
     type Error = Box<dyn Error>;
@@ -82,26 +271,55 @@ impl TryFrom<&Anim> for AnimData {
     type Error = Box<dyn Error>;
 
     fn try_from(anim: &Anim) -> Result<Self, Self::Error> {
-        let (major_version, minor_version) = anim.major_minor_version();
-        Ok(Self {
-            major_version,
-            minor_version,
-            final_frame_index: match &anim {
-                Anim::V12 {
-                    final_frame_index, ..
-                } => *final_frame_index,
-                Anim::V20 {
-                    final_frame_index, ..
-                } => *final_frame_index,
-                Anim::V21 {
-                    final_frame_index, ..
-                } => *final_frame_index,
-            },
-            groups: read_anim_groups(anim)?,
-        })
+        Ok(create_anim_data(anim, false)?)
+    }
+}
+
+impl AnimData {
+    /// Converts `anim` while also storing the original compression for each track in
+    /// [metadata](struct.TrackData.html#structfield.metadata).
+    ///
+    /// Tracks with unmodified values and scale options are written using the original data.
+    /// Modified tracks reuse the original [CompressionType] if it still supports the new values
+    /// but recalculate the remaining compression settings.
+    /**
+    ```rust no_run
+    # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    use ssbh_data::prelude::*;
+    use ssbh_lib::formats::anim::Anim;
+
+    let anim = Anim::from_file("model.nuanmb")?;
+    let mut data = AnimData::from_anim_preserving_compression(&anim)?;
+    data.final_frame_index += 1.0;
+    data.write_to_file("model_edited.nuanmb")?;
+    # Ok(()) }
+    ```
+     */
+    pub fn from_anim_preserving_compression(anim: &Anim) -> Result<Self, error::Error> {
+        create_anim_data(anim, true)
     }
 }
 
+fn create_anim_data(anim: &Anim, preserve_compression: bool) -> Result<AnimData, error::Error> {
+    let (major_version, minor_version) = anim.major_minor_version();
+    Ok(AnimData {
+        major_version,
+        minor_version,
+        final_frame_index: match &anim {
+            Anim::V12 {
+                final_frame_index, ..
+            } => *final_frame_index,
+            Anim::V20 {
+                final_frame_index, ..
+            } => *final_frame_index,
+            Anim::V21 {
+                final_frame_index, ..
+            } => *final_frame_index,
+        },
+        groups: read_anim_groups(anim, preserve_compression)?,
+    })
+}
+
 impl TryFrom<AnimData> for Anim {
     type Error = error::Error;
 
@@ -167,6 +385,23 @@ pub mod error {
         #[error(transparent)]
         BitError(#[from] bitutils::BitReadError),
 
+        /// An error occurred while decompressing a frame of a compressed track.
+        #[error(
+            "Failed to decompress {} of frame {}.",
+            if field.is_empty() { "value" } else { field },
+            frame
+        )]
+        DecompressionFailed {
+            frame: usize,
+            field: String,
+            #[source]
+            source: bitutils::BitReadError,
+        },
+
+        /// A string contains null bytes and can't be converted to a null terminated string.
+        #[error(transparent)]
+        String(#[from] ssbh_lib::StringError),
+
         #[error(
             "Compressed header bits per entry of {} does not match expected value of {}.",
             actual,
@@ -200,6 +435,37 @@ pub mod error {
         /// An error occurred while reading the compressed header for version 2.0 or later.
         #[error("The track data compression header is malformed and cannot be read.")]
         MalformedCompressionHeader,
+
+        /// The track has no frames but the compressed buffer still contains data.
+        /// This likely indicates a truncated or improperly padded file.
+        #[error(
+            "Found {} bytes of compressed data for a track with a frame count of 0.",
+            size
+        )]
+        UnexpectedCompressedData { size: usize },
+
+        /// The values can't be combined because the tracks have different types.
+        #[error(
+            "Cannot append {:?} track values to {:?} track values.",
+            other,
+            track_type
+        )]
+        MismatchedTrackTypes {
+            track_type: TrackTypeV2,
+            other: TrackTypeV2,
+        },
+
+        /// The value for the frame at `index` contains a NaN or infinite component.
+        #[error("The value at frame {} is not finite.", index)]
+        NonFiniteValue { index: usize },
+
+        /// The bit count is too large to store a compressed value.
+        #[error(
+            "Bit count {} exceeds the maximum bit count of {} for compressed values.",
+            bit_count,
+            compression::MAX_BIT_COUNT
+        )]
+        BitCountOutOfRange { bit_count: u64 },
     }
 }
 
@@ -289,7 +555,7 @@ fn create_anim_group(g: &GroupData, buffer: &mut Cursor<Vec<u8>>) -> Result<Grou
 
 fn create_anim_node(n: &NodeData, buffer: &mut Cursor<Vec<u8>>) -> Result<Node, error::Error> {
     Ok(Node {
-        name: n.name.as_str().into(), // TODO: Make a convenience method for this?
+        name: SsbhString::try_from_str(&n.name)?,
         tracks: n
             .tracks
             .iter()
@@ -303,28 +569,41 @@ fn create_anim_track_v2(
     buffer: &mut Cursor<Vec<u8>>,
     t: &TrackData,
 ) -> Result<TrackV2, error::Error> {
-    let compression_type = infer_optimal_compression_type(&t.values);
-
     // The current stream position matches the offsets used for Smash Ultimate's anim files.
     // This assumes we traverse the hierarchy (group -> node -> track) in DFS order.
     let pos_before = buffer.stream_position()?;
 
-    // Pointers for compressed data are relative to the start of the track's data.
-    // This requires using a second writer due to how SsbhWrite is implemented.
-    let mut track_data = Cursor::new(Vec::new());
-
-    // TODO: Add tests for preserving scale compensation?.
-    t.values.write(
-        &mut track_data,
-        compression_type,
-        t.scale_options.compensate_scale,
-    )?;
+    let compression_type = match t.metadata.as_ref() {
+        Some(metadata) if is_unmodified_track(t, metadata) => {
+            buffer.write_all(&metadata.data)?;
+            metadata.compression_type
+        }
+        metadata => {
+            let compression_type = metadata
+                .map(|m| m.compression_type)
+                .filter(|c| supports_compression_type(&t.values, *c))
+                .unwrap_or_else(|| infer_optimal_compression_type(&t.values));
+
+            // Pointers for compressed data are relative to the start of the track's data.
+            // This requires using a second writer due to how SsbhWrite is implemented.
+            let mut track_data = Cursor::new(Vec::new());
+
+            // TODO: Add tests for preserving scale compensation?.
+            t.values.write(
+                &mut track_data,
+                compression_type,
+                t.scale_options.compensate_scale,
+            )?;
+
+            buffer.write_all(&track_data.into_inner())?;
+            compression_type
+        }
+    };
 
-    buffer.write_all(&track_data.into_inner())?;
     let pos_after = buffer.stream_position()?;
 
     Ok(TrackV2 {
-        name: t.name.as_str().into(),
+        name: SsbhString::try_from_str(&t.name)?,
         flags: TrackFlags {
             track_type: t.values.track_type(),
             compression_type,
@@ -336,6 +615,32 @@ fn create_anim_track_v2(
     })
 }
 
+// Check if the original data still produces the same values and scale options.
+fn is_unmodified_track(t: &TrackData, metadata: &TrackMetadata) -> bool {
+    let flags = TrackFlags {
+        track_type: t.values.track_type(),
+        compression_type: metadata.compression_type,
+    };
+    match read_track_values(&metadata.data, flags, t.values.len()) {
+        Ok((values, compensate_scale)) => {
+            values == t.values
+                && (compensate_scale && !t.transform_flags.override_scale)
+                    == t.scale_options.compensate_scale
+        }
+        Err(_) => false,
+    }
+}
+
+fn supports_compression_type(values: &TrackValues, compression_type: CompressionType) -> bool {
+    match (values, compression_type) {
+        (TrackValues::Transform(_), CompressionType::ConstTransform) => values.len() <= 1,
+        (TrackValues::Transform(_), CompressionType::Constant) => false,
+        (_, CompressionType::ConstTransform) => false,
+        (_, CompressionType::Constant) => values.len() <= 1,
+        (_, CompressionType::Direct | CompressionType::Compressed) => true,
+    }
+}
+
 fn infer_optimal_compression_type(values: &TrackValues) -> CompressionType {
     match (values, values.len()) {
         // Single frame animations use a special compression type.
@@ -361,7 +666,10 @@ fn infer_optimal_compression_type(values: &TrackValues) -> CompressionType {
 }
 
 // TODO: Test conversions from anim?
-fn read_anim_groups(anim: &Anim) -> Result<Vec<GroupData>, error::Error> {
+fn read_anim_groups(
+    anim: &Anim,
+    preserve_compression: bool,
+) -> Result<Vec<GroupData>, error::Error> {
     match anim {
         // TODO: Create fake groups for version 1.0?
         ssbh_lib::prelude::Anim::V12 {
@@ -376,14 +684,53 @@ fn read_anim_groups(anim: &Anim) -> Result<Vec<GroupData>, error::Error> {
             Ok(Vec::new())
         }
         ssbh_lib::formats::anim::Anim::V20 { groups, buffer, .. } => {
-            read_groups_v20(&groups.elements, &buffer.elements)
+            read_groups_v20(&groups.elements, &buffer.elements, preserve_compression)
         }
         ssbh_lib::formats::anim::Anim::V21 { groups, buffer, .. } => {
-            read_groups_v20(&groups.elements, &buffer.elements)
+            read_groups_v20(&groups.elements, &buffer.elements, preserve_compression)
         }
     }
 }
 
+/// Reads the data for the first track named `track_name` in a node named `node_name`
+/// without decoding the data for any other tracks.
+///
+/// Returns `Ok(None)` if no matching track is found.
+/// Version 1.2 anims are not supported and always return `Ok(None)`.
+/**
+```rust no_run
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+use ssbh_data::anim_data::read_track;
+
+let anim = ssbh_lib::formats::anim::Anim::from_file("camera.nuanmb")?;
+if let Some(track) = read_track(&anim, "gya_camera", "Transform")? {
+    println!("{:?}", track.values);
+}
+# Ok(()) }
+```
+ */
+pub fn read_track(
+    anim: &Anim,
+    node_name: &str,
+    track_name: &str,
+) -> Result<Option<TrackData>, error::Error> {
+    let (groups, buffer) = match anim {
+        Anim::V12 { .. } => return Ok(None),
+        Anim::V20 { groups, buffer, .. } => (groups, buffer),
+        Anim::V21 { groups, buffer, .. } => (groups, buffer),
+    };
+
+    groups
+        .elements
+        .iter()
+        .flat_map(|g| g.nodes.elements.iter())
+        .filter(|n| n.name.to_str() == Some(node_name))
+        .flat_map(|n| n.tracks.elements.iter())
+        .find(|t| t.name.to_str() == Some(track_name))
+        .map(|t| create_track_data_v20(t, &buffer.elements, false))
+        .transpose()
+}
+
 fn create_track_data_v12(
     track: &ssbh_lib::formats::anim::TrackV1,
     buffers: &ssbh_lib::SsbhArray<ssbh_lib::SsbhByteBuffer>,
@@ -429,12 +776,14 @@ fn create_track_data_v12(
         scale_options: ScaleOptions::default(),
         values: TrackValues::Float(Vec::new()),
         transform_flags: TransformFlags::default(),
+        metadata: None,
     })
 }
 
 fn read_groups_v20(
     anim_groups: &[ssbh_lib::formats::anim::Group],
     anim_buffer: &[u8],
+    preserve_compression: bool,
 ) -> Result<Vec<GroupData>, error::Error> {
     let mut groups = Vec::new();
 
@@ -445,7 +794,7 @@ fn read_groups_v20(
             let mut tracks = Vec::new();
             for anim_track in &anim_node.tracks.elements {
                 // Find and read the track data.
-                let track = create_track_data_v20(anim_track, anim_buffer)?;
+                let track = create_track_data_v20(anim_track, anim_buffer, preserve_compression)?;
                 tracks.push(track);
             }
 
@@ -469,6 +818,7 @@ fn read_groups_v20(
 fn create_track_data_v20(
     track: &ssbh_lib::formats::anim::TrackV2,
     buffer: &[u8],
+    preserve_compression: bool,
 ) -> Result<TrackData, error::Error> {
     let start = track.data_offset as usize;
     let end =
@@ -499,6 +849,10 @@ fn create_track_data_v20(
                 && !track.transform_flags.override_compensate_scale(),
         },
         transform_flags: track.transform_flags.into(),
+        metadata: preserve_compression.then(|| TrackMetadata {
+            compression_type: track.flags.compression_type,
+            data: buffer.to_vec(),
+        }),
     })
 }
 
@@ -533,7 +887,8 @@ let track = TrackData {
     name: "Transform".to_string(),
     values: TrackValues::Transform(vec![Transform::IDENTITY]),
     scale_options: ScaleOptions::default(),
-    transform_flags: TransformFlags::default()
+    transform_flags: TransformFlags::default(),
+    metadata: None,
 };
 ```
  */
@@ -558,6 +913,25 @@ pub struct TrackData {
     /// and repeat that element for each frame in the animation
     /// up to and including [final_frame_index](struct.AnimData.html#structfield.final_frame_index).
     pub values: TrackValues,
+
+    /// The original compression for tracks read using [AnimData::from_anim_preserving_compression].
+    /// This should usually be `None` for new tracks.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
+    pub metadata: Option<TrackMetadata>,
+}
+
+/// The original encoded data for a [TrackData] used to avoid recompressing unmodified tracks.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct TrackMetadata {
+    /// The compression type of the original track.
+    pub compression_type: CompressionType,
+    /// The original track data including any compression header.
+    pub data: Vec<u8>,
 }
 
 /// Determines how scaling is calculated for bone chains. Only applies to [TrackValues::Transform].
@@ -608,7 +982,7 @@ impl From<AnimTransformFlags> for TransformFlags {
 /// A decomposed 2D transformation for texture coordinates.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Debug, BinRead, PartialEq, SsbhWrite, Default, Clone, Copy)]
+#[derive(Debug, BinRead, BinWrite, PartialEq, SsbhWrite, Default, Clone, Copy)]
 pub struct UvTransform {
     pub scale_u: f32,
     pub scale_v: f32,
@@ -651,6 +1025,145 @@ impl Transform {
             z: 0.0,
         },
     };
+
+    /// Returns the little endian bytes for the uncompressed transform as it would be stored in an Anim buffer.
+    /// The scale, rotation, and translation are followed by a `u32` flag for `compensate_scale`.
+    /**
+    ```rust
+    # use ssbh_data::anim_data::Transform;
+    let bytes = Transform::IDENTITY.to_le_bytes(false);
+    assert_eq!(44, bytes.len());
+    assert_eq!([0, 0, 0, 0], bytes[40..]);
+    ```
+     */
+    pub fn to_le_bytes(&self, compensate_scale: bool) -> [u8; 44] {
+        let mut bytes = [0u8; 44];
+        bytes[..12].copy_from_slice(&self.scale.to_le_bytes());
+        bytes[12..28].copy_from_slice(&self.rotation.to_le_bytes());
+        bytes[28..40].copy_from_slice(&self.translation.to_le_bytes());
+        bytes[40..].copy_from_slice(&u32::from(compensate_scale).to_le_bytes());
+        bytes
+    }
+
+    /// Calculates the combined transformation matrix by applying scale, then rotation, then translation.
+    /**
+    ```rust
+    # use ssbh_data::anim_data::Transform;
+    # use ssbh_lib::{Matrix4x4, Vector4};
+    assert_eq!(Matrix4x4::identity(), Transform::IDENTITY.to_matrix());
+
+    let transform = Transform {
+        translation: ssbh_lib::Vector3::new(1.0, 2.0, 3.0),
+        ..Transform::IDENTITY
+    };
+    assert_eq!(Vector4::new(1.0, 2.0, 3.0, 1.0), transform.to_matrix().col4);
+    ```
+     */
+    pub fn to_matrix(&self) -> Matrix4x4 {
+        let matrix = glam::Mat4::from_scale_rotation_translation(
+            glam::Vec3::from(self.scale.to_array()),
+            glam::Quat::from_array(self.rotation.to_array()),
+            glam::Vec3::from(self.translation.to_array()),
+        );
+        Matrix4x4::from_cols_array(&matrix.to_cols_array_2d())
+    }
+
+    /// Returns `true` if each component differs from `other` by at most `epsilon`.
+    /**
+    ```rust
+    # use ssbh_data::anim_data::Transform;
+    let transform = Transform {
+        translation: ssbh_lib::Vector3::new(0.0, 0.0001, 0.0),
+        ..Transform::IDENTITY
+    };
+    assert!(transform.abs_diff_eq(&Transform::IDENTITY, 0.001));
+    assert!(!transform.abs_diff_eq(&Transform::IDENTITY, 0.00001));
+    ```
+     */
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        slice_abs_diff_eq(&self.scale.to_array(), &other.scale.to_array(), epsilon)
+            && slice_abs_diff_eq(
+                &self.rotation.to_array(),
+                &other.rotation.to_array(),
+                epsilon,
+            )
+            && slice_abs_diff_eq(
+                &self.translation.to_array(),
+                &other.translation.to_array(),
+                epsilon,
+            )
+    }
+}
+
+impl UvTransform {
+    /// Returns `true` if each component differs from `other` by at most `epsilon`.
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        slice_abs_diff_eq(&self.to_array(), &other.to_array(), epsilon)
+    }
+
+    fn to_array(self) -> [f32; 5] {
+        [
+            self.scale_u,
+            self.scale_v,
+            self.rotation,
+            self.translate_u,
+            self.translate_v,
+        ]
+    }
+}
+
+// Ramer–Douglas–Peucker using the error from linear interpolation between frames.
+fn keep_decimated_frames(values: &[f32], max_error: f32, keep: &mut [bool]) {
+    if values.is_empty() {
+        return;
+    }
+
+    let last = values.len() - 1;
+    keep[0] = true;
+    keep[last] = true;
+
+    let mut segments = vec![(0, last)];
+    while let Some((start, end)) = segments.pop() {
+        let max_frame = (start + 1..end)
+            .map(|i| {
+                let t = (i - start) as f32 / (end - start) as f32;
+                let interpolated = values[start] * (1.0 - t) + values[end] * t;
+                (i, (values[i] - interpolated).abs())
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        if let Some((i, error)) = max_frame {
+            if error > max_error {
+                keep[i] = true;
+                segments.push((start, i));
+                segments.push((i, end));
+            }
+        }
+    }
+}
+
+fn slice_abs_diff_eq(a: &[f32], b: &[f32], epsilon: f32) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() <= epsilon)
+}
+
+/// The byte order used for reading and writing track data.
+///
+/// Smash Ultimate uses [Endianness::Little].
+/// The bits in a compressed buffer are always packed in the same order regardless of [Endianness].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Endianness {
+    #[default]
+    Little,
+    Big,
+}
+
+impl From<Endianness> for binrw::Endian {
+    fn from(e: Endianness) -> Self {
+        match e {
+            Endianness::Little => binrw::Endian::Little,
+            Endianness::Big => binrw::Endian::Big,
+        }
+    }
 }
 
 /// A value collection with an element for each frame of the animation.
@@ -671,6 +1184,19 @@ pub enum TrackValues {
     Vector4(Vec<Vector4>),
 }
 
+/// The value for a single frame of a [TrackValues].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TrackValueAtFrame {
+    Transform(Transform),
+    UvTransform(UvTransform),
+    Float(f32),
+    PatternIndex(u32),
+    Boolean(bool),
+    Vector4(Vector4),
+}
+
 impl TrackValues {
     /// Returns the number of elements, which is equivalent to the number of frames.
     /// # Examples
@@ -709,93 +1235,867 @@ impl TrackValues {
         }
     }
 
-    fn track_type(&self) -> TrackTypeV2 {
-        match self {
-            TrackValues::Transform(_) => TrackTypeV2::Transform,
-            TrackValues::UvTransform(_) => TrackTypeV2::UvTransform,
-            TrackValues::Float(_) => TrackTypeV2::Float,
-            TrackValues::PatternIndex(_) => TrackTypeV2::PatternIndex,
-            TrackValues::Boolean(_) => TrackTypeV2::Boolean,
-            TrackValues::Vector4(_) => TrackTypeV2::Vector4,
-        }
+    /// Creates a single frame [TrackValues::Transform] that is constant for the entire animation.
+    pub fn constant_transform(value: Transform) -> Self {
+        TrackValues::Transform(vec![value])
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Creates a single frame [TrackValues::UvTransform] that is constant for the entire animation.
+    pub fn constant_uv_transform(value: UvTransform) -> Self {
+        TrackValues::UvTransform(vec![value])
+    }
 
-    // TODO: Test the conversions more thoroughly.
+    /// Creates a single frame [TrackValues::Float] that is constant for the entire animation.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{CompressionType, TrackValues};
+    let values = TrackValues::constant_float(0.5);
+    assert_eq!(TrackValues::Float(vec![0.5]), values);
+    assert_eq!(CompressionType::Constant, values.choose_compression());
+    ```
+     */
+    pub fn constant_float(value: f32) -> Self {
+        TrackValues::Float(vec![value])
+    }
 
-    #[test]
-    fn create_empty_anim_v_2_0() {
-        let anim = create_anim(&AnimData {
-            major_version: 2,
-            minor_version: 0,
-            final_frame_index: 1.5,
-            groups: Vec::new(),
-        })
-        .unwrap();
+    /// Creates a single frame [TrackValues::PatternIndex] that is constant for the entire animation.
+    pub fn constant_pattern_index(value: u32) -> Self {
+        TrackValues::PatternIndex(vec![value])
+    }
 
-        assert!(matches!(
-            anim,
-            Anim::V20 {
-                final_frame_index,
-                ..
-            } if final_frame_index == 1.5
-        ));
+    /// Creates a single frame [TrackValues::Boolean] that is constant for the entire animation.
+    pub fn constant_boolean(value: bool) -> Self {
+        TrackValues::Boolean(vec![value])
     }
 
-    #[test]
-    fn create_empty_anim_v_2_1() {
-        let anim = create_anim(&AnimData {
-            major_version: 2,
-            minor_version: 1,
-            final_frame_index: 2.5,
-            groups: Vec::new(),
-        })
-        .unwrap();
+    /// Creates a single frame [TrackValues::Vector4] that is constant for the entire animation.
+    pub fn constant_vector4(value: Vector4) -> Self {
+        TrackValues::Vector4(vec![value])
+    }
 
-        assert!(matches!(anim, Anim::V21 {
-            final_frame_index, 
-            ..
-        } if final_frame_index == 2.5));
+    /// Splits the values for a [TrackValues::Transform] into separate
+    /// translation, rotation, and scale channels with one element per frame.
+    /// Returns [None] for other value types.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{TrackValues, Transform};
+    let values = TrackValues::Transform(vec![Transform::IDENTITY]);
+    let (translation, rotation, scale) = values.split_transform().unwrap();
+    assert_eq!(vec![Transform::IDENTITY.translation], translation);
+    assert_eq!(vec![Transform::IDENTITY.rotation], rotation);
+    assert_eq!(vec![Transform::IDENTITY.scale], scale);
+
+    assert_eq!(None, TrackValues::Float(vec![0.5]).split_transform());
+    ```
+     */
+    pub fn split_transform(&self) -> Option<(Vec<Vector3>, Vec<Vector4>, Vec<Vector3>)> {
+        match self {
+            TrackValues::Transform(values) => Some((
+                values.iter().map(|t| t.translation).collect(),
+                values.iter().map(|t| t.rotation).collect(),
+                values.iter().map(|t| t.scale).collect(),
+            )),
+            _ => None,
+        }
     }
 
-    #[test]
-    fn create_anim_negative_frame_index() {
-        let result = create_anim(&AnimData {
-            major_version: 2,
-            minor_version: 1,
-            final_frame_index: -1.0,
-            groups: Vec::new(),
-        });
+    /// Returns the [CompressionType] that produces the smallest output for these values.
+    ///
+    /// Tracks with at most one frame or with all frames equal use [CompressionType::Constant]
+    /// or [CompressionType::ConstTransform] for [TrackValues::Transform].
+    /// Other tracks use [CompressionType::Compressed] if compression saves space
+    /// and [CompressionType::Direct] otherwise.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{CompressionType, TrackValues};
+    let values = TrackValues::Float(vec![0.5; 5]);
+    assert_eq!(CompressionType::Constant, values.choose_compression());
 
-        assert!(matches!(
-            result,
-            Err(error::Error::InvalidFinalFrameIndex {
-                final_frame_index
-            }) if final_frame_index == -1.0
-        ));
+    let values = TrackValues::Float((0..20).map(|i| i as f32).collect());
+    assert_eq!(CompressionType::Compressed, values.choose_compression());
+    ```
+     */
+    pub fn choose_compression(&self) -> CompressionType {
+        if self.all_equal() {
+            match self {
+                TrackValues::Transform(_) => CompressionType::ConstTransform,
+                _ => CompressionType::Constant,
+            }
+        } else {
+            infer_optimal_compression_type(self)
+        }
     }
 
-    #[test]
-    fn create_anim_insufficient_frame_index() {
-        let result = create_anim(&AnimData {
-            major_version: 2,
-            minor_version: 1,
-            final_frame_index: 2.0,
-            groups: vec![GroupData {
-                group_type: GroupType::Visibility,
-                nodes: vec![NodeData {
-                    name: String::new(),
-                    tracks: vec![TrackData {
-                        name: String::new(),
-                        values: TrackValues::Boolean(vec![true; 4]),
-                        scale_options: ScaleOptions::default(),
-                        transform_flags: TransformFlags::default(),
-                    }],
-                }],
+    /// Writes the values to `writer` using the [CompressionType] from [TrackValues::choose_compression].
+    ///
+    /// Only the first frame is written for [CompressionType::Constant] and [CompressionType::ConstTransform],
+    /// so the frame count for the track should be set to 1.
+    /// Returns the [CompressionType] used for writing.
+    pub fn write_optimal<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        compensate_scale: bool,
+    ) -> Result<CompressionType, error::Error> {
+        let compression = self.choose_compression();
+        self.simplify()
+            .write(writer, compression, compensate_scale)?;
+        Ok(compression)
+    }
+
+    /// Returns `true` if every frame is equal to the first frame.
+    /// Floating point components can differ by at most `epsilon`.
+    /// [TrackValues::PatternIndex] and [TrackValues::Boolean] values use exact equality.
+    ///
+    /// Constant tracks can be simplified to a single frame using [TrackValues::simplify].
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::TrackValues;
+    let values = TrackValues::Float(vec![0.5, 0.50001, 0.49999]);
+    assert!(values.is_constant(0.0001));
+    assert!(!values.is_constant(0.0));
+
+    assert!(!TrackValues::Boolean(vec![true, false]).is_constant(1.0));
+    ```
+     */
+    pub fn is_constant(&self, epsilon: f32) -> bool {
+        fn all_near<T>(values: &[T], eq: impl Fn(&T, &T) -> bool) -> bool {
+            match values.first() {
+                Some(first) => values.iter().all(|v| eq(first, v)),
+                None => true,
+            }
+        }
+
+        match self {
+            TrackValues::Transform(v) => all_near(v, |a, b| a.abs_diff_eq(b, epsilon)),
+            TrackValues::UvTransform(v) => all_near(v, |a, b| a.abs_diff_eq(b, epsilon)),
+            TrackValues::Float(v) => all_near(v, |a, b| (a - b).abs() <= epsilon),
+            TrackValues::PatternIndex(v) => v.iter().all_equal(),
+            TrackValues::Boolean(v) => v.iter().all_equal(),
+            TrackValues::Vector4(v) => all_near(v, |a, b| {
+                slice_abs_diff_eq(&a.to_array(), &b.to_array(), epsilon)
+            }),
+        }
+    }
+
+    // The names for the values returned by components_at_frame.
+    fn component_names(&self) -> &'static [Option<&'static str>] {
+        match self {
+            TrackValues::Transform(_) => &[
+                Some("scale.x"),
+                Some("scale.y"),
+                Some("scale.z"),
+                Some("rotation.x"),
+                Some("rotation.y"),
+                Some("rotation.z"),
+                Some("rotation.w"),
+                Some("translation.x"),
+                Some("translation.y"),
+                Some("translation.z"),
+            ],
+            TrackValues::UvTransform(_) => &[
+                Some("scale_u"),
+                Some("scale_v"),
+                Some("rotation"),
+                Some("translate_u"),
+                Some("translate_v"),
+            ],
+            TrackValues::Float(_) | TrackValues::PatternIndex(_) | TrackValues::Boolean(_) => {
+                &[None]
+            }
+            TrackValues::Vector4(_) => &[Some("x"), Some("y"), Some("z"), Some("w")],
+        }
+    }
+
+    // Frames past the end use the final value.
+    fn components_at_frame(&self, frame: usize) -> Vec<f32> {
+        fn value<T>(values: &[T], frame: usize) -> Option<&T> {
+            values.get(frame).or_else(|| values.last())
+        }
+
+        match self {
+            TrackValues::Transform(v) => value(v, frame)
+                .map(|t| {
+                    [
+                        t.scale.to_array().as_slice(),
+                        &t.rotation.to_array(),
+                        &t.translation.to_array(),
+                    ]
+                    .concat()
+                })
+                .unwrap_or_default(),
+            TrackValues::UvTransform(v) => value(v, frame)
+                .map(|t| t.to_array().to_vec())
+                .unwrap_or_default(),
+            TrackValues::Float(v) => value(v, frame).map(|f| vec![*f]).unwrap_or_default(),
+            TrackValues::PatternIndex(v) => {
+                value(v, frame).map(|i| vec![*i as f32]).unwrap_or_default()
+            }
+            TrackValues::Boolean(v) => value(v, frame)
+                .map(|b| vec![if *b { 1.0 } else { 0.0 }])
+                .unwrap_or_default(),
+            TrackValues::Vector4(v) => value(v, frame)
+                .map(|v| v.to_array().to_vec())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns an error with the index of the first frame containing a NaN or infinite component.
+    /// Compression ranges ignore NaN values, so non finite values should be checked before writing.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::TrackValues;
+    assert!(TrackValues::Float(vec![0.0, 1.0]).check_finite().is_ok());
+    assert!(TrackValues::Float(vec![0.0, f32::INFINITY]).check_finite().is_err());
+    ```
+     */
+    pub fn check_finite(&self) -> Result<(), error::Error> {
+        fn all_finite(components: &[f32]) -> bool {
+            components.iter().all(|c| c.is_finite())
+        }
+
+        let index = match self {
+            TrackValues::Transform(v) => v.iter().position(|t| {
+                !(all_finite(&t.scale.to_array())
+                    && all_finite(&t.rotation.to_array())
+                    && all_finite(&t.translation.to_array()))
+            }),
+            TrackValues::UvTransform(v) => v.iter().position(|t| !all_finite(&t.to_array())),
+            TrackValues::Float(v) => v.iter().position(|f| !f.is_finite()),
+            TrackValues::PatternIndex(_) | TrackValues::Boolean(_) => None,
+            TrackValues::Vector4(v) => v.iter().position(|v| !all_finite(&v.to_array())),
+        };
+
+        match index {
+            Some(index) => Err(error::Error::NonFiniteValue { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Collapses the values to a single frame if all frames are equal.
+    /// Tracks with differing values are returned unchanged.
+    ///
+    /// The simplified values should be written using [CompressionType::Constant]
+    /// or [CompressionType::ConstTransform] for [TrackValues::Transform].
+    /// See [TrackValues::choose_compression].
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{CompressionType, TrackValues};
+    let values = TrackValues::Boolean(vec![true; 100]);
+    let simplified = values.simplify();
+    assert_eq!(TrackValues::Boolean(vec![true]), simplified);
+    assert_eq!(CompressionType::Constant, simplified.choose_compression());
+    ```
+     */
+    pub fn simplify(&self) -> TrackValues {
+        if self.all_equal() {
+            self.first_frame()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Calculates the transformation matrix for each frame using [Transform::to_matrix].
+    /// Returns [None] if the values are not [TrackValues::Transform].
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{TrackValues, Transform};
+    # use ssbh_lib::Matrix4x4;
+    let values = TrackValues::Transform(vec![Transform::IDENTITY; 2]);
+    assert_eq!(
+        Some(vec![Matrix4x4::identity(), Matrix4x4::identity()]),
+        values.to_matrices()
+    );
+    assert_eq!(None, TrackValues::Float(vec![0.5]).to_matrices());
+    ```
+     */
+    pub fn to_matrices(&self) -> Option<Vec<Matrix4x4>> {
+        match self {
+            TrackValues::Transform(v) => Some(v.iter().map(Transform::to_matrix).collect()),
+            _ => None,
+        }
+    }
+
+    /// Appends the frames from `other` to the end of these values.
+    /// Returns an error if the values do not have the same variant.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::TrackValues;
+    let mut values = TrackValues::Float(vec![0.0, 1.0]);
+    values.append(&TrackValues::Float(vec![2.0])).unwrap();
+    assert_eq!(TrackValues::Float(vec![0.0, 1.0, 2.0]), values);
+
+    assert!(values.append(&TrackValues::Boolean(vec![true])).is_err());
+    ```
+     */
+    pub fn append(&mut self, other: &TrackValues) -> Result<(), error::Error> {
+        match (self, other) {
+            (TrackValues::Transform(v), TrackValues::Transform(o)) => v.extend_from_slice(o),
+            (TrackValues::UvTransform(v), TrackValues::UvTransform(o)) => v.extend_from_slice(o),
+            (TrackValues::Float(v), TrackValues::Float(o)) => v.extend_from_slice(o),
+            (TrackValues::PatternIndex(v), TrackValues::PatternIndex(o)) => v.extend_from_slice(o),
+            (TrackValues::Boolean(v), TrackValues::Boolean(o)) => v.extend_from_slice(o),
+            (TrackValues::Vector4(v), TrackValues::Vector4(o)) => v.extend_from_slice(o),
+            (values, other) => {
+                return Err(error::Error::MismatchedTrackTypes {
+                    track_type: values.track_type(),
+                    other: other.track_type(),
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the frame indices of the keyframes from [TrackValues::decimate] in increasing order.
+    /// The first and last frames are always kept.
+    /// Values other than [TrackValues::Float] and [TrackValues::Vector4] keep every frame.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::TrackValues;
+    let values = TrackValues::Float(vec![0.0, 1.0, 2.0, 1.0, 0.0]);
+    assert_eq!(vec![0, 2, 4], values.decimated_frames(0.001));
+    ```
+     */
+    pub fn decimated_frames(&self, max_error: f32) -> Vec<usize> {
+        let component_count = match self {
+            TrackValues::Float(_) => 1,
+            TrackValues::Vector4(_) => 4,
+            _ => return (0..self.len()).collect(),
+        };
+
+        let frames: Vec<_> = (0..self.len())
+            .map(|frame| self.components_at_frame(frame))
+            .collect();
+
+        // Decimate each component separately and keep the frames needed by any component.
+        let mut keep = vec![false; frames.len()];
+        for i in 0..component_count {
+            let component: Vec<_> = frames.iter().map(|f| f[i]).collect();
+            keep_decimated_frames(&component, max_error, &mut keep);
+        }
+
+        keep.iter()
+            .enumerate()
+            .filter_map(|(i, keep)| keep.then_some(i))
+            .collect()
+    }
+
+    /// Returns the `(frame, value)` keyframes needed to reconstruct the values within `max_error`
+    /// by linearly interpolating between keyframes.
+    /// Each component of [TrackValues::Float] and [TrackValues::Vector4] values
+    /// is decimated separately using the Ramer–Douglas–Peucker algorithm.
+    /// Other values keep every frame.
+    ///
+    /// Peaks and other local extrema are kept unless they are within `max_error` of the line
+    /// between the neighboring kept frames.
+    /// Anim tracks store a value for every frame,
+    /// so the keyframes should be interpolated back to every frame before writing.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{TrackValueAtFrame, TrackValues};
+    let values = TrackValues::Float(vec![0.0, 1.0, 2.0, 1.0, 0.0]);
+    assert_eq!(
+        vec![
+            (0, TrackValueAtFrame::Float(0.0)),
+            (2, TrackValueAtFrame::Float(2.0)),
+            (4, TrackValueAtFrame::Float(0.0)),
+        ],
+        values.decimate(0.001)
+    );
+    ```
+     */
+    pub fn decimate(&self, max_error: f32) -> Vec<(usize, TrackValueAtFrame)> {
+        self.decimated_frames(max_error)
+            .into_iter()
+            .map(|i| {
+                let value = match self {
+                    TrackValues::Transform(v) => TrackValueAtFrame::Transform(v[i]),
+                    TrackValues::UvTransform(v) => TrackValueAtFrame::UvTransform(v[i]),
+                    TrackValues::Float(v) => TrackValueAtFrame::Float(v[i]),
+                    TrackValues::PatternIndex(v) => TrackValueAtFrame::PatternIndex(v[i]),
+                    TrackValues::Boolean(v) => TrackValueAtFrame::Boolean(v[i]),
+                    TrackValues::Vector4(v) => TrackValueAtFrame::Vector4(v[i]),
+                };
+                (i, value)
+            })
+            .collect()
+    }
+
+    /// Mirrors [TrackValues::Transform] values across the YZ plane by negating the X axis.
+    /// Other values are unchanged.
+    ///
+    /// Rotations are quaternions with components `(x, y, z, w)`.
+    /// Reflecting across the plane perpendicular to an axis preserves the rotation about that axis
+    /// by keeping that component and `w` while negating the remaining two components.
+    /// Scale is unchanged.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{TrackValues, Transform};
+    # use ssbh_lib::{Vector3, Vector4};
+    let mut values = TrackValues::Transform(vec![Transform {
+        scale: Vector3::new(1.0, 1.0, 1.0),
+        rotation: Vector4::new(0.1, 0.2, 0.3, 0.9),
+        translation: Vector3::new(1.0, 2.0, 3.0),
+    }]);
+    values.mirror_x();
+    assert_eq!(
+        TrackValues::Transform(vec![Transform {
+            scale: Vector3::new(1.0, 1.0, 1.0),
+            rotation: Vector4::new(0.1, -0.2, -0.3, 0.9),
+            translation: Vector3::new(-1.0, 2.0, 3.0),
+        }]),
+        values
+    );
+    ```
+     */
+    pub fn mirror_x(&mut self) {
+        self.mirror(0);
+    }
+
+    /// Mirrors [TrackValues::Transform] values across the XZ plane by negating the Y axis.
+    /// See [TrackValues::mirror_x] for details.
+    pub fn mirror_y(&mut self) {
+        self.mirror(1);
+    }
+
+    /// Mirrors [TrackValues::Transform] values across the XY plane by negating the Z axis.
+    /// See [TrackValues::mirror_x] for details.
+    pub fn mirror_z(&mut self) {
+        self.mirror(2);
+    }
+
+    fn mirror(&mut self, axis: usize) {
+        if let TrackValues::Transform(values) = self {
+            for t in values {
+                t.translation[axis] = -t.translation[axis];
+
+                // Negate the quaternion components for the other two axes.
+                for i in (0..3).filter(|i| *i != axis) {
+                    t.rotation[i] = -t.rotation[i];
+                }
+            }
+        }
+    }
+
+    fn all_equal(&self) -> bool {
+        match self {
+            TrackValues::Transform(v) => v.iter().all_equal(),
+            TrackValues::UvTransform(v) => v.iter().all_equal(),
+            TrackValues::Float(v) => v.iter().all_equal(),
+            TrackValues::PatternIndex(v) => v.iter().all_equal(),
+            TrackValues::Boolean(v) => v.iter().all_equal(),
+            TrackValues::Vector4(v) => v.iter().all_equal(),
+        }
+    }
+
+    fn first_frame(&self) -> Self {
+        match self {
+            TrackValues::Transform(v) => {
+                TrackValues::Transform(v.iter().take(1).copied().collect())
+            }
+            TrackValues::UvTransform(v) => {
+                TrackValues::UvTransform(v.iter().take(1).copied().collect())
+            }
+            TrackValues::Float(v) => TrackValues::Float(v.iter().take(1).copied().collect()),
+            TrackValues::PatternIndex(v) => {
+                TrackValues::PatternIndex(v.iter().take(1).copied().collect())
+            }
+            TrackValues::Boolean(v) => TrackValues::Boolean(v.iter().take(1).copied().collect()),
+            TrackValues::Vector4(v) => TrackValues::Vector4(v.iter().take(1).copied().collect()),
+        }
+    }
+
+    fn track_type(&self) -> TrackTypeV2 {
+        match self {
+            TrackValues::Transform(_) => TrackTypeV2::Transform,
+            TrackValues::UvTransform(_) => TrackTypeV2::UvTransform,
+            TrackValues::Float(_) => TrackTypeV2::Float,
+            TrackValues::PatternIndex(_) => TrackTypeV2::PatternIndex,
+            TrackValues::Boolean(_) => TrackTypeV2::Boolean,
+            TrackValues::Vector4(_) => TrackTypeV2::Vector4,
+        }
+    }
+}
+
+/// Returns the expected track type for commonly used track names in Smash Ultimate
+/// or `None` if the name is not recognized.
+///
+/// Material parameter names like "CustomVector31" or "CustomFloat8" require a numeric suffix.
+/// This can be used to check that a [TrackData] has [TrackValues] of the appropriate type.
+/**
+```rust
+use ssbh_data::anim_data::{expected_track_type, TrackTypeV2};
+
+assert_eq!(Some(TrackTypeV2::Vector4), expected_track_type("CustomVector30"));
+assert_eq!(Some(TrackTypeV2::Boolean), expected_track_type("Visibility"));
+assert_eq!(None, expected_track_type("CustomVector"));
+```
+ */
+pub fn expected_track_type(name: &str) -> Option<TrackTypeV2> {
+    let has_index = |prefix: &str| {
+        name.strip_prefix(prefix)
+            .map(|i| !i.is_empty() && i.chars().all(|c| c.is_ascii_digit()))
+            .unwrap_or(false)
+    };
+
+    match name {
+        "Transform" => Some(TrackTypeV2::Transform),
+        "Visibility" => Some(TrackTypeV2::Boolean),
+        _ if has_index("CustomVector") => Some(TrackTypeV2::Vector4),
+        _ if has_index("CustomFloat") => Some(TrackTypeV2::Float),
+        _ if has_index("CustomBoolean") => Some(TrackTypeV2::Boolean),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_hex_eq;
+    use hexlit::hex;
+
+    // TODO: Test the conversions more thoroughly.
+
+    #[test]
+    fn transform_to_le_bytes() {
+        let transform = Transform {
+            scale: Vector3::new(1.0, 2.0, 3.0),
+            rotation: Vector4::new(0.4, 1.5, 1.0, 1.0),
+            translation: Vector3::new(-1.0, -2.0, -3.0),
+        };
+        assert_hex_eq!(
+            &hex!(
+                "0000803f 00000040 00004040
+                 cdcccc3e 0000c03f 0000803f 0000803f
+                 000080bf 000000c0 000040c0
+                 01000000"
+            ),
+            &transform.to_le_bytes(true)
+        );
+    }
+
+    #[test]
+    fn pose_at_frame_two_bones() {
+        let transform = |x| Transform {
+            translation: Vector3::new(x, 0.0, 0.0),
+            ..Transform::IDENTITY
+        };
+
+        let data = AnimData {
+            major_version: 2,
+            minor_version: 0,
+            final_frame_index: 2.0,
+            groups: vec![
+                GroupData {
+                    group_type: GroupType::Transform,
+                    nodes: vec![
+                        NodeData {
+                            name: "A".to_string(),
+                            tracks: vec![TrackData {
+                                name: "Transform".to_string(),
+                                values: TrackValues::Transform(vec![
+                                    transform(1.0),
+                                    transform(2.0),
+                                    transform(3.0),
+                                ]),
+                                scale_options: ScaleOptions::default(),
+                                transform_flags: TransformFlags::default(),
+                                metadata: None,
+                            }],
+                        },
+                        NodeData {
+                            name: "B".to_string(),
+                            tracks: vec![TrackData {
+                                name: "Transform".to_string(),
+                                values: TrackValues::constant_transform(transform(4.0)),
+                                scale_options: ScaleOptions::default(),
+                                transform_flags: TransformFlags::default(),
+                                metadata: None,
+                            }],
+                        },
+                    ],
+                },
+                GroupData {
+                    group_type: GroupType::Visibility,
+                    nodes: vec![NodeData {
+                        name: "C".to_string(),
+                        tracks: vec![TrackData {
+                            name: "Visibility".to_string(),
+                            values: TrackValues::constant_boolean(true),
+                            scale_options: ScaleOptions::default(),
+                            transform_flags: TransformFlags::default(),
+                            metadata: None,
+                        }],
+                    }],
+                },
+            ],
+        };
+
+        let pose = data.pose_at_frame(0);
+        assert_eq!(2, pose.len());
+        assert_eq!(transform(1.0), pose["A"]);
+        assert_eq!(transform(4.0), pose["B"]);
+
+        let pose = data.pose_at_frame(5);
+        assert_eq!(transform(3.0), pose["A"]);
+        assert_eq!(transform(4.0), pose["B"]);
+    }
+
+    #[test]
+    fn add_track_write_read() {
+        use crate::SsbhData;
+
+        let mut data = AnimData {
+            major_version: 2,
+            minor_version: 0,
+            final_frame_index: 0.0,
+            groups: Vec::new(),
+        };
+        data.add_track(
+            GroupType::Material,
+            "EyeL",
+            "CustomVector31",
+            TrackValues::Vector4(vec![
+                Vector4::new(1.0, 2.0, 3.0, 4.0),
+                Vector4::new(5.0, 6.0, 7.0, 8.0),
+            ]),
+        );
+        assert_eq!(1.0, data.final_frame_index);
+
+        let mut writer = Cursor::new(Vec::new());
+        data.write(&mut writer).unwrap();
+        writer.set_position(0);
+        let new_data = AnimData::read(&mut writer).unwrap();
+
+        assert_eq!(1.0, new_data.final_frame_index);
+        assert_eq!(1, new_data.groups.len());
+        assert_eq!(GroupType::Material, new_data.groups[0].group_type);
+        assert_eq!("EyeL", new_data.groups[0].nodes[0].name);
+        assert_eq!(
+            &TrackData {
+                name: "CustomVector31".to_string(),
+                scale_options: ScaleOptions::default(),
+                transform_flags: TransformFlags::default(),
+                values: TrackValues::Vector4(vec![
+                    Vector4::new(1.0, 2.0, 3.0, 4.0),
+                    Vector4::new(5.0, 6.0, 7.0, 8.0),
+                ]),
+                metadata: None,
+            },
+            &new_data.groups[0].nodes[0].tracks[0]
+        );
+    }
+
+    #[test]
+    fn add_track_existing_group_and_node() {
+        let mut data = AnimData {
+            major_version: 2,
+            minor_version: 0,
+            final_frame_index: 5.0,
+            groups: Vec::new(),
+        };
+        data.add_track(
+            GroupType::Material,
+            "a",
+            "CustomFloat0",
+            TrackValues::Float(vec![1.0]),
+        );
+        data.add_track(
+            GroupType::Transform,
+            "a",
+            "Transform",
+            TrackValues::Transform(vec![Transform::IDENTITY]),
+        );
+        data.add_track(
+            GroupType::Material,
+            "a",
+            "CustomFloat1",
+            TrackValues::Float(vec![2.0]),
+        )
+        .scale_options
+        .compensate_scale = true;
+
+        assert_eq!(5.0, data.final_frame_index);
+        assert_eq!(2, data.groups.len());
+        assert_eq!(1, data.groups[0].nodes.len());
+        assert_eq!(2, data.groups[0].nodes[0].tracks.len());
+        assert!(
+            data.groups[0].nodes[0].tracks[1]
+                .scale_options
+                .compensate_scale
+        );
+        assert_eq!(GroupType::Transform, data.groups[1].group_type);
+    }
+
+    #[test]
+    fn split_transform_two_frames() {
+        let values = TrackValues::Transform(vec![
+            Transform {
+                scale: Vector3::new(1.0, 2.0, 3.0),
+                rotation: Vector4::new(0.0, 0.0, 0.0, 1.0),
+                translation: Vector3::new(4.0, 5.0, 6.0),
+            },
+            Transform {
+                scale: Vector3::new(7.0, 8.0, 9.0),
+                rotation: Vector4::new(1.0, 0.0, 0.0, 0.0),
+                translation: Vector3::new(10.0, 11.0, 12.0),
+            },
+        ]);
+
+        assert_eq!(
+            Some((
+                vec![Vector3::new(4.0, 5.0, 6.0), Vector3::new(10.0, 11.0, 12.0)],
+                vec![
+                    Vector4::new(0.0, 0.0, 0.0, 1.0),
+                    Vector4::new(1.0, 0.0, 0.0, 0.0)
+                ],
+                vec![Vector3::new(1.0, 2.0, 3.0), Vector3::new(7.0, 8.0, 9.0)],
+            )),
+            values.split_transform()
+        );
+    }
+
+    #[test]
+    fn split_transform_non_transform() {
+        assert_eq!(None, TrackValues::Boolean(vec![true]).split_transform());
+    }
+
+    #[test]
+    fn constant_track_values() {
+        assert_eq!(
+            TrackValues::Transform(vec![Transform::IDENTITY]),
+            TrackValues::constant_transform(Transform::IDENTITY)
+        );
+        assert_eq!(
+            TrackValues::UvTransform(vec![UvTransform::default()]),
+            TrackValues::constant_uv_transform(UvTransform::default())
+        );
+        assert_eq!(
+            TrackValues::Float(vec![0.5]),
+            TrackValues::constant_float(0.5)
+        );
+        assert_eq!(
+            TrackValues::PatternIndex(vec![3]),
+            TrackValues::constant_pattern_index(3)
+        );
+        assert_eq!(
+            TrackValues::Boolean(vec![true]),
+            TrackValues::constant_boolean(true)
+        );
+        assert_eq!(
+            TrackValues::Vector4(vec![Vector4::new(1.0, 2.0, 3.0, 4.0)]),
+            TrackValues::constant_vector4(Vector4::new(1.0, 2.0, 3.0, 4.0))
+        );
+    }
+
+    #[test]
+    fn expected_track_types() {
+        assert_eq!(
+            Some(TrackTypeV2::Transform),
+            expected_track_type("Transform")
+        );
+        assert_eq!(
+            Some(TrackTypeV2::Boolean),
+            expected_track_type("Visibility")
+        );
+        assert_eq!(
+            Some(TrackTypeV2::Vector4),
+            expected_track_type("CustomVector31")
+        );
+        assert_eq!(
+            Some(TrackTypeV2::Float),
+            expected_track_type("CustomFloat8")
+        );
+        assert_eq!(
+            Some(TrackTypeV2::Boolean),
+            expected_track_type("CustomBoolean11")
+        );
+        assert_eq!(None, expected_track_type("CustomVector"));
+        assert_eq!(None, expected_track_type("CustomVectorA"));
+        assert_eq!(None, expected_track_type("transform"));
+        assert_eq!(None, expected_track_type("EyeL"));
+    }
+
+    #[test]
+    fn create_empty_anim_v_2_0() {
+        let anim = create_anim(&AnimData {
+            major_version: 2,
+            minor_version: 0,
+            final_frame_index: 1.5,
+            groups: Vec::new(),
+        })
+        .unwrap();
+
+        assert!(matches!(
+            anim,
+            Anim::V20 {
+                final_frame_index,
+                ..
+            } if final_frame_index == 1.5
+        ));
+    }
+
+    #[test]
+    fn create_empty_anim_v_2_1() {
+        let anim = create_anim(&AnimData {
+            major_version: 2,
+            minor_version: 1,
+            final_frame_index: 2.5,
+            groups: Vec::new(),
+        })
+        .unwrap();
+
+        assert!(matches!(anim, Anim::V21 {
+            final_frame_index, 
+            ..
+        } if final_frame_index == 2.5));
+    }
+
+    #[test]
+    fn create_anim_negative_frame_index() {
+        let result = create_anim(&AnimData {
+            major_version: 2,
+            minor_version: 1,
+            final_frame_index: -1.0,
+            groups: Vec::new(),
+        });
+
+        assert!(matches!(
+            result,
+            Err(error::Error::InvalidFinalFrameIndex {
+                final_frame_index
+            }) if final_frame_index == -1.0
+        ));
+    }
+
+    #[test]
+    fn create_anim_insufficient_frame_index() {
+        let result = create_anim(&AnimData {
+            major_version: 2,
+            minor_version: 1,
+            final_frame_index: 2.0,
+            groups: vec![GroupData {
+                group_type: GroupType::Visibility,
+                nodes: vec![NodeData {
+                    name: String::new(),
+                    tracks: vec![TrackData {
+                        name: String::new(),
+                        values: TrackValues::Boolean(vec![true; 4]),
+                        scale_options: ScaleOptions::default(),
+                        transform_flags: TransformFlags::default(),
+                        metadata: None,
+                    }],
+                }],
             }],
         });
 
@@ -808,6 +2108,29 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn create_anim_interior_nul() {
+        let result = create_anim(&AnimData {
+            major_version: 2,
+            minor_version: 0,
+            final_frame_index: 0.0,
+            groups: vec![GroupData {
+                group_type: GroupType::Visibility,
+                nodes: vec![NodeData {
+                    name: "ab\0cd".into(),
+                    tracks: Vec::new(),
+                }],
+            }],
+        });
+
+        assert!(matches!(
+            result,
+            Err(error::Error::String(ssbh_lib::StringError::InteriorNul {
+                position: 2
+            }))
+        ));
+    }
+
     #[test]
     fn create_anim_zero_frame_index() {
         let anim = create_anim(&AnimData {
@@ -842,6 +2165,51 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn read_single_track() {
+        let anim = create_anim(&AnimData {
+            major_version: 2,
+            minor_version: 0,
+            final_frame_index: 2.0,
+            groups: vec![GroupData {
+                group_type: GroupType::Material,
+                nodes: vec![NodeData {
+                    name: "mat".to_string(),
+                    tracks: vec![
+                        TrackData {
+                            name: "CustomFloat0".to_string(),
+                            values: TrackValues::Float(vec![1.0, 2.0, 3.0]),
+                            scale_options: ScaleOptions::default(),
+                            transform_flags: TransformFlags::default(),
+                            metadata: None,
+                        },
+                        TrackData {
+                            name: "CustomBoolean1".to_string(),
+                            values: TrackValues::Boolean(vec![true, false, true]),
+                            scale_options: ScaleOptions::default(),
+                            transform_flags: TransformFlags::default(),
+                            metadata: None,
+                        },
+                    ],
+                }],
+            }],
+        })
+        .unwrap();
+
+        assert_eq!(
+            TrackData {
+                name: "CustomBoolean1".to_string(),
+                values: TrackValues::Boolean(vec![true, false, true]),
+                scale_options: ScaleOptions::default(),
+                transform_flags: TransformFlags::default(),
+                metadata: None,
+            },
+            read_track(&anim, "mat", "CustomBoolean1").unwrap().unwrap()
+        );
+        assert_eq!(None, read_track(&anim, "mat", "CustomFloat1").unwrap());
+        assert_eq!(None, read_track(&anim, "other", "CustomFloat0").unwrap());
+    }
+
     #[test]
     fn create_node_no_tracks() {
         let node = NodeData {
@@ -866,12 +2234,14 @@ mod tests {
                     values: TrackValues::Float(vec![1.0, 2.0, 3.0]),
                     scale_options: ScaleOptions::default(),
                     transform_flags: TransformFlags::default(),
+                    metadata: None,
                 },
                 TrackData {
                     name: "t2".to_string(),
                     values: TrackValues::PatternIndex(vec![4, 5]),
                     scale_options: ScaleOptions::default(),
                     transform_flags: TransformFlags::default(),
+                    metadata: None,
                 },
             ],
         };
@@ -1090,6 +2460,462 @@ mod tests {
         );
     }
 
+    #[test]
+    fn choose_compression_all_equal_float() {
+        assert_eq!(
+            CompressionType::Constant,
+            TrackValues::Float(vec![1.5; 5]).choose_compression()
+        );
+    }
+
+    #[test]
+    fn choose_compression_all_equal_transform() {
+        assert_eq!(
+            CompressionType::ConstTransform,
+            TrackValues::Transform(vec![Transform::IDENTITY; 5]).choose_compression()
+        );
+    }
+
+    #[test]
+    fn choose_compression_varying_float() {
+        assert_eq!(
+            CompressionType::Compressed,
+            TrackValues::Float((0..20).map(|i| i as f32).collect()).choose_compression()
+        );
+    }
+
+    #[test]
+    fn choose_compression_varying_float_few_frames() {
+        // Compression doesn't save space for short tracks.
+        assert_eq!(
+            CompressionType::Direct,
+            TrackValues::Float(vec![1.0, 2.0, 3.0]).choose_compression()
+        );
+    }
+
+    #[test]
+    fn is_constant_float() {
+        assert!(TrackValues::Float(vec![0.5; 4]).is_constant(0.0));
+        assert!(TrackValues::Float(vec![0.5, 0.5009, 0.4991]).is_constant(0.001));
+    }
+
+    #[test]
+    fn is_constant_float_outside_epsilon() {
+        assert!(!TrackValues::Float(vec![0.5, 0.5, 0.502]).is_constant(0.001));
+    }
+
+    #[test]
+    fn is_constant_transform() {
+        let nearly_identity = Transform {
+            rotation: Vector4::new(0.0, 0.0005, 0.0, 1.0),
+            ..Transform::IDENTITY
+        };
+        let values = TrackValues::Transform(vec![Transform::IDENTITY, nearly_identity]);
+        assert!(values.is_constant(0.001));
+        assert!(!values.is_constant(0.0001));
+    }
+
+    #[test]
+    fn is_constant_empty_and_exact() {
+        assert!(TrackValues::Vector4(Vec::new()).is_constant(0.0));
+        assert!(TrackValues::PatternIndex(vec![3, 3]).is_constant(0.0));
+        assert!(!TrackValues::PatternIndex(vec![3, 4]).is_constant(10.0));
+    }
+
+    #[test]
+    fn to_table_transform_and_boolean() {
+        let mut data = AnimData {
+            major_version: 2,
+            minor_version: 0,
+            final_frame_index: 0.0,
+            groups: Vec::new(),
+        };
+        data.add_track(
+            GroupType::Transform,
+            "A",
+            "Transform",
+            TrackValues::Transform(vec![
+                Transform::IDENTITY,
+                Transform {
+                    translation: Vector3::new(1.0, 2.0, 3.0),
+                    ..Transform::IDENTITY
+                },
+            ]),
+        );
+        data.add_track(
+            GroupType::Visibility,
+            "B",
+            "Visibility",
+            TrackValues::Boolean(vec![true]),
+        );
+        data.add_track(
+            GroupType::Visibility,
+            "C",
+            "Visibility",
+            TrackValues::Boolean(Vec::new()),
+        );
+        data.final_frame_index = 2.0;
+
+        let (columns, rows) = data.to_table();
+        assert_eq!(11, columns.len());
+        assert_eq!("A.Transform.scale.x", columns[0]);
+        assert_eq!("A.Transform.translation.z", columns[9]);
+        assert_eq!("B.Visibility", columns[10]);
+
+        assert_eq!(3, rows.len());
+        assert!(rows.iter().all(|r| r.len() == columns.len()));
+        assert_eq!(0.0, rows[0][9]);
+        assert_eq!(3.0, rows[1][9]);
+        // Frames past the end use the final value.
+        assert_eq!(3.0, rows[2][9]);
+        assert_eq!(1.0, rows[2][10]);
+    }
+
+    #[test]
+    fn check_finite_float_nan() {
+        assert!(matches!(
+            TrackValues::Float(vec![0.0, 1.0, f32::NAN, 2.0]).check_finite(),
+            Err(error::Error::NonFiniteValue { index: 2 })
+        ));
+    }
+
+    #[test]
+    fn check_finite_transform() {
+        let mut values = vec![Transform::IDENTITY; 3];
+        assert!(TrackValues::Transform(values.clone())
+            .check_finite()
+            .is_ok());
+
+        values[1].rotation.w = f32::NEG_INFINITY;
+        assert!(matches!(
+            TrackValues::Transform(values).check_finite(),
+            Err(error::Error::NonFiniteValue { index: 1 })
+        ));
+    }
+
+    #[test]
+    fn simplify_constant_float() {
+        assert_eq!(
+            TrackValues::Float(vec![0.25]),
+            TrackValues::Float(vec![0.25; 100]).simplify()
+        );
+    }
+
+    #[test]
+    fn simplify_constant_transform() {
+        assert_eq!(
+            TrackValues::Transform(vec![Transform::IDENTITY]),
+            TrackValues::Transform(vec![Transform::IDENTITY; 100]).simplify()
+        );
+    }
+
+    #[test]
+    fn simplify_varying_float() {
+        assert_eq!(
+            TrackValues::Float(vec![0.25, 0.5]),
+            TrackValues::Float(vec![0.25, 0.5]).simplify()
+        );
+    }
+
+    #[test]
+    fn simplify_empty() {
+        assert_eq!(
+            TrackValues::Vector4(Vec::new()),
+            TrackValues::Vector4(Vec::new()).simplify()
+        );
+    }
+
+    #[test]
+    fn decimate_piecewise_linear_float() {
+        let values = TrackValues::Float(vec![0.0, 1.0, 2.0, 3.0, 2.0, 1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(vec![0, 3, 6, 8], values.decimated_frames(0.0001));
+        assert_eq!(
+            vec![
+                (0, TrackValueAtFrame::Float(0.0)),
+                (3, TrackValueAtFrame::Float(3.0)),
+                (6, TrackValueAtFrame::Float(0.0)),
+                (8, TrackValueAtFrame::Float(0.0)),
+            ],
+            values.decimate(0.0001)
+        );
+    }
+
+    #[test]
+    fn decimate_float_within_error() {
+        let values = TrackValues::Float(vec![0.0, 1.05, 2.0, 3.0]);
+        assert_eq!(
+            vec![
+                (0, TrackValueAtFrame::Float(0.0)),
+                (3, TrackValueAtFrame::Float(3.0))
+            ],
+            values.decimate(0.1)
+        );
+        assert_eq!(vec![0, 1, 2, 3], values.decimated_frames(0.01));
+    }
+
+    #[test]
+    fn decimate_vector4_components() {
+        // Each component needs a different interior frame.
+        let values = TrackValues::Vector4(vec![
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+            Vector4::new(1.0, 1.0, 0.0, 1.0),
+            Vector4::new(2.0, 0.0, 0.0, 1.0),
+            Vector4::new(3.0, 0.0, 1.0, 1.0),
+            Vector4::new(4.0, 0.0, 0.0, 1.0),
+            Vector4::new(5.0, 0.0, 0.0, 1.0),
+        ]);
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], values.decimated_frames(0.0001));
+
+        // Only the z component has a peak.
+        let values = TrackValues::Vector4(vec![
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+            Vector4::new(1.0, 0.0, 1.0, 1.0),
+            Vector4::new(2.0, 0.0, 2.0, 1.0),
+            Vector4::new(3.0, 0.0, 1.0, 1.0),
+            Vector4::new(4.0, 0.0, 0.0, 1.0),
+        ]);
+        assert_eq!(
+            vec![
+                (
+                    0,
+                    TrackValueAtFrame::Vector4(Vector4::new(0.0, 0.0, 0.0, 1.0))
+                ),
+                (
+                    2,
+                    TrackValueAtFrame::Vector4(Vector4::new(2.0, 0.0, 2.0, 1.0))
+                ),
+                (
+                    4,
+                    TrackValueAtFrame::Vector4(Vector4::new(4.0, 0.0, 0.0, 1.0))
+                ),
+            ],
+            values.decimate(0.0001)
+        );
+    }
+
+    #[test]
+    fn decimate_other_values_every_frame() {
+        let values = TrackValues::Boolean(vec![true, true, true]);
+        assert_eq!(
+            vec![
+                (0, TrackValueAtFrame::Boolean(true)),
+                (1, TrackValueAtFrame::Boolean(true)),
+                (2, TrackValueAtFrame::Boolean(true)),
+            ],
+            values.decimate(1.0)
+        );
+        assert_eq!(vec![0, 1, 2], values.decimated_frames(1.0));
+        assert!(TrackValues::Float(Vec::new())
+            .decimated_frames(1.0)
+            .is_empty());
+    }
+
+    #[test]
+    fn to_matrices_transform() {
+        let transforms = vec![
+            Transform {
+                scale: Vector3::new(1.0, 2.0, 3.0),
+                rotation: Vector4::new(0.0, 0.0, 0.0, 1.0),
+                translation: Vector3::new(4.0, 5.0, 6.0),
+            },
+            Transform {
+                scale: Vector3::new(2.0, 2.0, 2.0),
+                // 90 degrees about the z-axis.
+                rotation: Vector4::new(0.0, 0.0, 0.70710677, 0.70710677),
+                translation: Vector3::new(-1.0, 0.0, 1.0),
+            },
+        ];
+
+        let matrices = TrackValues::Transform(transforms.clone())
+            .to_matrices()
+            .unwrap();
+        assert_eq!(2, matrices.len());
+
+        for (transform, matrix) in transforms.iter().zip(matrices) {
+            let expected = glam::Mat4::from_translation(transform.translation.to_array().into())
+                * glam::Mat4::from_quat(glam::Quat::from_array(transform.rotation.to_array()))
+                * glam::Mat4::from_scale(transform.scale.to_array().into());
+            assert!(expected.abs_diff_eq(
+                glam::Mat4::from_cols_array_2d(&matrix.to_cols_array()),
+                1e-6
+            ));
+        }
+    }
+
+    #[test]
+    fn to_matrices_non_transform() {
+        assert_eq!(None, TrackValues::Float(vec![1.0, 2.0]).to_matrices());
+        assert_eq!(None, TrackValues::Boolean(vec![true]).to_matrices());
+    }
+
+    #[test]
+    fn mirror_transform_tracks() {
+        let transform = Transform {
+            scale: Vector3::new(1.0, 2.0, 3.0),
+            rotation: Vector4::new(0.1, 0.2, 0.3, 0.9),
+            translation: Vector3::new(4.0, 5.0, 6.0),
+        };
+
+        let mut values = TrackValues::Transform(vec![transform; 2]);
+        values.mirror_x();
+        assert_eq!(
+            TrackValues::Transform(vec![
+                Transform {
+                    scale: Vector3::new(1.0, 2.0, 3.0),
+                    rotation: Vector4::new(0.1, -0.2, -0.3, 0.9),
+                    translation: Vector3::new(-4.0, 5.0, 6.0),
+                };
+                2
+            ]),
+            values
+        );
+
+        let mut values = TrackValues::Transform(vec![transform]);
+        values.mirror_y();
+        assert_eq!(
+            TrackValues::Transform(vec![Transform {
+                scale: Vector3::new(1.0, 2.0, 3.0),
+                rotation: Vector4::new(-0.1, 0.2, -0.3, 0.9),
+                translation: Vector3::new(4.0, -5.0, 6.0),
+            }]),
+            values
+        );
+
+        let mut values = TrackValues::Transform(vec![transform]);
+        values.mirror_z();
+        assert_eq!(
+            TrackValues::Transform(vec![Transform {
+                scale: Vector3::new(1.0, 2.0, 3.0),
+                rotation: Vector4::new(-0.1, -0.2, 0.3, 0.9),
+                translation: Vector3::new(4.0, 5.0, -6.0),
+            }]),
+            values
+        );
+    }
+
+    #[test]
+    fn mirror_non_transform_track() {
+        let mut values = TrackValues::Float(vec![1.0, -2.0]);
+        values.mirror_x();
+        assert_eq!(TrackValues::Float(vec![1.0, -2.0]), values);
+    }
+
+    #[test]
+    fn append_float_tracks() {
+        let mut values = TrackValues::Float(vec![0.5, 1.0]);
+        values.append(&TrackValues::Float(vec![1.5, 2.0])).unwrap();
+        assert_eq!(TrackValues::Float(vec![0.5, 1.0, 1.5, 2.0]), values);
+    }
+
+    #[test]
+    fn append_mismatched_tracks() {
+        let mut values = TrackValues::Float(vec![0.5]);
+        let result = values.append(&TrackValues::PatternIndex(vec![1, 2]));
+        assert!(matches!(
+            result,
+            Err(error::Error::MismatchedTrackTypes {
+                track_type: TrackTypeV2::Float,
+                other: TrackTypeV2::PatternIndex
+            })
+        ));
+        // The original values should be unchanged.
+        assert_eq!(TrackValues::Float(vec![0.5]), values);
+    }
+
+    #[test]
+    fn write_optimal_all_equal_float() {
+        let mut writer = Cursor::new(Vec::new());
+        let compression = TrackValues::Float(vec![1.0; 5])
+            .write_optimal(&mut writer, false)
+            .unwrap();
+
+        assert_eq!(CompressionType::Constant, compression);
+        assert_eq!(&1.0f32.to_le_bytes(), &writer.get_ref()[..]);
+    }
+
+    #[test]
+    fn write_optimal_varying_float() {
+        let mut writer = Cursor::new(Vec::new());
+        let compression = TrackValues::Float(vec![1.0, 2.0])
+            .write_optimal(&mut writer, false)
+            .unwrap();
+
+        assert_eq!(CompressionType::Direct, compression);
+        assert_eq!(8, writer.get_ref().len());
+    }
+
+    #[test]
+    fn preserve_compression_unmodified_track() {
+        // fighter/cloud/motion/body/c00/b00guardon.nuanmb, EyeL, CustomVector31
+        // Recompressing this track changes the compression and compressed values.
+        let data = hex!(
+            04000000 50000300 60000000 08000000
+            0000803f 0000803f 00000000 00000000
+            0000803f 0000803f 00000000 00000000
+            3108ac3d bc74133e 03000000 00000000
+            00000000 00000000 00000000 00000000
+            0000803f 0000803f 3108ac3d 00000000
+            88c6fa
+        );
+        let track = TrackV2 {
+            name: "CustomVector31".into(),
+            flags: TrackFlags {
+                track_type: TrackTypeV2::Vector4,
+                compression_type: CompressionType::Compressed,
+            },
+            frame_count: 8,
+            transform_flags: AnimTransformFlags::new(),
+            data_offset: 0,
+            data_size: data.len() as u64,
+        };
+
+        let track_data = create_track_data_v20(&track, &data, true).unwrap();
+        let mut buffer = Cursor::new(Vec::new());
+        let new_track = create_anim_track_v2(&mut buffer, &track_data).unwrap();
+        assert_eq!(track.flags, new_track.flags);
+        assert_hex_eq!(&data, buffer.get_ref());
+
+        // Tracks without metadata are recompressed.
+        let track_data = create_track_data_v20(&track, &data, false).unwrap();
+        let mut buffer = Cursor::new(Vec::new());
+        create_anim_track_v2(&mut buffer, &track_data).unwrap();
+        assert_ne!(&data[..], &buffer.get_ref()[..]);
+    }
+
+    #[test]
+    fn preserve_compression_modified_track() {
+        let data: Vec<_> = (0..12).flat_map(|i| (i as f32).to_le_bytes()).collect();
+        let track = TrackV2 {
+            name: "CustomFloat0".into(),
+            flags: TrackFlags {
+                track_type: TrackTypeV2::Float,
+                compression_type: CompressionType::Direct,
+            },
+            frame_count: 12,
+            transform_flags: AnimTransformFlags::new(),
+            data_offset: 0,
+            data_size: data.len() as u64,
+        };
+
+        let mut track_data = create_track_data_v20(&track, &data, true).unwrap();
+        track_data.values = TrackValues::Float((0..12).map(|i| i as f32 * 2.0).collect());
+
+        // The original compression type is used even though compression would save space.
+        assert_eq!(
+            CompressionType::Compressed,
+            track_data.values.choose_compression()
+        );
+        let mut buffer = Cursor::new(Vec::new());
+        let new_track = create_anim_track_v2(&mut buffer, &track_data).unwrap();
+        assert_eq!(CompressionType::Direct, new_track.flags.compression_type);
+        assert_eq!(
+            (0..12)
+                .flat_map(|i| (i as f32 * 2.0).to_le_bytes())
+                .collect::<Vec<_>>(),
+            buffer.into_inner()
+        );
+    }
+
     #[test]
     fn read_v20_track_invalid_offset() {
         let result = create_track_data_v20(
@@ -1105,6 +2931,7 @@ mod tests {
                 data_size: 1,
             },
             &[0u8; 4],
+            false,
         );
 
         assert!(matches!(
@@ -1132,6 +2959,7 @@ mod tests {
                 data_size: 1,
             },
             &[0u8; 4],
+            false,
         );
 
         assert!(matches!(
@@ -1159,6 +2987,7 @@ mod tests {
                 data_size: 5,
             },
             &[0u8; 3],
+            false,
         );
 
         assert!(matches!(
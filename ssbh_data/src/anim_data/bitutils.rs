@@ -58,32 +58,35 @@ impl BitReader {
     }
 }
 
-// Assume preallocated sizes for writing bits.
-// This requires storing the current index.
-// TODO: Find an efficient way to do this with just appending.
+// Bits are appended to the end of the buffer.
+// Reserving the capacity up front avoids reallocating without zero filling the buffer.
 pub struct BitWriter {
     bits: BitVec<u8, Lsb0>,
-    index: usize,
 }
 
 impl BitWriter {
-    pub fn new(bits: BitVec<u8, Lsb0>) -> Self {
-        Self { bits, index: 0 }
+    pub fn with_capacity(bit_count: usize) -> Self {
+        Self {
+            bits: BitVec::with_capacity(bit_count),
+        }
     }
 
     pub fn write(&mut self, value: u32, bit_count: usize) {
         // TODO: Errors?
-        self.bits[self.index..self.index + bit_count].store_le(value);
-        self.index += bit_count;
+        self.bits
+            .extend_from_bitslice(&value.view_bits::<Lsb0>()[..bit_count]);
     }
 
     pub fn write_bit(&mut self, value: bool) {
-        // TODO: Errors?
-        *self.bits.get_mut(self.index).unwrap() = value;
-        self.index += 1;
+        self.bits.push(value);
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bits.len()
     }
 
     pub fn into_bytes(self) -> Vec<u8> {
+        // The last byte is padded with zeros.
         self.bits.into_vec()
     }
 }
@@ -129,9 +132,7 @@ mod tests {
 
     #[test]
     fn write_bits() {
-        let mut bits = BitVec::<u8, Lsb0>::new();
-        bits.resize(4, false);
-        let mut writer = BitWriter::new(bits);
+        let mut writer = BitWriter::with_capacity(4);
 
         writer.write_bit(true);
         writer.write_bit(true);
@@ -143,13 +144,28 @@ mod tests {
 
     #[test]
     fn write_u32() {
-        let mut bits = BitVec::<u8, Lsb0>::new();
-        bits.resize(5, false);
-        let mut writer = BitWriter::new(bits);
+        let mut writer = BitWriter::with_capacity(5);
 
         writer.write(25, 5);
         assert_eq!(vec![0b11001], writer.into_bytes());
     }
 
-    // TODO: Support writing past end?
+    #[test]
+    fn write_u32_across_bytes() {
+        let mut writer = BitWriter::with_capacity(15);
+
+        writer.write_bit(true);
+        writer.write(0b10110011101011, 14);
+        assert_eq!(15, writer.bit_len());
+        assert_eq!(vec![0b11010111, 0b1011001], writer.into_bytes());
+    }
+
+    #[test]
+    fn write_zero_bits() {
+        let mut writer = BitWriter::with_capacity(0);
+
+        writer.write(25, 0);
+        assert_eq!(0, writer.bit_len());
+        assert!(writer.into_bytes().is_empty());
+    }
 }
@@ -1,22 +1,22 @@
 use binrw::io::{Cursor, Read, Seek, Write};
-use binrw::{BinRead, BinReaderExt, BinResult};
-use bitvec::prelude::*;
+use binrw::{BinRead, BinReaderExt, BinResult, BinWriterExt, Endian};
 use itertools::Itertools;
 
 use ssbh_write::SsbhWrite;
 
 use ssbh_lib::{
     formats::anim::{CompressionType, TrackFlags},
-    Ptr16, Ptr32, Vector4,
+    Vector4,
 };
 
 use super::{
     bitutils::{BitReader, BitWriter},
-    compression::{
-        CompressedBuffer, CompressedHeader, CompressedTrackData, Compression, CompressionFlags,
-    },
+    compression::{CompressedTrackData, Compression, CompressionFlags},
+};
+use super::{
+    compression::*, error::Error, Endianness, TrackValueAtFrame, TrackValues, Transform,
+    UvTransform,
 };
-use super::{compression::*, error::Error, TrackValues, Transform, UvTransform};
 
 impl TrackValues {
     pub(crate) fn write<W: Write + Seek>(
@@ -25,6 +25,40 @@ impl TrackValues {
         compression: CompressionType,
         compensate_scale: bool,
     ) -> Result<(), Error> {
+        self.write_with_endian(writer, compression, compensate_scale, Endianness::Little)
+    }
+
+    /// Writes the values with the given `compression` and byte order of `endian`.
+    /// The data can be read again using [read_track_values_with_endian] with the same `endian`.
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{read_track_values_with_endian, Endianness, TrackValues};
+    # use ssbh_lib::formats::anim::{CompressionType, TrackFlags, TrackTypeV2};
+    let values = TrackValues::Float(vec![0.5, 1.0, 1.5]);
+
+    let mut writer = std::io::Cursor::new(Vec::new());
+    values
+        .write_with_endian(&mut writer, CompressionType::Direct, false, Endianness::Big)
+        .unwrap();
+
+    let flags = TrackFlags {
+        track_type: TrackTypeV2::Float,
+        compression_type: CompressionType::Direct,
+    };
+    let (new_values, _) =
+        read_track_values_with_endian(writer.get_ref(), flags, 3, Endianness::Big).unwrap();
+    assert_eq!(values, new_values);
+    ```
+     */
+    pub fn write_with_endian<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        compression: CompressionType,
+        compensate_scale: bool,
+        endian: Endianness,
+    ) -> Result<(), Error> {
+        let endian = endian.into();
+
         // TODO: Find a way to simplify calculating the default and compression.
         // TODO: Find a way to clean up this code.
         // The default depends on the values.
@@ -48,24 +82,26 @@ impl TrackValues {
                             .collect_vec(),
                         flags,
                         compensate_scale,
+                        endian,
                     )?,
                     TrackValues::UvTransform(values) => {
-                        write_compressed(writer, values, flags, compensate_scale)?
+                        write_compressed(writer, values, flags, compensate_scale, endian)?
                     }
                     TrackValues::Float(values) => {
-                        write_compressed(writer, values, flags, compensate_scale)?
+                        write_compressed(writer, values, flags, compensate_scale, endian)?
                     }
                     TrackValues::PatternIndex(values) => {
-                        write_compressed(writer, values, flags, compensate_scale)?
+                        write_compressed(writer, values, flags, compensate_scale, endian)?
                     }
                     TrackValues::Boolean(values) => write_compressed(
                         writer,
                         &values.iter().map(Boolean::from).collect_vec(),
                         flags,
                         compensate_scale,
+                        endian,
                     )?,
                     TrackValues::Vector4(values) => {
-                        write_compressed(writer, values, flags, compensate_scale)?
+                        write_compressed(writer, values, flags, compensate_scale, endian)?
                     }
                 }
             }
@@ -75,22 +111,90 @@ impl TrackValues {
                         .iter()
                         .map(|t| UncompressedTransform::from_transform(t, compensate_scale))
                         .collect();
-                    values.write(writer)?;
+                    writer.write_type(&values, endian)?;
                 }
-                TrackValues::UvTransform(values) => values.write(writer)?,
-                TrackValues::Float(values) => values.write(writer)?,
-                TrackValues::PatternIndex(values) => values.write(writer)?,
+                TrackValues::UvTransform(values) => writer.write_type(values, endian)?,
+                TrackValues::Float(values) => writer.write_type(values, endian)?,
+                TrackValues::PatternIndex(values) => writer.write_type(values, endian)?,
                 TrackValues::Boolean(values) => {
                     let values: Vec<Boolean> = values.iter().map(Boolean::from).collect();
-                    values.write(writer)?;
+                    writer.write_type(&values, endian)?;
                 }
-                TrackValues::Vector4(values) => values.write(writer)?,
+                TrackValues::Vector4(values) => writer.write_type(values, endian)?,
             },
         }
 
         Ok(())
     }
 
+    /// Estimates the size in bytes of the track data when written with `compression`
+    /// without writing any data.
+    ///
+    /// Compressed tracks include the compressed header, default value, compression, and bits for each frame.
+    /// Other compression types write every value uncompressed.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{CompressionType, TrackValues};
+    let values = TrackValues::Float(vec![0.5, 1.0, 1.5]);
+    assert_eq!(12, values.estimated_size(CompressionType::Direct));
+    ```
+     */
+    pub fn estimated_size(&self, compression: CompressionType) -> u64 {
+        match compression {
+            CompressionType::Compressed => {
+                let flags = CompressionFlags::from_track(self);
+                let bits_per_entry = match self {
+                    TrackValues::Transform(values) => compressed_bit_count(
+                        &values
+                            .iter()
+                            .map(|t| UncompressedTransform::from_transform(t, false))
+                            .collect_vec(),
+                        flags,
+                    ),
+                    TrackValues::UvTransform(values) => compressed_bit_count(values, flags),
+                    TrackValues::Float(values) => compressed_bit_count(values, flags),
+                    TrackValues::PatternIndex(values) => compressed_bit_count(values, flags),
+                    TrackValues::Boolean(values) => {
+                        compressed_bit_count(&values.iter().map(Boolean::from).collect_vec(), flags)
+                    }
+                    TrackValues::Vector4(values) => compressed_bit_count(values, flags),
+                };
+
+                // The compressed buffer is padded to a whole number of bytes.
+                let buffer_size = (self.len() as u64 * bits_per_entry).div_ceil(8);
+                self.compressed_overhead_in_bytes() + buffer_size
+            }
+            _ => self.len() as u64 * self.data_size_in_bytes(),
+        }
+    }
+
+    /// Returns the [estimated_size](TrackValues::estimated_size) in bytes for
+    /// [CompressionType::Direct] and [CompressionType::Compressed] as `(direct, compressed)`.
+    ///
+    /// [CompressionType::Compressed] has a fixed overhead for the header, default value, and compression,
+    /// so tracks with only a few frames are often smaller using [CompressionType::Direct].
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{CompressionType, TrackValues};
+    let values = TrackValues::Float((0..100).map(|i| i as f32).collect());
+    let (direct, compressed) = values.compare_sizes();
+    let compression = if compressed < direct {
+        CompressionType::Compressed
+    } else {
+        CompressionType::Direct
+    };
+    assert_eq!(CompressionType::Compressed, compression);
+    ```
+     */
+    pub fn compare_sizes(&self) -> (u64, u64) {
+        (
+            self.estimated_size(CompressionType::Direct),
+            self.estimated_size(CompressionType::Compressed),
+        )
+    }
+
     // HACK: Use default since SsbhWrite expects self for size in bytes.
     pub(crate) fn compressed_overhead_in_bytes(&self) -> u64 {
         match self {
@@ -119,30 +223,133 @@ impl TrackValues {
     }
 }
 
+fn compressed_bit_count<T: CompressedData>(values: &[T], flags: CompressionFlags) -> u64 {
+    let (_, compression) = T::get_default_and_compression(values, false);
+    compression.bit_count(flags)
+}
+
 fn write_compressed<W: Write + Seek, T: CompressedData>(
     writer: &mut W,
     values: &[T],
     flags: CompressionFlags,
     compensate_scale: bool,
-) -> Result<(), std::io::Error> {
+    endian: Endian,
+) -> BinResult<()> {
     let (default, compression) = T::get_default_and_compression(values, compensate_scale);
+    write_compressed_with_compression(writer, values, default, compression, flags, endian)
+}
+
+/// Compresses `values` as a compressed float track using the range and bit count from `compression`
+/// instead of calculating the compression from the values.
+/// Values outside the range are clamped to `compression.min` and `compression.max`.
+/**
+```rust
+# use ssbh_data::anim_data::{write_compressed_floats, F32Compression};
+let compression = F32Compression::with_explicit_range(0.0, 1.0, 8).unwrap();
+let mut writer = std::io::Cursor::new(Vec::new());
+write_compressed_floats(&mut writer, &[0.0, 0.5, 1.5], compression).unwrap();
+```
+ */
+pub fn write_compressed_floats<W: Write + Seek>(
+    writer: &mut W,
+    values: &[f32],
+    compression: F32Compression,
+) -> Result<(), std::io::Error> {
+    let (default, _) = f32::get_default_and_compression(values, false);
+    write_compressed_with_compression(
+        writer,
+        values,
+        default,
+        compression,
+        CompressionFlags::new(),
+        Endian::Little,
+    )
+    .map_err(into_io_error)
+}
+
+/// Compresses `values` as a compressed vector track using the ranges and bit counts from `compression`
+/// instead of calculating the compression from the values.
+/// See [Vector4Compression::from_ranges_with_bits] for setting the bit count of each component.
+pub fn write_compressed_vector4s<W: Write + Seek>(
+    writer: &mut W,
+    values: &[Vector4],
+    compression: Vector4Compression,
+) -> Result<(), std::io::Error> {
+    let (default, _) = Vector4::get_default_and_compression(values, false);
+    write_compressed_with_compression(
+        writer,
+        values,
+        default,
+        compression,
+        CompressionFlags::new(),
+        Endian::Little,
+    )
+    .map_err(into_io_error)
+}
 
+/// Compresses `values` by storing each value as a raw IEEE 754 half precision float
+/// instead of a value normalized to a range.
+/// The data can be read again using [read_compressed_half_floats].
+/**
+```rust
+# use ssbh_data::anim_data::{read_compressed_half_floats, write_compressed_half_floats};
+let mut writer = std::io::Cursor::new(Vec::new());
+write_compressed_half_floats(&mut writer, &[0.0, -1.5]).unwrap();
+assert_eq!(
+    vec![0.0, -1.5],
+    read_compressed_half_floats(writer.get_ref(), 2).unwrap()
+);
+```
+ */
+#[cfg(feature = "half_compression")]
+pub fn write_compressed_half_floats<W: Write + Seek>(
+    writer: &mut W,
+    values: &[f32],
+) -> Result<(), std::io::Error> {
+    let values: Vec<_> = values.iter().copied().map(HalfFloat).collect();
+    write_compressed(
+        writer,
+        &values,
+        CompressionFlags::new(),
+        false,
+        Endian::Little,
+    )
+    .map_err(into_io_error)
+}
+
+/// Reads `frame_count` values written by [write_compressed_half_floats].
+#[cfg(feature = "half_compression")]
+pub fn read_compressed_half_floats(data: &[u8], frame_count: usize) -> Result<Vec<f32>, Error> {
+    let values: Vec<HalfFloat> =
+        read_compressed(&mut Cursor::new(data), frame_count, Endian::Little)?;
+    Ok(values.into_iter().map(|v| v.0).collect())
+}
+
+fn write_compressed_with_compression<W: Write + Seek, T: CompressedData>(
+    writer: &mut W,
+    values: &[T],
+    default: T,
+    compression: T::Compression,
+    flags: CompressionFlags,
+    endian: Endian,
+) -> BinResult<()> {
     let compressed_data = create_compressed_buffer(values, &compression, flags);
 
-    let data = CompressedTrackData::<T> {
-        header: CompressedHeader::<T> {
-            unk_4: 4,
-            flags,
-            default_data: Ptr16::new(default),
-            bits_per_entry: compression.bit_count(flags) as u16, // TODO: This might overflow.
-            compressed_data: Ptr32::new(CompressedBuffer(compressed_data)),
-            frame_count: values.len() as u32,
-        },
+    let data = CompressedTrackData::<T>::new(
+        default,
         compression,
-    };
-    data.write(writer)?;
+        compressed_data,
+        flags,
+        values.len() as u32,
+    );
+    writer.write_type(&data, endian)
+}
 
-    Ok(())
+fn into_io_error(e: binrw::Error) -> std::io::Error {
+    match e {
+        binrw::Error::Io(e) => e,
+        e => std::io::Error::other(e.to_string()),
+    }
 }
 
 fn create_compressed_buffer<T: CompressedData>(
@@ -150,28 +357,32 @@ fn create_compressed_buffer<T: CompressedData>(
     compression: &T::Compression,
     flags: CompressionFlags,
 ) -> Vec<u8> {
-    // Construct a single buffer and keep incrementing the bit index.
-    // This essentially creates a bit writer buffered with u8 elements.
     // We already know the exact size, so there's no need to reallocate.
-    // TODO: Can we reserve size and append instead?
-    let mut bits = BitVec::<u8, Lsb0>::new();
-    bits.resize(values.len() * compression.bit_count(flags) as usize, false);
-    let mut writer = BitWriter::new(bits);
+    // Each value writes exactly bit_count bits, so the buffer doesn't need to be zero filled.
+    // Components with a bit count of 0 are skipped entirely when compressing.
+    let bit_count = values.len() * compression.bit_count(flags) as usize;
+    let mut writer = BitWriter::with_capacity(bit_count);
 
     for v in values {
         v.compress(&mut writer, compression, flags);
     }
+    debug_assert_eq!(bit_count, writer.bit_len());
 
     writer.into_bytes()
 }
 
+// The frame count is read from the file and may be much larger than the actual data.
+// Limit the initial reservation to avoid allocating gigabytes for malformed frame counts.
+const MAX_RESERVED_FRAMES: usize = 0x10000;
+
 fn read_uncompressed<R: Read + Seek, T: BinRead<Args = ()>>(
     reader: &mut R,
     frame_count: usize,
+    endian: Endian,
 ) -> BinResult<Vec<T>> {
-    let mut values = Vec::new();
+    let mut values = Vec::with_capacity(frame_count.min(MAX_RESERVED_FRAMES));
     for _ in 0..frame_count {
-        let value: T = reader.read_le()?;
+        let value: T = reader.read_type(endian)?;
         values.push(value);
     }
     Ok(values)
@@ -181,6 +392,18 @@ pub fn read_track_values(
     track_data: &[u8],
     flags: TrackFlags,
     count: usize,
+) -> Result<(TrackValues, bool), Error> {
+    read_track_values_with_endian(track_data, flags, count, Endianness::Little)
+}
+
+/// Reads `count` values from `track_data` using the byte order of `endian`
+/// and returns the values and whether scale compensation is enabled.
+/// See [TrackValues::write_with_endian] for writing the data.
+pub fn read_track_values_with_endian(
+    track_data: &[u8],
+    flags: TrackFlags,
+    count: usize,
+    endian: Endianness,
 ) -> Result<(TrackValues, bool), Error> {
     // TODO: Are Const, ConstTransform, and Direct all the same?
     // TODO: Can frame count be higher than 1 for Const and ConstTransform?
@@ -188,23 +411,28 @@ pub fn read_track_values(
     use crate::anim_data::TrackTypeV2 as TrackTy;
     use crate::anim_data::TrackValues as Values;
 
+    let endian = endian.into();
     let mut reader = Cursor::new(track_data);
 
     let (values, compensate_scale) = match flags.compression_type {
         CompressionType::Compressed => match flags.track_type {
             TrackTy::Transform => {
                 // TODO: Is there a cleaner way to get the scale inheritance information?
-                let (values, compensate_scale) = read_compressed_transforms(&mut reader, count)?;
+                let (values, compensate_scale) =
+                    read_compressed_transforms(&mut reader, count, endian)?;
                 let values = values.iter().map(Transform::from).collect();
                 (Values::Transform(values), compensate_scale)
             }
             TrackTy::UvTransform => (
-                Values::UvTransform(read_compressed(&mut reader, count)?),
+                Values::UvTransform(read_compressed(&mut reader, count, endian)?),
+                false,
+            ),
+            TrackTy::Float => (
+                Values::Float(read_compressed(&mut reader, count, endian)?),
                 false,
             ),
-            TrackTy::Float => (Values::Float(read_compressed(&mut reader, count)?), false),
             TrackTy::PatternIndex => (
-                Values::PatternIndex(read_compressed(&mut reader, count)?),
+                Values::PatternIndex(read_compressed(&mut reader, count, endian)?),
                 false,
             ),
             TrackTy::Boolean => {
@@ -212,17 +440,21 @@ pub fn read_track_values(
                 // TODO: Create a separate UncompressedData trait?
                 // i.e. CompressedData: UncompressedData
                 // This may be able to simplify the conversion logic for bool and Transform.
-                let values: Vec<Boolean> = read_compressed(&mut reader, count)?;
+                let values: Vec<Boolean> = read_compressed(&mut reader, count, endian)?;
                 (
                     Values::Boolean(values.iter().map(bool::from).collect()),
                     false,
                 )
             }
-            TrackTy::Vector4 => (Values::Vector4(read_compressed(&mut reader, count)?), false),
+            TrackTy::Vector4 => (
+                Values::Vector4(read_compressed(&mut reader, count, endian)?),
+                false,
+            ),
         },
         _ => match flags.track_type {
             TrackTy::Transform => {
-                let values: Vec<UncompressedTransform> = read_uncompressed(&mut reader, count)?;
+                let values: Vec<UncompressedTransform> =
+                    read_uncompressed(&mut reader, count, endian)?;
                 // TODO: This should be an error if the values aren't all the same.
                 let compensate_scale = values
                     .iter()
@@ -235,23 +467,26 @@ pub fn read_track_values(
                 )
             }
             TrackTy::UvTransform => (
-                Values::UvTransform(read_uncompressed(&mut reader, count)?),
+                Values::UvTransform(read_uncompressed(&mut reader, count, endian)?),
+                false,
+            ),
+            TrackTy::Float => (
+                Values::Float(read_uncompressed(&mut reader, count, endian)?),
                 false,
             ),
-            TrackTy::Float => (Values::Float(read_uncompressed(&mut reader, count)?), false),
             TrackTy::PatternIndex => (
-                Values::PatternIndex(read_uncompressed(&mut reader, count)?),
+                Values::PatternIndex(read_uncompressed(&mut reader, count, endian)?),
                 false,
             ),
             TrackTy::Boolean => {
-                let values = read_uncompressed(&mut reader, count)?;
+                let values = read_uncompressed(&mut reader, count, endian)?;
                 (
                     Values::Boolean(values.iter().map(bool::from).collect_vec()),
                     false,
                 )
             }
             TrackTy::Vector4 => (
-                Values::Vector4(read_uncompressed(&mut reader, count)?),
+                Values::Vector4(read_uncompressed(&mut reader, count, endian)?),
                 false,
             ),
         },
@@ -260,11 +495,103 @@ pub fn read_track_values(
     Ok((values, compensate_scale))
 }
 
+/// Reads the values from `track_data` and writes them again with the same compression type.
+/// Known good track data should be unchanged, so `roundtrip_bytes(data, flags, count)? == data`.
+///
+/// Compressed data may still differ from the input since the compression settings
+/// are calculated from the values when writing.
+pub fn roundtrip_bytes(
+    track_data: &[u8],
+    flags: TrackFlags,
+    count: usize,
+) -> Result<Vec<u8>, Error> {
+    let (values, compensate_scale) = read_track_values(track_data, flags, count)?;
+
+    let mut writer = Cursor::new(Vec::new());
+    values.write(&mut writer, flags.compression_type, compensate_scale)?;
+    Ok(writer.into_inner())
+}
+
+/// Reads the default value from the compressed header of `track_data` without decompressing any frames.
+/// This is the value used for components with a bit count of 0.
+///
+/// Uncompressed tracks don't have a compressed header,
+/// so the returned values contain the first frame instead.
+pub fn read_compression_default(
+    track_data: &[u8],
+    flags: TrackFlags,
+) -> Result<TrackValues, Error> {
+    use crate::anim_data::TrackTypeV2 as TrackTy;
+    use crate::anim_data::TrackValues as Values;
+
+    if flags.compression_type != CompressionType::Compressed {
+        let (values, _) = read_track_values(track_data, flags, 1)?;
+        return Ok(values);
+    }
+
+    let mut reader = Cursor::new(track_data);
+
+    let values = match flags.track_type {
+        TrackTy::Transform => {
+            let value: UncompressedTransform = read_compressed_default(&mut reader)?;
+            Values::Transform(vec![Transform::from(&value)])
+        }
+        TrackTy::UvTransform => Values::UvTransform(vec![read_compressed_default(&mut reader)?]),
+        TrackTy::Float => Values::Float(vec![read_compressed_default(&mut reader)?]),
+        TrackTy::PatternIndex => Values::PatternIndex(vec![read_compressed_default(&mut reader)?]),
+        TrackTy::Boolean => {
+            let value: Boolean = read_compressed_default(&mut reader)?;
+            Values::Boolean(vec![value.into()])
+        }
+        TrackTy::Vector4 => Values::Vector4(vec![read_compressed_default(&mut reader)?]),
+    };
+
+    Ok(values)
+}
+
+/// Returns `true` if the compressed header of `track_data` stores a single uniform scale value
+/// instead of separate values for each scale component.
+///
+/// Only compressed [TrackValues::Transform] and [TrackValues::UvTransform] tracks can use uniform scale,
+/// so this returns `false` for other tracks without reading any data.
+pub fn read_uniform_scale(track_data: &[u8], flags: TrackFlags) -> Result<bool, Error> {
+    use crate::anim_data::TrackTypeV2 as TrackTy;
+
+    if flags.compression_type != CompressionType::Compressed {
+        return Ok(false);
+    }
+
+    let mut reader = Cursor::new(track_data);
+
+    let compression_flags = match flags.track_type {
+        TrackTy::Transform => {
+            let data: CompressedTrackData<UncompressedTransform> = reader.read_le()?;
+            data.header.flags
+        }
+        TrackTy::UvTransform => {
+            let data: CompressedTrackData<UvTransform> = reader.read_le()?;
+            data.header.flags
+        }
+        _ => return Ok(false),
+    };
+
+    Ok(compression_flags.uniform_scale())
+}
+
+fn read_compressed_default<R: Read + Seek, T: CompressedData>(reader: &mut R) -> Result<T, Error> {
+    let mut data: CompressedTrackData<T> = reader.read_le()?;
+    data.header
+        .default_data
+        .take()
+        .ok_or(Error::MalformedCompressionHeader)
+}
+
 fn read_compressed<R: Read + Seek, T: CompressedData>(
     reader: &mut R,
     frame_count: usize,
+    endian: Endian,
 ) -> Result<Vec<T>, Error> {
-    let data: CompressedTrackData<T> = reader.read_le()?;
+    let data: CompressedTrackData<T> = reader.read_type(endian)?;
     let values = read_compressed_inner(data, frame_count)?;
     Ok(values)
 }
@@ -273,59 +600,189 @@ fn read_compressed_inner<T: CompressedData>(
     data: CompressedTrackData<T>,
     frame_count: usize,
 ) -> Result<Vec<T>, Error> {
-    // Check for unexpected compression flags.
-    // This is either an unresearched flag or an improperly compressed file.
-    let expected_bit_count = data.compression.bit_count(data.header.flags) as usize;
-    if data.header.bits_per_entry as usize != expected_bit_count {
-        return Err(Error::UnexpectedBitCount {
-            expected: expected_bit_count,
-            actual: data.header.bits_per_entry as usize,
-        });
+    let frames = CompressedFrames::new(data, frame_count)?;
+
+    let mut values = Vec::with_capacity(frames.remaining.min(MAX_RESERVED_FRAMES));
+    for value in frames {
+        values.push(value?);
     }
+    Ok(values)
+}
 
-    let buffer = &data
-        .header
-        .compressed_data
-        .as_ref()
-        .ok_or(Error::MalformedCompressionHeader)?
-        .0;
+/// Decompresses a single frame at a time from the compressed bit buffer.
+struct CompressedFrames<T: CompressedData> {
+    data: CompressedTrackData<T>,
+    reader: BitReader,
+    remaining: usize,
+    frame: usize,
+}
 
-    // Decompress values.
-    let mut reader = BitReader::from_slice(buffer);
+impl<T: CompressedData> CompressedFrames<T> {
+    fn new(data: CompressedTrackData<T>, frame_count: usize) -> Result<Self, Error> {
+        // Check for unexpected compression flags.
+        // This is either an unresearched flag or an improperly compressed file.
+        let expected_bit_count = T::expected_bit_count(&data.header, &data.compression) as usize;
+        if data.header.bits_per_entry as usize != expected_bit_count {
+            return Err(Error::UnexpectedBitCount {
+                expected: expected_bit_count,
+                actual: data.header.bits_per_entry as usize,
+            });
+        }
 
-    // Encode a repeated value as a single "frame".
-    // TODO: Investigate the side effects of forcing uncompressed on save.
-    // This prevents a potential out of memory or lengthy loop.
-    // This case doesn't occur in any of Smash Ultimate's game files.
-    let actual_count = if expected_bit_count == 0 && frame_count > 0 {
-        1
-    } else {
-        frame_count
-    };
+        let buffer = &data
+            .header
+            .compressed_data
+            .as_ref()
+            .ok_or(Error::MalformedCompressionHeader)?
+            .0;
+
+        if frame_count == 0 && !buffer.is_empty() {
+            return Err(Error::UnexpectedCompressedData { size: buffer.len() });
+        }
+
+        data.header
+            .default_data
+            .as_ref()
+            .ok_or(Error::MalformedCompressionHeader)?;
+
+        // Decompress values.
+        let reader = BitReader::from_slice(buffer);
+
+        // Encode a repeated value as a single "frame".
+        // TODO: Investigate the side effects of forcing uncompressed on save.
+        // This prevents a potential out of memory or lengthy loop.
+        // This case doesn't occur in any of Smash Ultimate's game files.
+        let remaining = if expected_bit_count == 0 && frame_count > 0 {
+            1
+        } else {
+            frame_count
+        };
+
+        Ok(Self {
+            data,
+            reader,
+            remaining,
+            frame: 0,
+        })
+    }
+}
 
-    let mut values = Vec::new();
-    for _ in 0..actual_count {
+impl<T: CompressedData> Iterator for CompressedFrames<T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // The default is checked when creating the iterator.
+        let default = self.data.header.default_data.as_ref()?;
         let value = T::decompress(
-            &mut reader,
-            &data.compression,
-            data.header
-                .default_data
-                .as_ref()
-                .ok_or(Error::MalformedCompressionHeader)?,
-            T::get_args(&data.header),
-        )?;
+            &mut self.reader,
+            &self.data.compression,
+            default,
+            T::get_args(&self.data.header),
+        );
+        let frame = self.frame;
+        self.frame += 1;
+        Some(value.map_err(|e| Error::DecompressionFailed {
+            frame,
+            field: e.field,
+            source: e.source,
+        }))
+    }
 
-        values.push(value);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
+}
 
-    Ok(values)
+/// Lazily reads the values for each frame from `track_data`.
+/// This avoids allocating the values for all frames at once for long animations.
+///
+/// Reading stops after the first error.
+pub fn track_value_frames(
+    track_data: &[u8],
+    flags: TrackFlags,
+    count: usize,
+) -> impl Iterator<Item = Result<TrackValueAtFrame, Error>> + '_ {
+    use crate::anim_data::TrackTypeV2 as TrackTy;
+    use TrackValueAtFrame as Value;
+
+    let frames: Box<dyn Iterator<Item = Result<TrackValueAtFrame, Error>>> = match flags
+        .compression_type
+    {
+        CompressionType::Compressed => match flags.track_type {
+            TrackTy::Transform => {
+                compressed_frames(track_data, count, |t: UncompressedTransform| {
+                    Value::Transform(Transform::from(&t))
+                })
+            }
+            TrackTy::UvTransform => compressed_frames(track_data, count, Value::UvTransform),
+            TrackTy::Float => compressed_frames(track_data, count, Value::Float),
+            TrackTy::PatternIndex => compressed_frames(track_data, count, Value::PatternIndex),
+            TrackTy::Boolean => compressed_frames(track_data, count, |b: Boolean| {
+                Value::Boolean(bool::from(b))
+            }),
+            TrackTy::Vector4 => compressed_frames(track_data, count, Value::Vector4),
+        },
+        _ => match flags.track_type {
+            TrackTy::Transform => {
+                uncompressed_frames(track_data, count, |t: UncompressedTransform| {
+                    Value::Transform(Transform::from(&t))
+                })
+            }
+            TrackTy::UvTransform => uncompressed_frames(track_data, count, Value::UvTransform),
+            TrackTy::Float => uncompressed_frames(track_data, count, Value::Float),
+            TrackTy::PatternIndex => uncompressed_frames(track_data, count, Value::PatternIndex),
+            TrackTy::Boolean => uncompressed_frames(track_data, count, |b: Boolean| {
+                Value::Boolean(bool::from(b))
+            }),
+            TrackTy::Vector4 => uncompressed_frames(track_data, count, Value::Vector4),
+        },
+    };
+
+    // Stop after the first error to avoid yielding the same error repeatedly.
+    let mut failed = false;
+    frames.take_while(move |frame| {
+        let take = !failed;
+        failed = frame.is_err();
+        take
+    })
+}
+
+fn compressed_frames<T: CompressedData + 'static>(
+    track_data: &[u8],
+    frame_count: usize,
+    f: fn(T) -> TrackValueAtFrame,
+) -> Box<dyn Iterator<Item = Result<TrackValueAtFrame, Error>>> {
+    let frames = Cursor::new(track_data)
+        .read_le::<CompressedTrackData<T>>()
+        .map_err(Error::from)
+        .and_then(|data| CompressedFrames::new(data, frame_count));
+
+    match frames {
+        Ok(frames) => Box::new(frames.map(move |v| v.map(f))),
+        Err(e) => Box::new(std::iter::once(Err(e))),
+    }
+}
+
+fn uncompressed_frames<'a, T: BinRead<Args = ()> + 'a>(
+    track_data: &'a [u8],
+    frame_count: usize,
+    f: fn(T) -> TrackValueAtFrame,
+) -> Box<dyn Iterator<Item = Result<TrackValueAtFrame, Error>> + 'a> {
+    let mut reader = Cursor::new(track_data);
+    Box::new((0..frame_count).map(move |_| reader.read_le::<T>().map(f).map_err(Into::into)))
 }
 
 fn read_compressed_transforms<R: Read + Seek>(
     reader: &mut R,
     frame_count: usize,
+    endian: Endian,
 ) -> Result<(Vec<UncompressedTransform>, bool), Error> {
-    let data: CompressedTrackData<UncompressedTransform> = reader.read_le()?;
+    let data: CompressedTrackData<UncompressedTransform> = reader.read_type(endian)?;
 
     // TODO: What happens if the scale type is ConstUniformScale but the scale is not uniform?
     // TODO: What happens if the scale type is ConstScale or ConstUniformScale but the scale values change?
@@ -348,7 +805,7 @@ mod tests {
     use super::*;
     use crate::{anim_data::Transform, assert_hex_eq};
     use hexlit::hex;
-    use ssbh_lib::{formats::anim::TrackTypeV2, Vector3};
+    use ssbh_lib::{formats::anim::TrackTypeV2, Ptr16, Ptr32, Vector3};
 
     #[test]
     fn read_constant_vector4_single_frame() {
@@ -373,6 +830,88 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn track_value_frames_constant_vector4_single_frame() {
+        // fighter/mario/motion/body/c00/a00wait1.nuanmb, EyeL, CustomVector30
+        let data = hex!(cdcccc3e 0000c03f 0000803f 0000803f);
+        let frames: Vec<_> = track_value_frames(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Vector4,
+                compression_type: CompressionType::Constant,
+            },
+            1,
+        )
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+        assert_eq!(
+            vec![TrackValueAtFrame::Vector4(Vector4::new(0.4, 1.5, 1.0, 1.0))],
+            frames
+        );
+    }
+
+    #[test]
+    fn track_value_frames_compressed_matches_read_track_values() {
+        // stage/kirby_greens/normal/motion/whispy_set/whispy_set_turnblowl3.nuanmb, _sfx_GrdGreensGrassAM1, nfTexture0[0]
+        let data = hex!(
+            // header
+            04000900 60002600 74000000 14000000
+            // scale compression
+            2a8e633e 34a13d3f 0a000000 00000000
+            cdcc4c3e 7a8c623f 0a000000 00000000
+            // rotation compression
+            00000000 00000000 10000000 00000000
+            // translation compression
+            ec51b8be bc7413bd 09000000 00000000
+            a24536be e17a943e 09000000 00000000
+            // default value
+            34a13d3f 7a8c623f 00000000 bc7413bd a24536be
+            // compressed values
+            ffffff1f 80b4931a cfc12071 8de500e6 535555
+        );
+        let flags = TrackFlags {
+            track_type: TrackTypeV2::UvTransform,
+            compression_type: CompressionType::Compressed,
+        };
+
+        let frames: Vec<_> = track_value_frames(&data, flags, 4)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let (values, _) = read_track_values(&data, flags, 4).unwrap();
+        match values {
+            TrackValues::UvTransform(values) => assert_eq!(
+                values
+                    .into_iter()
+                    .map(TrackValueAtFrame::UvTransform)
+                    .collect_vec(),
+                frames
+            ),
+            _ => panic!("Unexpected track type"),
+        }
+    }
+
+    #[test]
+    fn track_value_frames_not_enough_data() {
+        let data = hex!(0000803f 0000803f);
+        let frames: Vec<_> = track_value_frames(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Float,
+                compression_type: CompressionType::Direct,
+            },
+            4,
+        )
+        .collect();
+
+        // Iteration stops after the first error.
+        assert_eq!(3, frames.len());
+        assert!(matches!(frames[0], Ok(TrackValueAtFrame::Float(v)) if v == 1.0));
+        assert!(matches!(frames[1], Ok(TrackValueAtFrame::Float(v)) if v == 1.0));
+        assert!(matches!(frames[2], Err(Error::BinRead(_))));
+    }
+
     #[test]
     fn write_constant_vector4_single_frame() {
         // fighter/mario/motion/body/c00/a00wait1.nuanmb, EyeL, CustomVector30
@@ -867,7 +1406,7 @@ mod tests {
 
         assert_eq!(
             values,
-            read_compressed(&mut Cursor::new(writer.get_ref()), 2).unwrap()
+            read_compressed(&mut Cursor::new(writer.get_ref()), 2, Endian::Little).unwrap()
         );
     }
 
@@ -923,7 +1462,7 @@ mod tests {
 
         assert_eq!(
             values,
-            read_compressed(&mut Cursor::new(writer.get_ref()), 2).unwrap()
+            read_compressed(&mut Cursor::new(writer.get_ref()), 2, Endian::Little).unwrap()
         );
     }
 
@@ -1010,6 +1549,95 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn write_compressed_floats_explicit_range() {
+        let mut writer = Cursor::new(Vec::new());
+        write_compressed_floats(
+            &mut writer,
+            &[-0.5, 0.5, 2.0],
+            F32Compression::with_explicit_range(0.0, 1.0, 8).unwrap(),
+        )
+        .unwrap();
+
+        let data = writer.into_inner();
+        let (values, _) = read_track_values(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Float,
+                compression_type: CompressionType::Compressed,
+            },
+            3,
+        )
+        .unwrap();
+
+        // Values outside the range are clamped.
+        let values = match values {
+            TrackValues::Float(values) => values,
+            _ => panic!("unexpected track type"),
+        };
+        assert_eq!(0.0, values[0]);
+        assert!((values[1] - 0.5).abs() < 1.0 / 255.0);
+        assert_eq!(1.0, values[2]);
+    }
+
+    #[test]
+    fn read_compressed_float_zero_frames_with_data() {
+        // The same data as read_compressed_float_multiple_frames with a frame count of 0.
+        let data = hex!(
+            04000000 20000200 24000000 00000000 // header
+            00000000 00004040 02000000 00000000 // compression
+            00000000                            // default value
+            e403                                // compressed values
+        );
+        let result = read_track_values(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Float,
+                compression_type: CompressionType::Compressed,
+            },
+            0,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::UnexpectedCompressedData { size: 2 })
+        ));
+    }
+
+    #[test]
+    fn read_compressed_vector4_truncated_buffer() {
+        // The same data as read_compressed_vector4_multiple_frames without the final byte.
+        // The 16 remaining bits only contain the 3 bits for the first 5 frames.
+        let data = hex!(
+            // header
+            04000000 50000300 60000000 08000000
+            // xyzw compression
+            0000803f 0000803f 00000000 00000000
+            0000803f 0000803f 00000000 00000000
+            3108ac3d bc74133e 03000000 00000000
+            00000000 00000000 00000000 00000000
+            // default value
+            0000803f 0000803f 3108ac3d 00000000
+            // compressed values
+            88c6
+        );
+        let result = read_track_values(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Vector4,
+                compression_type: CompressionType::Compressed,
+            },
+            8,
+        );
+
+        let error = result.unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::DecompressionFailed { frame: 5, field, .. } if field == "z"
+        ));
+        assert_eq!("Failed to decompress z of frame 5.", error.to_string());
+    }
+
     #[test]
     fn read_constant_float_single_frame() {
         // assist/shovelknight/model/body/c00/model.nuanmb, asf_shovelknight_mat, CustomFloat8
@@ -1026,7 +1654,39 @@ mod tests {
 
         assert_eq!(false, compensate_scale);
 
-        assert!(matches!(values, TrackValues::Float(values) if values == vec![0.4]));
+        assert!(matches!(values, TrackValues::Float(values) if values == vec![0.4]));
+    }
+
+    #[test]
+    fn read_constant_float_huge_frame_count() {
+        // The reservation is capped, so this fails with an error instead of running out of memory.
+        let data = hex!(cdcccc3e);
+        let result = read_track_values(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Float,
+                compression_type: CompressionType::Constant,
+            },
+            0xFFFFFFFF,
+        );
+
+        assert!(matches!(result, Err(Error::BinRead(_))));
+    }
+
+    #[test]
+    fn read_compressed_float_huge_frame_count_capacity() {
+        // The same data as read_compressed_float_all_equal.
+        let data = hex!(
+            04000000 20000000 24000000 FFFFFFFF // header
+            cdcccc3e cdcccc3e 10000000 00000000 // compression
+            cdcccc3e                            // default value
+                                                // compressed values
+        );
+        let values: Vec<f32> =
+            read_compressed(&mut Cursor::new(data), 0xFFFFFFFF, Endian::Little).unwrap();
+
+        assert_eq!(vec![0.4], values);
+        assert!(values.capacity() <= MAX_RESERVED_FRAMES);
     }
 
     #[test]
@@ -1071,6 +1731,46 @@ mod tests {
         assert!(matches!(values, TrackValues::Float(values) if values == vec![0.4]));
     }
 
+    #[test]
+    fn read_compression_default_float_all_equal() {
+        // The same data as read_compressed_float_all_equal.
+        let data = hex!(
+            04000000 20000000 24000000 FFFFFFFF // header
+            cdcccc3e cdcccc3e 10000000 00000000 // compression
+            cdcccc3e                            // default value
+                                                // compressed values
+        );
+        let flags = TrackFlags {
+            track_type: TrackTypeV2::Float,
+            compression_type: CompressionType::Compressed,
+        };
+
+        let default = read_compression_default(&data, flags).unwrap();
+        assert_eq!(TrackValues::Float(vec![0.4]), default);
+
+        let (values, _) = read_track_values(&data, flags, 0xFFFFFFFF).unwrap();
+        assert_eq!(values, default);
+    }
+
+    #[test]
+    fn read_compression_default_constant() {
+        // fighter/mario/motion/body/c00/a00wait1.nuanmb, EyeL, CustomVector30
+        let data = hex!(cdcccc3e 0000c03f 0000803f 0000803f);
+        let default = read_compression_default(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Vector4,
+                compression_type: CompressionType::Constant,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            TrackValues::Vector4(vec![Vector4::new(0.4, 1.5, 1.0, 1.0)]),
+            default
+        );
+    }
+
     #[test]
     fn read_compressed_float_multiple_frames() {
         // pacman/model/body/c00/model.nuanmb, phong3__phong0__S_CUS_0xa2001001___7__AT_GREATER128___VTC__NORMEXP16___CULLNONE_A_AB_SORT, CustomFloat2
@@ -1122,7 +1822,8 @@ mod tests {
 
         assert_eq!(
             values,
-            read_compressed(&mut Cursor::new(writer.get_ref()), 2).unwrap()
+            read_compressed::<_, f32>(&mut Cursor::new(writer.get_ref()), 2, Endian::Little)
+                .unwrap()
         );
     }
 
@@ -1179,6 +1880,31 @@ mod tests {
         assert!(matches!(values, TrackValues::Boolean(values) if values == vec![false]));
     }
 
+    #[test]
+    fn read_compressed_boolean_zero_bit_count() {
+        // This is an edge case that doesn't appear in game.
+        // 0 bits per entry uses the default value for all frames.
+        let data = hex!(
+            04000000 20000000 21000000 03000000 // header
+            00000000 00000000 00000000 00000000 // bool compression (always 0's)
+            01                                  // default value
+                                                // compressed values (bits)
+        );
+        let (values, compensate_scale) = read_track_values(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Boolean,
+                compression_type: CompressionType::Compressed,
+            },
+            3,
+        )
+        .unwrap();
+
+        assert!(!compensate_scale);
+
+        assert!(matches!(values, TrackValues::Boolean(values) if values == vec![true]));
+    }
+
     #[test]
     fn read_compressed_boolean_multiple_frames() {
         // assist/ashley/motion/body/c00/vis.nuanmb, magic, Visibility
@@ -1229,7 +1955,7 @@ mod tests {
 
         assert_eq!(
             vec![Boolean(1)],
-            read_compressed(&mut Cursor::new(writer.get_ref()), 1).unwrap()
+            read_compressed(&mut Cursor::new(writer.get_ref()), 1, Endian::Little).unwrap()
         );
     }
 
@@ -1256,7 +1982,7 @@ mod tests {
 
         assert_eq!(
             vec![Boolean(0), Boolean(1), Boolean(1)],
-            read_compressed(&mut Cursor::new(writer.get_ref()), 3).unwrap()
+            read_compressed(&mut Cursor::new(writer.get_ref()), 3, Endian::Little).unwrap()
         );
     }
 
@@ -1284,7 +2010,7 @@ mod tests {
 
         assert_eq!(
             vec![Boolean(1); 11],
-            read_compressed(&mut Cursor::new(writer.get_ref()), 11).unwrap()
+            read_compressed(&mut Cursor::new(writer.get_ref()), 11, Endian::Little).unwrap()
         );
     }
 
@@ -1375,6 +2101,50 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn write_compressed_vector4_multiple_frames_bits_per_component() {
+        // fighter/cloud/motion/body/c00/b00guardon.nuanmb, EyeL, CustomVector31
+        // Only the z component varies and uses 3 bits.
+        let data = hex!(
+            // header
+            04000000 50000300 60000000 08000000
+            // xyzw compression
+            0000803f 0000803f 00000000 00000000
+            0000803f 0000803f 00000000 00000000
+            3108ac3d bc74133e 03000000 00000000
+            00000000 00000000 00000000 00000000
+            // default value
+            0000803f 0000803f 3108ac3d 00000000
+            // compressed values
+            88c6fa
+        );
+        let values: Vec<Vector4> =
+            read_compressed(&mut Cursor::new(&data), 8, Endian::Little).unwrap();
+
+        let compression = Vector4Compression::from_ranges_with_bits([
+            (1.0, 1.0, 24),
+            (1.0, 1.0, 24),
+            (0.084, 0.144, 3),
+            (0.0, 0.0, 24),
+        ])
+        .unwrap();
+        let mut writer = Cursor::new(Vec::new());
+        write_compressed_vector4s(&mut writer, &values, compression).unwrap();
+
+        // Compressed values are truncated, so only the header, compression, and default match exactly.
+        let new_data = writer.into_inner();
+        assert_hex_eq!(&data[..0x60], &new_data[..0x60]);
+        assert_eq!(data.len(), new_data.len());
+
+        let new_values: Vec<Vector4> =
+            read_compressed(&mut Cursor::new(&new_data), 8, Endian::Little).unwrap();
+        for (value, new_value) in values.iter().zip(&new_values) {
+            assert_eq!((1.0, 1.0, 0.0), (new_value.x, new_value.y, new_value.w));
+            // Truncation loses at most one step of (0.144 - 0.084) / 7.
+            assert!((value.z - new_value.z).abs() < 0.009);
+        }
+    }
+
     #[test]
     fn write_compressed_vector4_multiple_frames() {
         let values = vec![
@@ -1409,8 +2179,175 @@ mod tests {
 
         assert_eq!(
             values,
-            read_compressed(&mut Cursor::new(writer.get_ref()), 2).unwrap()
+            read_compressed(&mut Cursor::new(writer.get_ref()), 2, Endian::Little).unwrap()
+        );
+    }
+
+    #[cfg(feature = "half_compression")]
+    #[test]
+    fn write_read_compressed_half_float() {
+        let values = vec![
+            HalfFloat(0.0),
+            HalfFloat(-1.5),
+            HalfFloat(0.333),
+            HalfFloat(1000.0),
+        ];
+
+        let mut writer = Cursor::new(Vec::new());
+        write_compressed(
+            &mut writer,
+            &values,
+            CompressionFlags::new(),
+            false,
+            Endian::Little,
+        )
+        .unwrap();
+
+        assert_hex_eq!(
+            writer.get_ref(),
+            &hex!(
+                // header
+                04000000 18001000 1C000000 04000000
+                // compression
+                10000000 00000000
+                // default value
+                00000000
+                // compressed values
+                0000 00BE 5435 D063
+            )
+        );
+
+        let new_values: Vec<HalfFloat> =
+            read_compressed(&mut Cursor::new(writer.get_ref()), 4, Endian::Little).unwrap();
+        for (value, new_value) in values.iter().zip(&new_values) {
+            assert_eq!(half::f16::from_f32(value.0).to_f32(), new_value.0);
+        }
+        assert_eq!(-1.5, new_values[1].0);
+
+        let mut writer = Cursor::new(Vec::new());
+        write_compressed_half_floats(&mut writer, &values.iter().map(|v| v.0).collect_vec())
+            .unwrap();
+        assert_eq!(
+            new_values.iter().map(|v| v.0).collect_vec(),
+            read_compressed_half_floats(writer.get_ref(), 4).unwrap()
+        );
+    }
+
+    #[test]
+    fn read_compressed_track_data_new() {
+        // Two frames of 2 bits each with the second float using the default value.
+        let data = CompressedTrackData::<Vector4>::new(
+            Vector4::new(1.0, 2.0, 3.0, 4.0),
+            Vector4Compression {
+                x: F32Compression {
+                    min: 0.0,
+                    max: 3.0,
+                    bit_count: 2,
+                },
+                y: F32Compression::default(),
+                z: F32Compression::default(),
+                w: F32Compression::default(),
+            },
+            vec![0b1100],
+            CompressionFlags::new(),
+            2,
+        );
+        assert_eq!(4, data.header.unk_4);
+        assert_eq!(2, data.header.bits_per_entry);
+        assert_eq!(2, data.header.frame_count);
+
+        assert_eq!(
+            vec![
+                Vector4::new(0.0, 2.0, 3.0, 4.0),
+                Vector4::new(3.0, 2.0, 3.0, 4.0)
+            ],
+            read_compressed_inner(data, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn roundtrip_compressed_vector4_multiple_frames() {
+        let data = hex!(
+            // header
+            04000000 50006000 60000000 02000000
+            // xyzw compression
+            000080BF 0000803F 18000000 00000000
+            000000C0 00000040 18000000 00000000
+            000040C0 00004040 18000000 00000000
+            000080C0 00008040 18000000 00000000
+            // default value
+            000080BF 000000C0 000040C0 000080C0
+            // compressed values
+            000000 000000 000000 000000 FFFFFF FFFFFF FFFFFF FFFFFF
+        );
+
+        assert_hex_eq!(
+            &data,
+            &roundtrip_bytes(
+                &data,
+                TrackFlags {
+                    track_type: TrackTypeV2::Vector4,
+                    compression_type: CompressionType::Compressed,
+                },
+                2
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn estimated_size_compressed_vector4() {
+        let values = TrackValues::Vector4(
+            (0..10)
+                .map(|i| Vector4::new(i as f32, 1.0, -(i as f32), (i % 3) as f32))
+                .collect(),
+        );
+        let mut writer = Cursor::new(Vec::new());
+        values
+            .write(&mut writer, CompressionType::Compressed, false)
+            .unwrap();
+
+        assert_eq!(
+            writer.get_ref().len() as u64,
+            values.estimated_size(CompressionType::Compressed)
+        );
+    }
+
+    #[test]
+    fn estimated_size_direct_transform() {
+        let values = TrackValues::Transform(vec![Transform::IDENTITY; 3]);
+        let mut writer = Cursor::new(Vec::new());
+        values
+            .write(&mut writer, CompressionType::Direct, false)
+            .unwrap();
+
+        assert_eq!(
+            writer.get_ref().len() as u64,
+            values.estimated_size(CompressionType::Direct)
+        );
+    }
+
+    #[test]
+    fn compare_sizes_few_frames() {
+        let values = TrackValues::Vector4(vec![
+            Vector4::new(0.0, 1.0, 2.0, 3.0),
+            Vector4::new(1.0, 2.0, 3.0, 4.0),
+        ]);
+        let (direct, compressed) = values.compare_sizes();
+        assert_eq!(32, direct);
+        assert!(direct < compressed);
+    }
+
+    #[test]
+    fn compare_sizes_many_frames() {
+        let values = TrackValues::Vector4(
+            (0..100)
+                .map(|i| Vector4::new(i as f32, 1.0, -(i as f32), 0.5))
+                .collect(),
         );
+        let (direct, compressed) = values.compare_sizes();
+        assert_eq!(1600, direct);
+        assert!(compressed < direct);
     }
 
     #[test]
@@ -1447,8 +2384,137 @@ mod tests {
 
         assert_eq!(
             values,
-            read_compressed(&mut Cursor::new(writer.get_ref()), 2).unwrap()
+            read_compressed(&mut Cursor::new(writer.get_ref()), 2, Endian::Little).unwrap()
+        );
+    }
+
+    fn write_read_big_endian(values: TrackValues, compression_type: CompressionType) -> Vec<u8> {
+        let track_type = match values {
+            TrackValues::Transform(_) => TrackTypeV2::Transform,
+            TrackValues::UvTransform(_) => TrackTypeV2::UvTransform,
+            TrackValues::Float(_) => TrackTypeV2::Float,
+            TrackValues::PatternIndex(_) => TrackTypeV2::PatternIndex,
+            TrackValues::Boolean(_) => TrackTypeV2::Boolean,
+            TrackValues::Vector4(_) => TrackTypeV2::Vector4,
+        };
+
+        let flags = TrackFlags {
+            track_type,
+            compression_type,
+        };
+
+        // Compression is lossy, so compare with the little endian values.
+        let mut writer = Cursor::new(Vec::new());
+        values.write(&mut writer, compression_type, false).unwrap();
+        let (expected, _) = read_track_values(writer.get_ref(), flags, values.len()).unwrap();
+
+        let mut writer = Cursor::new(Vec::new());
+        values
+            .write_with_endian(&mut writer, compression_type, false, Endianness::Big)
+            .unwrap();
+        let (new_values, _) =
+            read_track_values_with_endian(writer.get_ref(), flags, values.len(), Endianness::Big)
+                .unwrap();
+        assert_eq!(expected, new_values);
+
+        writer.into_inner()
+    }
+
+    #[test]
+    fn write_read_uncompressed_float_big_endian() {
+        let bytes = write_read_big_endian(
+            TrackValues::Float(vec![1.0, -2.0, 0.5]),
+            CompressionType::Direct,
+        );
+        assert_hex_eq!(bytes, hex!(3F800000 C0000000 3F000000));
+    }
+
+    #[test]
+    fn write_read_compressed_float_big_endian() {
+        let bytes = write_read_big_endian(
+            TrackValues::Float(vec![1.0, 2.0, 3.0]),
+            CompressionType::Compressed,
         );
+
+        // Check that the header fields were swapped.
+        assert_eq!(&[0x00, 0x04], &bytes[0..2]);
+        assert_eq!(&[0x00, 0x00, 0x00, 0x03], &bytes[12..16]);
+
+        // The big endian data shouldn't be readable as little endian.
+        assert!(read_track_values(
+            &bytes,
+            TrackFlags {
+                track_type: TrackTypeV2::Float,
+                compression_type: CompressionType::Compressed,
+            },
+            3
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn write_read_big_endian_all_types() {
+        for compression_type in [CompressionType::Direct, CompressionType::Compressed] {
+            write_read_big_endian(
+                TrackValues::Transform(vec![Transform::IDENTITY; 2]),
+                compression_type,
+            );
+            write_read_big_endian(
+                TrackValues::UvTransform(vec![UvTransform::default(); 2]),
+                compression_type,
+            );
+            write_read_big_endian(TrackValues::PatternIndex(vec![1, 2]), compression_type);
+            write_read_big_endian(
+                TrackValues::Boolean(vec![true, false, true]),
+                compression_type,
+            );
+            write_read_big_endian(
+                TrackValues::Vector4(vec![
+                    Vector4::new(0.0, 0.0, 0.0, 0.0),
+                    Vector4::new(1.0, 1.0, 1.0, 1.0),
+                ]),
+                compression_type,
+            );
+        }
+    }
+
+    #[test]
+    fn write_compressed_vector4_10k_frames() {
+        // The constant z component uses 0 bits per frame.
+        let values: Vec<_> = (0..10000)
+            .map(|i| Vector4::new(i as f32 / 100.0, (i % 7) as f32, 1.0, -(i as f32)))
+            .collect();
+
+        let (_, compression) = Vector4::get_default_and_compression(&values, false);
+        let flags = CompressionFlags::new();
+        let bits_per_entry = compression.bit_count(flags) as usize;
+        assert_eq!(72, bits_per_entry);
+
+        let buffer = create_compressed_buffer(&values, &compression, flags);
+        assert_eq!(values.len() * bits_per_entry / 8, buffer.len());
+
+        let mut writer = Cursor::new(Vec::new());
+        TrackValues::write(
+            &TrackValues::Vector4(values.clone()),
+            &mut writer,
+            CompressionType::Compressed,
+            false,
+        )
+        .unwrap();
+
+        let new_values: Vec<Vector4> = read_compressed(
+            &mut Cursor::new(writer.get_ref()),
+            values.len(),
+            Endian::Little,
+        )
+        .unwrap();
+        assert_eq!(values.len(), new_values.len());
+        for (expected, actual) in values.iter().zip(new_values.iter()) {
+            assert!((expected.x - actual.x).abs() < 0.001);
+            assert!((expected.y - actual.y).abs() < 0.001);
+            assert_eq!(expected.z, actual.z);
+            assert!((expected.w - actual.w).abs() < 0.001);
+        }
     }
 
     #[test]
@@ -1758,9 +2824,24 @@ mod tests {
             )
         );
 
+        // The rotations aren't unit quaternions, so reading normalizes the xyz components.
+        let length = (4.0f32 * 4.0 + 5.0 * 5.0 + 6.0 * 6.0).sqrt();
+        let expected = values
+            .iter()
+            .map(|t| Transform {
+                rotation: Vector4::new(
+                    t.rotation.x / length,
+                    t.rotation.y / length,
+                    t.rotation.z / length,
+                    0.0,
+                ),
+                ..*t
+            })
+            .collect_vec();
+
         assert_eq!(
-            values,
-            read_compressed(&mut Cursor::new(writer.get_ref()), 2)
+            expected,
+            read_compressed(&mut Cursor::new(writer.get_ref()), 2, Endian::Little)
                 .unwrap()
                 .iter()
                 .map(Transform::from)
@@ -1976,6 +3057,63 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn read_uniform_scale_compressed_transform() {
+        // The same data as read_compressed_transform_multiple_frames_uniform_scale.
+        let data = hex!(
+            // header
+            04000300 A0000900 CC000000 09000000
+            // scale compression
+            0000003F 0000803F 09000000 00000000
+            0000003F 0000803F 10000000 00000000
+            0000003F 0000803F 10000000 00000000
+            // rotation compression
+            1D13533D 1D13533D 10000000 00000000
+            03BA8ABD 03BA8ABD 10000000 00000000
+            16139BBE 16139BBE 10000000 00000000
+            // translation compression
+            CDCCEC3F CDCCEC3F 10000000 00000000
+            00000000 00000000 10000000 00000000
+            00000000 00000000 10000000 00000000
+            // default value
+            0000803F 0000803F 0000803F
+            1D13533D 03BA8ABD 16139BBE 1500733F
+            CDCCEC3F 00000000 00000000
+            00000000
+            // compressed values
+            FFFFFF37 0F7A2600 003301
+        );
+
+        assert!(read_uniform_scale(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Transform,
+                compression_type: CompressionType::Compressed,
+            }
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn read_uniform_scale_float() {
+        // The same data as read_compressed_float_all_equal.
+        let data = hex!(
+            04000000 20000000 24000000 FFFFFFFF // header
+            cdcccc3e cdcccc3e 10000000 00000000 // compression
+            cdcccc3e                            // default value
+                                                // compressed values
+        );
+
+        assert!(!read_uniform_scale(
+            &data,
+            TrackFlags {
+                track_type: TrackTypeV2::Float,
+                compression_type: CompressionType::Compressed,
+            }
+        )
+        .unwrap());
+    }
+
     #[test]
     fn read_compressed_transform_multiple_frames_const_scale() {
         // fighter/buddy/motion/body/c00/a03jumpsquat.nuanmb", S_Waistbag1, Transform
@@ -2408,9 +3546,24 @@ mod tests {
             )
         );
 
+        // The rotations aren't unit quaternions, so reading normalizes the xyz components.
+        let length = (4.0f32 * 4.0 + 5.0 * 5.0 + 6.0 * 6.0).sqrt();
+        let expected = values
+            .iter()
+            .map(|t| Transform {
+                rotation: Vector4::new(
+                    t.rotation.x / length,
+                    t.rotation.y / length,
+                    t.rotation.z / length,
+                    0.0,
+                ),
+                ..*t
+            })
+            .collect_vec();
+
         assert_eq!(
-            values,
-            read_compressed(&mut Cursor::new(writer.get_ref()), 2)
+            expected,
+            read_compressed(&mut Cursor::new(writer.get_ref()), 2, Endian::Little)
                 .unwrap()
                 .iter()
                 .map(Transform::from)
@@ -1,14 +1,18 @@
-use binrw::{BinRead, BinResult, ReadOptions};
+use binrw::{BinRead, BinResult, BinWrite, ReadOptions, WriteOptions};
 use bitvec::prelude::*;
 use modular_bitfield::prelude::*;
 use std::{
+    convert::TryFrom,
     fmt::Debug,
-    io::{Read, Seek},
+    io::{Read, Seek, Write},
     num::NonZeroU64,
 };
 
 use ssbh_write::SsbhWrite;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use ssbh_lib::{Ptr16, Ptr32, Vector3, Vector4};
 
 use super::{TrackValues, Transform, UvTransform};
@@ -26,12 +30,114 @@ pub type CompressedBits = u32;
 // Use the highest bit count used for Smash Ultimate to avoid quality loss.
 pub const DEFAULT_F32_BIT_COUNT: u64 = 24;
 
+// Higher bit counts can't be represented by CompressedBits.
+pub const MAX_BIT_COUNT: u64 = 32;
+
+/// The header, compression, default value, and compressed bits for a compressed track.
 #[derive(Debug, BinRead, SsbhWrite)]
 pub struct CompressedTrackData<T: CompressedData> {
     pub header: CompressedHeader<T>,
     pub compression: T::Compression,
 }
 
+impl<T: CompressedData> CompressedTrackData<T> {
+    /// Creates compressed track data with a header for `frame_count` frames of `compressed_data`.
+    /// The bits per entry is calculated from `compression` and `flags`.
+    /**
+    ```rust
+    # use ssbh_data::anim_data::{CompressedTrackData, CompressionFlags, F32Compression};
+    let compression = F32Compression::with_explicit_range(0.0, 1.0, 8).unwrap();
+    let data = CompressedTrackData::<f32>::new(
+        0.0,
+        compression,
+        vec![0x00, 0x80, 0xFF],
+        CompressionFlags::new(),
+        3,
+    );
+    assert_eq!(8, data.header.bits_per_entry);
+    ```
+     */
+    pub fn new(
+        default: T,
+        compression: T::Compression,
+        compressed_data: Vec<u8>,
+        flags: CompressionFlags,
+        frame_count: u32,
+    ) -> Self {
+        Self {
+            header: CompressedHeader {
+                unk_4: 4,
+                flags,
+                default_data: Ptr16::new(default),
+                bits_per_entry: compression.bit_count(flags) as u16, // TODO: This might overflow.
+                compressed_data: Ptr32::new(CompressedBuffer(compressed_data)),
+                frame_count,
+            },
+            compression,
+        }
+    }
+}
+
+// SsbhWrite only supports little endian, so write the data with binrw to support both byte orders.
+// The layout matches SsbhWrite with the default and compressed buffer after the compression.
+// Offsets are relative to the start of the track data.
+impl<T: CompressedData> BinWrite for CompressedTrackData<T> {
+    type Args = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions,
+        _: Self::Args,
+    ) -> BinResult<()> {
+        let header = &self.header;
+        let default = header.default_data.as_ref();
+        let buffer = header.compressed_data.as_ref();
+
+        let default_offset = default
+            .map(|_| {
+                self.size_in_bytes()
+                    .next_multiple_of(T::alignment_in_bytes())
+            })
+            .unwrap_or(0);
+        let buffer_offset = buffer
+            .map(|_| default_offset + default.map(|d| d.size_in_bytes()).unwrap_or(0))
+            .unwrap_or(0);
+
+        let write_offset_error = |offset| {
+            binrw::Error::Io(std::io::Error::other(format!(
+                "Failed to convert offset {} for compressed track data.",
+                offset
+            )))
+        };
+
+        header.unk_4.write_options(writer, options, ())?;
+        header.flags.to_raw().write_options(writer, options, ())?;
+        u16::try_from(default_offset)
+            .map_err(|_| write_offset_error(default_offset))?
+            .write_options(writer, options, ())?;
+        header.bits_per_entry.write_options(writer, options, ())?;
+        u32::try_from(buffer_offset)
+            .map_err(|_| write_offset_error(buffer_offset))?
+            .write_options(writer, options, ())?;
+        header.frame_count.write_options(writer, options, ())?;
+        self.compression.write_options(writer, options, ())?;
+
+        if let Some(default) = default {
+            let padding = default_offset - self.size_in_bytes();
+            ssbh_write::write_padding(writer, padding as usize)?;
+            default.write_options(writer, options, ())?;
+        }
+
+        // The compressed bits are stored the same way regardless of byte order.
+        if let Some(buffer) = buffer {
+            writer.write_all(&buffer.0)?;
+        }
+
+        Ok(())
+    }
+}
+
 // TODO: These should be non nullable pointers?
 #[derive(Debug, BinRead, SsbhWrite)]
 pub struct CompressedHeader<T: CompressedData> {
@@ -61,8 +167,13 @@ pub struct CompressedBuffer(#[br(parse_with = read_to_end)] pub Vec<u8>);
 // Missing values are determined based on the compression's default values.
 // TODO: Why is this needed if compression can already set these to defaults?
 #[bitfield(bits = 16)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(from = "CompressionFlagsSerde", into = "CompressionFlagsSerde")
+)]
 #[derive(Debug, BinRead, Clone, Copy, PartialEq, Eq)]
-#[br(map = Self::from_bytes)]
+#[br(map = Self::from_raw)]
 pub struct CompressionFlags {
     pub const_scale: bool,
     pub uniform_scale: bool,
@@ -72,9 +183,78 @@ pub struct CompressionFlags {
     __: B12,
 }
 
+// Serialize the named flags instead of the underlying bytes.
+// The remaining unused bits are not preserved.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct CompressionFlagsSerde {
+    const_scale: bool,
+    uniform_scale: bool,
+    has_rotation: bool,
+    has_translation: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<CompressionFlagsSerde> for CompressionFlags {
+    fn from(f: CompressionFlagsSerde) -> Self {
+        Self::new()
+            .with_const_scale(f.const_scale)
+            .with_uniform_scale(f.uniform_scale)
+            .with_has_rotation(f.has_rotation)
+            .with_has_translation(f.has_translation)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<CompressionFlags> for CompressionFlagsSerde {
+    fn from(f: CompressionFlags) -> Self {
+        Self {
+            const_scale: f.const_scale(),
+            uniform_scale: f.uniform_scale(),
+            has_rotation: f.has_rotation(),
+            has_translation: f.has_translation(),
+        }
+    }
+}
+
 ssbh_write::ssbh_write_modular_bitfield_impl!(CompressionFlags, 2);
 
+impl Default for CompressionFlags {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CompressionFlags {
+    /// Creates flags from the raw little endian value stored in the compressed header.
+    /**
+    ```rust
+    # use ssbh_data::anim_data::CompressionFlags;
+    let flags = CompressionFlags::from_raw(0x0009);
+    assert!(flags.const_scale());
+    assert!(flags.has_translation());
+    assert_eq!(0x0009, flags.to_raw());
+    ```
+     */
+    pub fn from_raw(value: u16) -> Self {
+        Self::from_bytes(value.to_le_bytes())
+    }
+
+    /// Returns the raw value stored in the compressed header.
+    pub fn to_raw(&self) -> u16 {
+        u16::from_le_bytes(self.into_bytes())
+    }
+
+    /// Calculates the flags used when compressing `values`.
+    ///
+    /// For [TrackValues::Transform] and [TrackValues::UvTransform],
+    /// the scale is uniform if the scale components are equal for every frame
+    /// and constant if every frame has the same scale as the first frame.
+    /// Only a single scale component is stored for uniform scale.
+    /// Other track types use the default flags.
+    ///
+    /// Scale compensation for the parent bone is not inferred from the values
+    /// and should be requested explicitly with [ScaleOptions::compensate_scale](super::ScaleOptions).
     pub fn from_track(values: &TrackValues) -> CompressionFlags {
         match values {
             TrackValues::Transform(values) => {
@@ -82,9 +262,7 @@ impl CompressionFlags {
                     .first()
                     .map(|first| values.iter().all(|t| t.scale == first.scale))
                     .unwrap_or_default();
-                let is_uniform = values
-                    .iter()
-                    .all(|t| t.scale.x == t.scale.y && t.scale.y == t.scale.z);
+                let is_uniform = has_uniform_scale(values);
                 CompressionFlags::new()
                     .with_const_scale(is_const)
                     .with_uniform_scale(is_uniform)
@@ -113,8 +291,15 @@ impl CompressionFlags {
     }
 }
 
+// Uniform scale only needs to store a single scale component for each frame.
+fn has_uniform_scale(values: &[Transform]) -> bool {
+    values
+        .iter()
+        .all(|t| t.scale.x == t.scale.y && t.scale.y == t.scale.z)
+}
+
 // Shared logic for compressing track data to and from bits.
-pub trait CompressedData: BinRead<Args = ()> + SsbhWrite + Default {
+pub trait CompressedData: BinRead<Args = ()> + BinWrite<Args = ()> + SsbhWrite + Default {
     type Compression: Compression + std::fmt::Debug;
     type BitStore: BitStore;
     type CompressionArgs;
@@ -131,7 +316,7 @@ pub trait CompressedData: BinRead<Args = ()> + SsbhWrite + Default {
         compression: &Self::Compression,
         default: &Self,
         args: Self::CompressionArgs,
-    ) -> Result<Self, BitReadError>;
+    ) -> Result<Self, DecompressError>;
 
     // The size in bytes for the compressed header, default, and a single frame value.
     fn compressed_overhead_in_bytes() -> u64 {
@@ -148,9 +333,52 @@ pub trait CompressedData: BinRead<Args = ()> + SsbhWrite + Default {
         values: &[Self],
         compensate_scale: bool,
     ) -> (Self, Self::Compression);
+
+    // The bits per entry to expect in the header when reading.
+    fn expected_bit_count(header: &CompressedHeader<Self>, compression: &Self::Compression) -> u64 {
+        compression.bit_count(header.flags)
+    }
+}
+
+/// An error while decompressing a component of a compressed value.
+#[derive(Debug)]
+pub struct DecompressError {
+    /// The path to the component like `"scale.x"` or empty for values with a single component.
+    pub field: String,
+    pub source: BitReadError,
 }
 
-pub trait Compression: BinRead<Args = ()> + SsbhWrite + Default {
+impl From<BitReadError> for DecompressError {
+    fn from(source: BitReadError) -> Self {
+        Self {
+            field: String::new(),
+            source,
+        }
+    }
+}
+
+trait DecompressResultExt<T> {
+    /// Prepends `name` to the field path of the error.
+    fn field(self, name: &str) -> Result<T, DecompressError>;
+}
+
+impl<T, E: Into<DecompressError>> DecompressResultExt<T> for Result<T, E> {
+    fn field(self, name: &str) -> Result<T, DecompressError> {
+        self.map_err(|e| {
+            let e = e.into();
+            DecompressError {
+                field: if e.field.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{}.{}", name, e.field)
+                },
+                source: e.source,
+            }
+        })
+    }
+}
+
+pub trait Compression: BinRead<Args = ()> + BinWrite<Args = ()> + SsbhWrite + Default {
     fn bit_count(&self, flags: CompressionFlags) -> u64;
 }
 
@@ -160,7 +388,7 @@ pub trait BitReaderExt {
         compression: &T::Compression,
         default: &T,
         args: T::CompressionArgs,
-    ) -> Result<T, BitReadError>;
+    ) -> Result<T, DecompressError>;
 }
 
 impl BitReaderExt for BitReader {
@@ -169,18 +397,19 @@ impl BitReaderExt for BitReader {
         compression: &T::Compression,
         default: &T,
         args: T::CompressionArgs,
-    ) -> Result<T, BitReadError> {
+    ) -> Result<T, DecompressError> {
         T::decompress(self, compression, default, args)
     }
 }
 
-#[derive(Debug, BinRead, Clone, SsbhWrite, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, BinRead, BinWrite, Clone, SsbhWrite, Default, PartialEq)]
 pub struct U32Compression {
     pub min: u32,
     pub max: u32,
     // High bit counts should use uncompressed instead.
     // This also prevents a potential overflow.
-    #[br(assert(bit_count <= 32))]
+    #[br(assert(bit_count <= MAX_BIT_COUNT))]
     pub bit_count: u64,
 }
 
@@ -197,13 +426,14 @@ impl Compression for u128 {
     }
 }
 
-#[derive(Debug, BinRead, SsbhWrite, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, BinRead, BinWrite, SsbhWrite, Default, Clone, Copy, PartialEq)]
 pub struct F32Compression {
     pub min: f32,
     pub max: f32,
     // High bit counts should use uncompressed instead.
     // This also prevents a potential overflow.
-    #[br(assert(bit_count <= 32))]
+    #[br(assert(bit_count <= MAX_BIT_COUNT))]
     pub bit_count: u64,
 }
 
@@ -217,6 +447,26 @@ impl F32Compression {
             bit_count,
         }
     }
+
+    /// Creates a compression with the given range and `bit_count` regardless of the values to compress.
+    /// This keeps the range consistent for values with a known physical range like UV scale from `0.0` to `1.0`.
+    /// Values outside the range are clamped when compressing.
+    /// Returns an error if `bit_count` is greater than 32.
+    pub fn with_explicit_range(
+        min: f32,
+        max: f32,
+        bit_count: u64,
+    ) -> Result<Self, super::error::Error> {
+        if bit_count > MAX_BIT_COUNT {
+            return Err(super::error::Error::BitCountOutOfRange { bit_count });
+        }
+
+        Ok(Self {
+            min,
+            max,
+            bit_count,
+        })
+    }
 }
 
 impl Compression for F32Compression {
@@ -230,7 +480,8 @@ impl Compression for F32Compression {
     }
 }
 
-#[derive(Debug, BinRead, SsbhWrite, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, BinRead, BinWrite, SsbhWrite, Default, PartialEq)]
 pub struct Vector3Compression {
     pub x: F32Compression,
     pub y: F32Compression,
@@ -253,7 +504,8 @@ impl Compression for Vector3Compression {
     }
 }
 
-#[derive(Debug, BinRead, SsbhWrite, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, BinRead, BinWrite, SsbhWrite, Default, PartialEq)]
 pub struct Vector4Compression {
     pub x: F32Compression,
     pub y: F32Compression,
@@ -270,6 +522,24 @@ impl Vector4Compression {
             w: F32Compression::from_range(min.w, max.w),
         }
     }
+
+    /// Creates a compression with the given `(min, max, bit_count)` for each of the xyzw components.
+    /// Components with `min == max` are constant and always use a bit count of 0.
+    /// Returns an error if any component's `bit_count` is greater than 32.
+    pub fn from_ranges_with_bits(
+        ranges: [(f32, f32, u64); 4],
+    ) -> Result<Self, super::error::Error> {
+        let [x, y, z, w] = ranges.map(|(min, max, bit_count)| {
+            let bit_count = if min == max { 0 } else { bit_count };
+            F32Compression::with_explicit_range(min, max, bit_count)
+        });
+        Ok(Self {
+            x: x?,
+            y: y?,
+            z: z?,
+            w: w?,
+        })
+    }
 }
 
 impl Compression for Vector4Compression {
@@ -281,7 +551,8 @@ impl Compression for Vector4Compression {
     }
 }
 
-#[derive(Debug, BinRead, SsbhWrite, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, BinRead, BinWrite, SsbhWrite, Default, PartialEq)]
 pub struct TransformCompression {
     // The x component is used for uniform scale.
     pub scale: Vector3Compression,
@@ -292,7 +563,7 @@ pub struct TransformCompression {
 
 // This is also used for compressed transforms but compensate_scale is omitted.
 // Compressed transforms set compensate_scale using the header's default value.
-#[derive(Debug, BinRead, PartialEq, SsbhWrite, Clone, Copy, Default)]
+#[derive(Debug, BinRead, BinWrite, PartialEq, SsbhWrite, Clone, Copy, Default)]
 pub struct UncompressedTransform {
     pub scale: Vector3,
     pub rotation: Vector4,
@@ -345,7 +616,8 @@ impl Compression for TransformCompression {
     }
 }
 
-#[derive(Debug, BinRead, SsbhWrite, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, BinRead, BinWrite, SsbhWrite, Default, PartialEq)]
 pub struct UvTransformCompression {
     pub scale_u: F32Compression,
     pub scale_v: F32Compression,
@@ -374,27 +646,43 @@ impl Compression for UvTransformCompression {
     }
 }
 
-fn calculate_rotation_w(reader: &mut BitReader, rotation: Vector3) -> f32 {
+fn calculate_rotation(
+    reader: &mut BitReader,
+    rotation_xyz: Vector3,
+) -> Result<Vector4, BitReadError> {
     // Rotations are encoded as xyzw unit quaternions.
     // For a unit quaternion, x^2 + y^2 + z^2 + w^2 = 1.
     // Solving for the missing w gives two expressions:
     // w = sqrt(1 - x^2 + y^2 + z^2), w = -sqrt(1 - x^2 + y^2 + z^2).
     // Thus, we need only need to store the sign bit to uniquely determine w.
-    // TODO: Possible to read past end of stream?
-    let flip_w = reader.read_bit().unwrap();
+    let flip_w = reader.read_bit()?;
+
+    let length_squared = rotation_xyz.x * rotation_xyz.x
+        + rotation_xyz.y * rotation_xyz.y
+        + rotation_xyz.z * rotation_xyz.z;
 
-    let w2 = 1.0 - (rotation.x * rotation.x + rotation.y * rotation.y + rotation.z * rotation.z);
-    let w = if w2.is_sign_negative() {
+    let (xyz, w) = if length_squared > 1.0 {
+        // Lossy compression can produce an xyz part with a length slightly larger than 1.
+        // There is no w that makes this a unit quaternion, so normalize xyz instead.
+        // The closest unit quaternion to (x, y, z, 0) is (x, y, z, 0) / length.
         // TODO: How is this case handled in game?
-        0.0
+        let length = length_squared.sqrt();
+        (
+            Vector3::new(
+                rotation_xyz.x / length,
+                rotation_xyz.y / length,
+                rotation_xyz.z / length,
+            ),
+            0.0,
+        )
     } else {
-        w2.sqrt()
+        (rotation_xyz, (1.0 - length_squared).sqrt())
     };
 
     if flip_w {
-        -w
+        Ok(xyz.extend(-w))
     } else {
-        w
+        Ok(xyz.extend(w))
     }
 }
 
@@ -414,7 +702,8 @@ fn compress_f32(value: f32, min: f32, max: f32, bit_count: NonZeroU64) -> Compre
     // TODO: There could be large errors due to cancellations when the absolute difference of max and min is small.
     // This is likely rare in practice.
     // TODO: Investigate if this works better with f64 as an intermediate representation.
-    let ratio = (value - min) / (max - min);
+    // Clamp out of range values to the nearest representable value.
+    let ratio = ((value - min) / (max - min)).clamp(0.0, 1.0);
     let compressed = ratio * scale as f32;
     // TODO: Should this truncate or round for best accuracy?
     compressed as CompressedBits
@@ -426,6 +715,12 @@ fn decompress_f32(value: CompressedBits, min: f32, max: f32, bit_count: NonZeroU
     // This produces 2 ^ 8 evenly spaced floating point values between 0.0 and 1.0,
     // so 0b00000000 corresponds to 0.0 and 0b11111111 corresponds to 1.0.
 
+    // A range with a single value has only one possible decompressed value.
+    // This also avoids computing a degenerate interpolation below.
+    if min == max {
+        return Some(min);
+    }
+
     // Bit count can't be zero, which prevents divide by zero below.
     let scale = bit_mask(bit_count);
 
@@ -447,25 +742,33 @@ impl CompressedData for UncompressedTransform {
         compression: &Self::Compression,
         default: &Self,
         args: Self::CompressionArgs,
-    ) -> Result<Self, BitReadError> {
+    ) -> Result<Self, DecompressError> {
         let scale = if args.uniform_scale() {
-            let uniform_scale = reader.decompress(&compression.scale.x, &default.scale.x, ())?;
+            let uniform_scale = reader
+                .decompress(&compression.scale.x, &default.scale.x, ())
+                .field("scale.x")?;
             Vector3::new(uniform_scale, uniform_scale, uniform_scale)
         } else {
-            reader.decompress(&compression.scale, &default.scale, ())?
+            reader
+                .decompress(&compression.scale, &default.scale, ())
+                .field("scale")?
         };
 
-        let rotation_xyz = reader.decompress(&compression.rotation, &default.rotation.xyz(), ())?;
-        let translation = reader.decompress(&compression.translation, &default.translation, ())?;
-        let rotation_w = if args.has_rotation() {
-            calculate_rotation_w(reader, rotation_xyz)
+        let rotation_xyz = reader
+            .decompress(&compression.rotation, &default.rotation.xyz(), ())
+            .field("rotation")?;
+        let translation = reader
+            .decompress(&compression.translation, &default.translation, ())
+            .field("translation")?;
+        let rotation = if args.has_rotation() {
+            calculate_rotation(reader, rotation_xyz).field("rotation.w")?
         } else {
-            default.rotation.w
+            rotation_xyz.extend(default.rotation.w)
         };
 
         Ok(UncompressedTransform {
             scale,
-            rotation: Vector4::new(rotation_xyz.x, rotation_xyz.y, rotation_xyz.z, rotation_w),
+            rotation,
             translation,
             // Compressed transforms don't allow specifying compensate scale per frame.
             compensate_scale: default.compensate_scale,
@@ -546,14 +849,20 @@ impl CompressedData for UvTransform {
         compression: &Self::Compression,
         default: &Self,
         args: Self::CompressionArgs,
-    ) -> Result<Self, BitReadError> {
+    ) -> Result<Self, DecompressError> {
         // UvTransforms use similar logic to Transforms.
         let (scale_u, scale_v) = if args.uniform_scale() {
-            let uniform_scale = reader.decompress(&compression.scale_u, &default.scale_u, ())?;
+            let uniform_scale = reader
+                .decompress(&compression.scale_u, &default.scale_u, ())
+                .field("scale_u")?;
             (uniform_scale, uniform_scale)
         } else {
-            let scale_u = reader.decompress(&compression.scale_u, &default.scale_u, ())?;
-            let scale_v = reader.decompress(&compression.scale_v, &default.scale_v, ())?;
+            let scale_u = reader
+                .decompress(&compression.scale_u, &default.scale_u, ())
+                .field("scale_u")?;
+            let scale_v = reader
+                .decompress(&compression.scale_v, &default.scale_v, ())
+                .field("scale_v")?;
             (scale_u, scale_v)
         };
 
@@ -561,9 +870,15 @@ impl CompressedData for UvTransform {
             scale_u,
             scale_v,
             // TODO: Do flags affect these values?
-            rotation: reader.decompress(&compression.rotation, &default.rotation, ())?,
-            translate_u: reader.decompress(&compression.translate_u, &default.translate_u, ())?,
-            translate_v: reader.decompress(&compression.translate_v, &default.translate_v, ())?,
+            rotation: reader
+                .decompress(&compression.rotation, &default.rotation, ())
+                .field("rotation")?,
+            translate_u: reader
+                .decompress(&compression.translate_u, &default.translate_u, ())
+                .field("translate_u")?,
+            translate_v: reader
+                .decompress(&compression.translate_v, &default.translate_v, ())
+                .field("translate_v")?,
         })
     }
 
@@ -636,11 +951,17 @@ impl CompressedData for Vector3 {
         compression: &Self::Compression,
         default: &Self,
         _args: (),
-    ) -> Result<Self, BitReadError> {
+    ) -> Result<Self, DecompressError> {
         Ok(Self {
-            x: reader.decompress(&compression.x, &default.x, ())?,
-            y: reader.decompress(&compression.y, &default.y, ())?,
-            z: reader.decompress(&compression.z, &default.z, ())?,
+            x: reader
+                .decompress(&compression.x, &default.x, ())
+                .field("x")?,
+            y: reader
+                .decompress(&compression.y, &default.y, ())
+                .field("y")?,
+            z: reader
+                .decompress(&compression.z, &default.z, ())
+                .field("z")?,
         })
     }
 
@@ -713,12 +1034,20 @@ impl CompressedData for Vector4 {
         compression: &Self::Compression,
         default: &Self,
         _args: (),
-    ) -> Result<Self, BitReadError> {
+    ) -> Result<Self, DecompressError> {
         Ok(Vector4 {
-            x: reader.decompress(&compression.x, &default.x, ())?,
-            y: reader.decompress(&compression.y, &default.y, ())?,
-            z: reader.decompress(&compression.z, &default.z, ())?,
-            w: reader.decompress(&compression.w, &default.w, ())?,
+            x: reader
+                .decompress(&compression.x, &default.x, ())
+                .field("x")?,
+            y: reader
+                .decompress(&compression.y, &default.y, ())
+                .field("y")?,
+            z: reader
+                .decompress(&compression.z, &default.z, ())
+                .field("z")?,
+            w: reader
+                .decompress(&compression.w, &default.w, ())
+                .field("w")?,
         })
     }
 
@@ -756,7 +1085,7 @@ impl CompressedData for u32 {
         compression: &Self::Compression,
         _default: &Self,
         _: Self::CompressionArgs,
-    ) -> Result<Self, BitReadError> {
+    ) -> Result<Self, DecompressError> {
         // TODO: There's only a single track in Smash Ultimate that uses this, so this is just a guess.
         // TODO: How to decompress a u32 with min, max, and bitcount?
         let value = if compression.bit_count == 0 {
@@ -803,7 +1132,7 @@ impl CompressedData for f32 {
         compression: &Self::Compression,
         default: &Self,
         _args: Self::CompressionArgs,
-    ) -> Result<Self, BitReadError> {
+    ) -> Result<Self, DecompressError> {
         let value = match NonZeroU64::new(compression.bit_count as u64) {
             Some(bit_count) => {
                 if compression.min == compression.max {
@@ -823,11 +1152,12 @@ impl CompressedData for f32 {
         &self,
         writer: &mut BitWriter,
         compression: &Self::Compression,
-        _flags: CompressionFlags,
+        flags: CompressionFlags,
     ) {
-        if let Some(bit_count) = NonZeroU64::new(compression.bit_count as u64) {
+        // Match the bit count used for decompression.
+        if let Some(bit_count) = NonZeroU64::new(compression.bit_count(flags)) {
             let compressed_value = compress_f32(*self, compression.min, compression.max, bit_count);
-            writer.write(compressed_value, compression.bit_count as usize);
+            writer.write(compressed_value, bit_count.get() as usize);
         }
     }
 
@@ -843,7 +1173,7 @@ impl CompressedData for f32 {
     }
 }
 
-#[derive(Debug, BinRead, SsbhWrite, Default, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, BinRead, BinWrite, SsbhWrite, Default, PartialEq, Eq, Clone, Copy)]
 pub struct Boolean(pub u8);
 
 impl From<bool> for Boolean {
@@ -883,11 +1213,14 @@ impl CompressedData for Boolean {
     fn decompress(
         reader: &mut BitReader,
         _compression: &Self::Compression,
-        _default: &Self,
+        default: &Self,
         bits_per_entry: Self::CompressionArgs,
-    ) -> Result<Self, BitReadError> {
+    ) -> Result<Self, DecompressError> {
         // Boolean compression is based on bits per entry, which is usually set to 1 bit.
-        // TODO: 0 bits uses the default?
+        // 0 bits uses the default for all frames.
+        if bits_per_entry == 0 {
+            return Ok(*default);
+        }
         let value = reader.read_u8(bits_per_entry)?;
         Ok(Boolean(value))
     }
@@ -904,12 +1237,108 @@ impl CompressedData for Boolean {
         // TODO: Should booleans always default to false?
         (Boolean(0u8), 0)
     }
+
+    fn expected_bit_count(header: &CompressedHeader<Self>, compression: &Self::Compression) -> u64 {
+        // Allow 0 bits to indicate that all frames use the default value.
+        match header.bits_per_entry {
+            0 => 0,
+            _ => compression.bit_count(header.flags),
+        }
+    }
+}
+
+/// An [f32] value stored as a raw IEEE 754 half precision float in the compressed bit buffer.
+/// This avoids managing a range for each component at the cost of reduced precision.
+#[cfg(feature = "half_compression")]
+#[derive(Debug, BinRead, BinWrite, SsbhWrite, Default, PartialEq, Clone, Copy)]
+pub struct HalfFloat(pub f32);
+
+/// The number of bits for each compressed [HalfFloat].
+#[cfg(feature = "half_compression")]
+pub const HALF_BIT_COUNT: u64 = 16;
+
+/// The compression for [HalfFloat], which always uses [HALF_BIT_COUNT] bits.
+#[cfg(feature = "half_compression")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, BinRead, BinWrite, SsbhWrite, Clone, Copy, PartialEq, Eq)]
+pub struct HalfCompression {
+    #[br(assert(bit_count == HALF_BIT_COUNT))]
+    pub bit_count: u64,
+}
+
+#[cfg(feature = "half_compression")]
+impl Default for HalfCompression {
+    fn default() -> Self {
+        Self {
+            bit_count: HALF_BIT_COUNT,
+        }
+    }
+}
+
+#[cfg(feature = "half_compression")]
+impl Compression for HalfCompression {
+    fn bit_count(&self, _: CompressionFlags) -> u64 {
+        HALF_BIT_COUNT
+    }
+}
+
+#[cfg(feature = "half_compression")]
+impl CompressedData for HalfFloat {
+    type Compression = HalfCompression;
+    type BitStore = CompressedBits;
+    type CompressionArgs = ();
+
+    fn decompress(
+        reader: &mut BitReader,
+        _compression: &Self::Compression,
+        _default: &Self,
+        _args: Self::CompressionArgs,
+    ) -> Result<Self, DecompressError> {
+        let value = reader.read_u32(HALF_BIT_COUNT as usize)?;
+        Ok(HalfFloat(half::f16::from_bits(value as u16).to_f32()))
+    }
+
+    fn compress(&self, writer: &mut BitWriter, _: &Self::Compression, _: CompressionFlags) {
+        let value = half::f16::from_f32(self.0).to_bits();
+        writer.write(value as CompressedBits, HALF_BIT_COUNT as usize);
+    }
+
+    fn get_args(_: &CompressedHeader<Self>) -> Self::CompressionArgs {}
+
+    fn get_default_and_compression(values: &[Self], _: bool) -> (Self, Self::Compression) {
+        // The default is unused since every value is stored.
+        (
+            values.first().copied().unwrap_or_default(),
+            HalfCompression::default(),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn decompress_transform_error_field() {
+        // Only the translation has enough bits to read.
+        let compression = TransformCompression {
+            translation: Vector3Compression {
+                x: F32Compression::from_range(0.0, 1.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut reader = BitReader::from_slice(&[0u8]);
+        let error = reader
+            .decompress(
+                &compression,
+                &UncompressedTransform::default(),
+                CompressionFlags::new().with_has_rotation(true),
+            )
+            .unwrap_err();
+        assert_eq!("translation.x", error.field);
+    }
+
     #[test]
     fn bit_masks() {
         assert_eq!(0b1u64, bit_mask(NonZeroU64::new(1).unwrap()));
@@ -967,6 +1396,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decompress_float_min_equals_max() {
+        for bit_count in [1, 5, 16, 24] {
+            let bit_count = NonZeroU64::new(bit_count).unwrap();
+            assert_eq!(Some(5.0), decompress_f32(0, 5.0, 5.0, bit_count));
+            assert_eq!(Some(5.0), decompress_f32(1, 5.0, 5.0, bit_count));
+            assert_eq!(
+                Some(5.0),
+                decompress_f32(bit_mask(bit_count) as CompressedBits, 5.0, 5.0, bit_count)
+            );
+        }
+    }
+
     #[test]
     fn decompress_float_14bit() {
         // stage/poke_unova/battle/motion/s13_a, D_lightning_B, CustomVector3
@@ -1009,6 +1451,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compress_float_out_of_range() {
+        let bit_count = NonZeroU64::new(14).unwrap();
+        assert_eq!(
+            bit_mask(bit_count) as CompressedBits,
+            compress_f32(2.5, 0.0, 1.0, bit_count)
+        );
+        assert_eq!(0, compress_f32(-2.5, 0.0, 1.0, bit_count));
+        assert_eq!(
+            bit_mask(bit_count) as CompressedBits,
+            compress_f32(f32::INFINITY, -1.0, 1.0, bit_count)
+        );
+    }
+
     #[test]
     fn compress_decompress_float_24bit() {
         assert_eq!(
@@ -1029,48 +1485,107 @@ mod tests {
     }
 
     #[test]
-    fn calculate_rotation_w_unit_quaternion_true() {
+    fn calculate_rotation_unit_quaternion_true() {
         let mut reader = BitReader::from_slice(&[1u8]);
         assert_eq!(
-            0.0,
-            calculate_rotation_w(&mut reader, Vector3::new(1.0, 0.0, 0.0))
+            Vector4::new(1.0, 0.0, 0.0, 0.0),
+            calculate_rotation(&mut reader, Vector3::new(1.0, 0.0, 0.0)).unwrap()
         );
     }
 
     #[test]
-    fn calculate_rotation_w_non_unit_quaternion_true() {
+    fn calculate_rotation_non_unit_quaternion_true() {
         let mut reader = BitReader::from_slice(&[1u8]);
 
         // W isn't well defined in this case.
-        // Just assume W is 0.0 when the square root would be negative.
-        // TODO: There may be a better approach with better animation quality.
-        assert_eq!(
-            0.0,
-            calculate_rotation_w(&mut reader, Vector3::new(1.0, 1.0, 1.0))
-        );
+        // Normalize xyz to produce the closest unit quaternion.
+        let rotation = calculate_rotation(&mut reader, Vector3::new(1.0, 1.0, 1.0)).unwrap();
+        let expected = 1.0 / 3.0f32.sqrt();
+        assert_eq!(Vector4::new(expected, expected, expected, -0.0), rotation);
     }
 
     #[test]
-    fn calculate_rotation_w_unit_quaternion_false() {
+    fn calculate_rotation_unit_quaternion_false() {
         let mut reader = BitReader::from_slice(&[0u8]);
 
         assert_eq!(
-            0.0,
-            calculate_rotation_w(&mut reader, Vector3::new(1.0, 0.0, 0.0))
+            Vector4::new(0.6, 0.0, 0.0, 0.8),
+            calculate_rotation(&mut reader, Vector3::new(0.6, 0.0, 0.0)).unwrap()
         );
     }
 
     #[test]
-    fn calculate_rotation_w_non_unit_quaternion_false() {
+    fn calculate_rotation_non_unit_quaternion_false() {
         let mut reader = BitReader::from_slice(&[0u8]);
 
         // W isn't well defined in this case.
-        // Just assume W is 0.0 when the square root would be negative.
-        // TODO: There may be a better approach with better animation quality.
-        assert_eq!(
+        // Normalize xyz to produce the closest unit quaternion.
+        let rotation = calculate_rotation(&mut reader, Vector3::new(1.0, 1.0, 1.0)).unwrap();
+        let expected = 1.0 / 3.0f32.sqrt();
+        assert_eq!(Vector4::new(expected, expected, expected, 0.0), rotation);
+    }
+
+    #[test]
+    fn calculate_rotation_near_unit_quaternion_error() {
+        // Compression error can make the xyz length slightly larger than 1.
+        let expected = Vector4::new(
+            std::f32::consts::FRAC_1_SQRT_2,
+            std::f32::consts::FRAC_1_SQRT_2,
+            0.0,
             0.0,
-            calculate_rotation_w(&mut reader, Vector3::new(1.0, 1.0, 1.0))
         );
+        let xyz = Vector3::new(0.7072, 0.7072, 0.0);
+
+        let error = |q: Vector4| {
+            ((q.x - expected.x).powi(2)
+                + (q.y - expected.y).powi(2)
+                + (q.z - expected.z).powi(2)
+                + (q.w - expected.w).powi(2))
+            .sqrt()
+        };
+
+        // Setting w to 0.0 without modifying xyz produces a non unit quaternion.
+        let zero_clamp_error = error(xyz.extend(0.0));
+
+        let mut reader = BitReader::from_slice(&[0u8]);
+        let rotation = calculate_rotation(&mut reader, xyz).unwrap();
+        let length = (rotation.x * rotation.x
+            + rotation.y * rotation.y
+            + rotation.z * rotation.z
+            + rotation.w * rotation.w)
+            .sqrt();
+
+        assert!((length - 1.0).abs() < 1e-6);
+        assert!(error(rotation) < zero_clamp_error);
+        assert!(error(rotation) < 1e-6);
+    }
+
+    #[test]
+    fn compression_flags_raw() {
+        // stage/kirby_greens/normal/motion/whispy_set/whispy_set_turnblowl3.nuanmb, _sfx_GrdGreensGrassAM1, nfTexture0[0]
+        let flags = CompressionFlags::from_raw(0x0009);
+        assert_eq!(
+            CompressionFlags::new()
+                .with_const_scale(true)
+                .with_uniform_scale(false)
+                .with_has_rotation(false)
+                .with_has_translation(true),
+            flags
+        );
+        assert_eq!(0x0009, flags.to_raw());
+
+        let debug = format!("{:?}", flags);
+        assert!(debug.contains("const_scale: true"));
+        assert!(debug.contains("uniform_scale: false"));
+        assert!(debug.contains("has_rotation: false"));
+        assert!(debug.contains("has_translation: true"));
+    }
+
+    #[test]
+    fn compression_flags_raw_round_trip() {
+        for value in [0x0000, 0x0002, 0x0004, 0x000F] {
+            assert_eq!(value, CompressionFlags::from_raw(value).to_raw());
+        }
     }
 
     #[test]
@@ -1157,7 +1672,136 @@ mod tests {
         );
     }
 
-    // TODO: Also test UvTransform for scale flags?
+    #[cfg(feature = "serde")]
+    #[test]
+    fn transform_compression_json_round_trip() {
+        let compression = TransformCompression {
+            scale: Vector3Compression::from_range(
+                Vector3::new(0.5, 0.5, 0.5),
+                Vector3::new(1.0, 1.0, 1.0),
+            ),
+            rotation: Vector3Compression::from_range(Vector3::ZERO, Vector3::ZERO),
+            translation: Vector3Compression::from_range(
+                Vector3::new(-1.0, 0.0, 2.0),
+                Vector3::new(1.0, 0.0, 3.0),
+            ),
+        };
+
+        let json = serde_json::to_string(&compression).unwrap();
+        let result: TransformCompression = serde_json::from_str(&json).unwrap();
+        assert_eq!(compression, result);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn compression_flags_json_round_trip() {
+        let flags = CompressionFlags::new()
+            .with_uniform_scale(true)
+            .with_has_rotation(true);
+
+        let json = serde_json::to_string(&flags).unwrap();
+        assert_eq!(
+            r#"{"const_scale":false,"uniform_scale":true,"has_rotation":true,"has_translation":false}"#,
+            json
+        );
+        let result: CompressionFlags = serde_json::from_str(&json).unwrap();
+        assert_eq!(flags, result);
+    }
+
+    #[test]
+    fn has_uniform_scale_uniform() {
+        assert!(has_uniform_scale(&[
+            Transform {
+                scale: Vector3::new(1.0, 1.0, 1.0),
+                ..Default::default()
+            },
+            Transform {
+                scale: Vector3::new(2.0, 2.0, 2.0),
+                ..Default::default()
+            },
+        ]));
+    }
+
+    #[test]
+    fn has_uniform_scale_non_uniform() {
+        assert!(!has_uniform_scale(&[
+            Transform {
+                scale: Vector3::new(1.0, 1.0, 1.0),
+                ..Default::default()
+            },
+            Transform {
+                scale: Vector3::new(2.0, 1.0, 2.0),
+                ..Default::default()
+            },
+        ]));
+    }
+
+    #[test]
+    fn compression_flags_uv_uniform_scale() {
+        assert_eq!(
+            CompressionFlags::new()
+                .with_const_scale(false)
+                .with_uniform_scale(true)
+                .with_has_rotation(true)
+                .with_has_translation(true),
+            CompressionFlags::from_track(&TrackValues::UvTransform(vec![
+                UvTransform {
+                    scale_u: 1.0,
+                    scale_v: 1.0,
+                    ..Default::default()
+                },
+                UvTransform {
+                    scale_u: 2.0,
+                    scale_v: 2.0,
+                    ..Default::default()
+                }
+            ]),)
+        );
+    }
+
+    #[test]
+    fn compression_flags_uv_scale() {
+        assert_eq!(
+            CompressionFlags::new()
+                .with_const_scale(false)
+                .with_uniform_scale(false)
+                .with_has_rotation(true)
+                .with_has_translation(true),
+            CompressionFlags::from_track(&TrackValues::UvTransform(vec![
+                UvTransform {
+                    scale_u: 1.0,
+                    scale_v: 1.0,
+                    ..Default::default()
+                },
+                UvTransform {
+                    scale_u: 1.0,
+                    scale_v: 2.0,
+                    ..Default::default()
+                }
+            ]),)
+        );
+    }
+
+    #[test]
+    fn compression_flags_uniform_scale_single_non_uniform_frame() {
+        // A single non uniform frame prevents using uniform scale for the whole track.
+        let flags = CompressionFlags::from_track(&TrackValues::Transform(vec![
+            Transform {
+                scale: Vector3::new(1.0, 1.0, 1.0),
+                ..Default::default()
+            },
+            Transform {
+                scale: Vector3::new(1.0, 1.0, 0.5),
+                ..Default::default()
+            },
+            Transform {
+                scale: Vector3::new(1.0, 1.0, 1.0),
+                ..Default::default()
+            },
+        ]));
+        assert!(!flags.uniform_scale());
+    }
+
     // TODO: Does UvTransform use the const flag?
 
     #[test]
@@ -1183,6 +1827,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn f32_with_explicit_range_bit_count() {
+        assert_eq!(
+            F32Compression {
+                min: 0.0,
+                max: 1.0,
+                bit_count: 32
+            },
+            F32Compression::with_explicit_range(0.0, 1.0, 32).unwrap()
+        );
+        assert!(matches!(
+            F32Compression::with_explicit_range(0.0, 1.0, 33),
+            Err(crate::anim_data::error::Error::BitCountOutOfRange { bit_count: 33 })
+        ));
+        assert!(matches!(
+            F32Compression::with_explicit_range(0.0, 1.0, 64),
+            Err(crate::anim_data::error::Error::BitCountOutOfRange { bit_count: 64 })
+        ));
+    }
+
+    #[test]
+    fn vector4_from_ranges_with_bits_bit_count() {
+        let compression = Vector4Compression::from_ranges_with_bits([
+            (0.0, 1.0, 8),
+            (0.0, 0.0, 64),
+            (0.0, 1.0, 32),
+            (1.0, 1.0, 24),
+        ])
+        .unwrap();
+        assert_eq!(
+            (8, 0, 32, 0),
+            (
+                compression.x.bit_count,
+                compression.y.bit_count,
+                compression.z.bit_count,
+                compression.w.bit_count
+            )
+        );
+
+        assert!(matches!(
+            Vector4Compression::from_ranges_with_bits([
+                (0.0, 1.0, 8),
+                (0.0, 1.0, 8),
+                (0.0, 1.0, 8),
+                (0.0, 1.0, 33),
+            ]),
+            Err(crate::anim_data::error::Error::BitCountOutOfRange { bit_count: 33 })
+        ));
+    }
+
     #[test]
     fn f32_bit_count_min_equals_max() {
         assert_eq!(
@@ -1,7 +1,7 @@
 //! Types for working with [Hlpb] data in .nuhlpb files.
 use std::iter::repeat;
 
-use ssbh_lib::{formats::hlpb::*, Vector3, Vector4};
+use ssbh_lib::{formats::hlpb::*, Vector3, Vector4, Version};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -18,6 +18,13 @@ pub struct HlpbData {
     pub orient_constraints: Vec<OrientConstraintData>,
 }
 
+impl HlpbData {
+    /// The `(major_version, minor_version)` pairs supported for reading and writing.
+    pub fn supported_versions() -> &'static [(u16, u16)] {
+        &[(1, 1)]
+    }
+}
+
 // TODO: Simplify these fields?
 // TODO: Use clearer field names.
 /// Data associated with an [AimConstraint].
@@ -67,14 +74,15 @@ impl From<Hlpb> for HlpbData {
 
 impl From<&Hlpb> for HlpbData {
     fn from(h: &Hlpb) -> Self {
+        let (major_version, minor_version) = h.major_minor_version();
         match h {
             Hlpb::V11 {
                 aim_constraints,
                 orient_constraints,
                 ..
             } => Self {
-                major_version: 1,
-                minor_version: 0,
+                major_version,
+                minor_version,
                 aim_constraints: aim_constraints.elements.iter().map(Into::into).collect(),
                 orient_constraints: orient_constraints.elements.iter().map(Into::into).collect(),
             },
@@ -281,7 +289,7 @@ mod tests {
 
         let data = HlpbData {
             major_version: 1,
-            minor_version: 0,
+            minor_version: 1,
             aim_constraints: vec![AimConstraintData {
                 name: "aim1".to_string(),
                 aim_bone_name1: "root".to_string(),
@@ -53,14 +53,76 @@ pub mod modl_data;
 pub mod shdr_data;
 pub mod skel_data;
 
-use binrw::io::{Read, Seek, Write};
+use binrw::io::{Read, Seek, SeekFrom, Write};
 use ssbh_lib::prelude::*;
+use ssbh_lib::SsbhFileType;
 use std::convert::{TryFrom, TryInto};
 use std::error::Error;
+use std::io::Cursor;
 use std::path::Path;
+use thiserror::Error;
 
 pub use ssbh_lib::{CString, Color4f, Vector3, Vector4};
 
+/// The version of the data read from a file is not supported.
+/// The supported versions for each format are given by functions like [ModlData::supported_versions](crate::modl_data::ModlData::supported_versions).
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[error(
+    "Version {}.{} is not supported. Supported versions are {}.",
+    major_version,
+    minor_version,
+    format_versions(supported_versions)
+)]
+pub struct UnsupportedVersionError {
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub supported_versions: &'static [(u16, u16)],
+}
+
+fn format_versions(versions: &[(u16, u16)]) -> String {
+    versions
+        .iter()
+        .map(|(major, minor)| format!("{}.{}", major, minor))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn check_version(
+    major_version: u16,
+    minor_version: u16,
+    supported_versions: &'static [(u16, u16)],
+) -> Result<(), UnsupportedVersionError> {
+    if supported_versions.contains(&(major_version, minor_version)) {
+        Ok(())
+    } else {
+        Err(UnsupportedVersionError {
+            major_version,
+            minor_version,
+            supported_versions,
+        })
+    }
+}
+
+// Check the version from the SSBH header before parsing or converting the rest of the file.
+// Files that aren't SSBH files are left for ssbh_lib to report the appropriate error.
+fn check_ssbh_version<R: Read + Seek>(
+    reader: &mut R,
+    supported_versions: &'static [(u16, u16)],
+) -> Result<(), Box<dyn std::error::Error>> {
+    // The version follows the 16 byte SSBH header and 4 byte type magic.
+    let start = reader.stream_position()?;
+    let mut header = [0u8; 0x18];
+    let result = reader.read_exact(&mut header);
+    reader.seek(SeekFrom::Start(start))?;
+
+    if result.is_ok() && ssbh_lib::sniff_format(&header).is_some() {
+        let major_version = u16::from_le_bytes([header[0x14], header[0x15]]);
+        let minor_version = u16::from_le_bytes([header[0x16], header[0x17]]);
+        check_version(major_version, minor_version, supported_versions)?;
+    }
+    Ok(())
+}
+
 /// Functions for reading and writing supported formats.
 pub trait SsbhData: Sized {
     type WriteError: Error;
@@ -83,6 +145,64 @@ pub trait SsbhData: Sized {
     fn write_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Self::WriteError>;
 }
 
+/// The data for a file in any of the formats supported by [SsbhData].
+#[derive(Debug)]
+pub enum AnyData {
+    Adj(adj_data::AdjData),
+    Anim(anim_data::AnimData),
+    Hlpb(hlpb_data::HlpbData),
+    Matl(matl_data::MatlData),
+    Mesh(mesh_data::MeshData),
+    MeshEx(meshex_data::MeshExData),
+    Modl(modl_data::ModlData),
+    Skel(skel_data::SkelData),
+}
+
+impl AnyData {
+    /// Tries to read and convert the data from `path` using the appropriate data type.
+    /// The entire file is buffered for performance.
+    ///
+    /// SSBH formats are detected from the magic using [sniff_format](ssbh_lib::sniff_format).
+    /// Formats without a magic are detected from the `.adjb` or `.numshexb` extension.
+    /**
+    ```no_run
+    use ssbh_data::AnyData;
+
+    # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    match AnyData::from_file("model.numdlb")? {
+        AnyData::Modl(modl) => println!("{}", modl.model_name),
+        _ => (),
+    }
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let mut reader = Cursor::new(std::fs::read(path)?);
+
+        match ssbh_lib::sniff_format(reader.get_ref()) {
+            Some(SsbhFileType::Anim) => Ok(Self::Anim(SsbhData::read(&mut reader)?)),
+            Some(SsbhFileType::Hlpb) => Ok(Self::Hlpb(SsbhData::read(&mut reader)?)),
+            Some(SsbhFileType::Matl) => Ok(Self::Matl(SsbhData::read(&mut reader)?)),
+            Some(SsbhFileType::Mesh) => Ok(Self::Mesh(SsbhData::read(&mut reader)?)),
+            Some(SsbhFileType::Modl) => Ok(Self::Modl(SsbhData::read(&mut reader)?)),
+            Some(SsbhFileType::Skel) => Ok(Self::Skel(SsbhData::read(&mut reader)?)),
+            Some(_) => Err(UnsupportedFormatError.into()),
+            None => match path.extension().and_then(|e| e.to_str()) {
+                Some("adjb") => Ok(Self::Adj(SsbhData::read(&mut reader)?)),
+                Some("numshexb") => Ok(Self::MeshEx(SsbhData::read(&mut reader)?)),
+                _ => Err(UnsupportedFormatError.into()),
+            },
+        }
+    }
+}
+
+/// The file's format could not be determined or does not have a supported data type.
+#[derive(Debug, Error, PartialEq, Eq, Clone, Copy)]
+#[error("The file format is not supported.")]
+pub struct UnsupportedFormatError;
+
 /// Common imports for supported types and important traits.
 pub mod prelude {
     pub use crate::adj_data::AdjData;
@@ -94,22 +214,27 @@ pub mod prelude {
     pub use crate::modl_data::ModlData;
     pub use crate::shdr_data::ShdrData;
     pub use crate::skel_data::SkelData;
-    pub use crate::SsbhData;
+    pub use crate::{AnyData, SsbhData};
 }
 
 macro_rules! ssbh_data_impl {
-    ($ssbh_data:ty, $ssbh_lib:ty, $error:ty) => {
+    ($ssbh_data:ty, $ssbh_lib:ty, $error:ty $(, $supported_versions:expr)?) => {
         impl SsbhData for $ssbh_data {
             type WriteError = $error;
 
             fn from_file<P: AsRef<std::path::Path>>(
                 path: P,
             ) -> Result<Self, Box<dyn std::error::Error>> {
-                <$ssbh_lib>::from_file(path)?.try_into().map_err(Into::into)
+                let mut reader = Cursor::new(std::fs::read(path)?);
+                $(check_ssbh_version(&mut reader, $supported_versions)?;)?
+                let data: Self = <$ssbh_lib>::read(&mut reader)?.try_into()?;
+                Ok(data)
             }
 
             fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, Box<dyn std::error::Error>> {
-                <$ssbh_lib>::read(reader)?.try_into().map_err(Into::into)
+                $(check_ssbh_version(reader, $supported_versions)?;)?
+                let data: Self = <$ssbh_lib>::read(reader)?.try_into()?;
+                Ok(data)
             }
 
             fn write<W: Write + Seek>(&self, writer: &mut W) -> Result<(), Self::WriteError> {
@@ -130,21 +255,27 @@ macro_rules! ssbh_data_impl {
     };
 }
 
+// Converting to the ssbh_lib type when writing can't fail.
 macro_rules! ssbh_data_infallible_impl {
-    ($ssbh_data:ty, $ssbh_lib:ty, $error:ty) => {
+    ($ssbh_data:ty, $ssbh_lib:ty, $error:ty $(, $supported_versions:expr)?) => {
         impl SsbhData for $ssbh_data {
             type WriteError = $error;
 
             fn from_file<P: AsRef<std::path::Path>>(
                 path: P,
             ) -> Result<Self, Box<dyn std::error::Error>> {
-                Ok(<$ssbh_lib>::from_file(path)?.into())
+                let mut reader = Cursor::new(std::fs::read(path)?);
+                $(check_ssbh_version(&mut reader, $supported_versions)?;)?
+                let data: Self = <$ssbh_lib>::read(&mut reader)?.try_into()?;
+                Ok(data)
             }
 
             fn read<R: std::io::Read + std::io::Seek>(
                 reader: &mut R,
             ) -> Result<Self, Box<dyn std::error::Error>> {
-                Ok(<$ssbh_lib>::read(reader)?.into())
+                $(check_ssbh_version(reader, $supported_versions)?;)?
+                let data: Self = <$ssbh_lib>::read(reader)?.try_into()?;
+                Ok(data)
             }
 
             fn write<W: std::io::Write + std::io::Seek>(
@@ -166,15 +297,57 @@ macro_rules! ssbh_data_infallible_impl {
 
 // TODO: Make these methods usable without importing the trait?
 ssbh_data_impl!(adj_data::AdjData, Adj, adj_data::error::Error);
-ssbh_data_impl!(anim_data::AnimData, Anim, anim_data::error::Error);
-ssbh_data_impl!(matl_data::MatlData, Matl, matl_data::error::Error);
-ssbh_data_impl!(mesh_data::MeshData, Mesh, mesh_data::error::Error);
-ssbh_data_infallible_impl!(meshex_data::MeshExData, MeshEx, std::io::Error);
-ssbh_data_infallible_impl!(modl_data::ModlData, Modl, std::io::Error);
-ssbh_data_infallible_impl!(hlpb_data::HlpbData, Hlpb, std::io::Error);
-ssbh_data_impl!(skel_data::SkelData, Skel, skel_data::error::Error);
+ssbh_data_impl!(
+    anim_data::AnimData,
+    Anim,
+    anim_data::error::Error,
+    anim_data::AnimData::supported_versions()
+);
+ssbh_data_impl!(
+    matl_data::MatlData,
+    Matl,
+    matl_data::error::Error,
+    matl_data::MatlData::supported_versions()
+);
+ssbh_data_impl!(
+    mesh_data::MeshData,
+    Mesh,
+    mesh_data::error::Error,
+    mesh_data::MeshData::supported_versions()
+);
+ssbh_data_impl!(meshex_data::MeshExData, MeshEx, meshex_data::error::Error);
+ssbh_data_impl!(
+    modl_data::ModlData,
+    Modl,
+    modl_data::error::Error,
+    modl_data::ModlData::supported_versions()
+);
+ssbh_data_infallible_impl!(
+    hlpb_data::HlpbData,
+    Hlpb,
+    std::io::Error,
+    hlpb_data::HlpbData::supported_versions()
+);
+ssbh_data_impl!(
+    skel_data::SkelData,
+    Skel,
+    skel_data::error::Error,
+    skel_data::SkelData::supported_versions()
+);
 // TODO: ShdrData.
 
+// Checks that mapping[old] = new assigns a unique new index to each old index.
+pub(crate) fn is_permutation(mapping: &[usize]) -> bool {
+    let mut found = vec![false; mapping.len()];
+    for &i in mapping {
+        match found.get_mut(i) {
+            Some(found) if !*found => *found = true,
+            _ => return false,
+        }
+    }
+    true
+}
+
 #[cfg(test)]
 pub(crate) fn group_hex(a: &str, words_per_line: usize) -> String {
     use itertools::Itertools;
@@ -203,3 +376,112 @@ macro_rules! assert_hex_eq {
 
 #[cfg(test)]
 pub(crate) use assert_hex_eq;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{MatlData, MeshData};
+    use hexlit::hex;
+    use ssbh_lib::formats::modl::ModlEntry;
+
+    #[test]
+    fn any_data_from_modl_file() {
+        let path = std::env::temp_dir().join("ssbh_data_any_data_from_modl_file.numdlb");
+        Modl::V17 {
+            model_name: "model".into(),
+            skeleton_file_name: "model.nusktb".into(),
+            material_file_names: vec!["model.numatb".into()].into(),
+            animation_file_name: ssbh_lib::RelPtr64::null(),
+            mesh_file_name: "model.numshb".into(),
+            entries: vec![ModlEntry {
+                mesh_object_name: "object".into(),
+                mesh_object_subindex: 0,
+                material_label: "material".into(),
+            }]
+            .into(),
+        }
+        .write_to_file(&path)
+        .unwrap();
+
+        let data = AnyData::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        match data.unwrap() {
+            AnyData::Modl(modl) => {
+                assert_eq!("model", modl.model_name);
+                assert_eq!("material", modl.entries[0].material_label);
+            }
+            _ => panic!("Expected AnyData::Modl"),
+        }
+    }
+
+    #[test]
+    fn any_data_unsupported_extension() {
+        let path = std::env::temp_dir().join("ssbh_data_any_data_unsupported_extension.txt");
+        std::fs::write(&path, b"abcd").unwrap();
+
+        let result = AnyData::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result
+            .unwrap_err()
+            .downcast_ref::<UnsupportedFormatError>()
+            .is_some());
+    }
+
+    #[test]
+    fn read_matl_unsupported_version() {
+        let mut reader = Cursor::new(hex!(
+            "48425353 40000000 00000000 00000000 4C54414D 01000500"
+        ));
+        let error = MatlData::read(&mut reader).unwrap_err();
+        assert_eq!(
+            Some(&UnsupportedVersionError {
+                major_version: 1,
+                minor_version: 5,
+                supported_versions: MatlData::supported_versions()
+            }),
+            error.downcast_ref::<UnsupportedVersionError>()
+        );
+        // The reader position should be unchanged.
+        assert_eq!(0, reader.position());
+    }
+
+    #[test]
+    fn read_mesh_unsupported_version() {
+        let mut reader = Cursor::new(hex!(
+            "48425353 40000000 00000000 00000000 4853454D 02002D01"
+        ));
+        let error = MeshData::read(&mut reader).unwrap_err();
+        assert_eq!(
+            Some(&UnsupportedVersionError {
+                major_version: 2,
+                minor_version: 301,
+                supported_versions: MeshData::supported_versions()
+            }),
+            error.downcast_ref::<UnsupportedVersionError>()
+        );
+    }
+
+    #[test]
+    fn check_supported_version() {
+        assert_eq!(Ok(()), check_version(2, 1, &[(2, 0), (2, 1)]));
+    }
+
+    #[test]
+    fn check_unsupported_version() {
+        let error = check_version(1, 2, &[(2, 0), (2, 1)]).unwrap_err();
+        assert_eq!(
+            UnsupportedVersionError {
+                major_version: 1,
+                minor_version: 2,
+                supported_versions: &[(2, 0), (2, 1)]
+            },
+            error
+        );
+        assert_eq!(
+            "Version 1.2 is not supported. Supported versions are 2.0, 2.1.",
+            error.to_string()
+        );
+    }
+}
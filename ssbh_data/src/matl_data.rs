@@ -65,6 +65,10 @@ pub mod error {
             minor_version: u16,
         },
 
+        /// A string contains null bytes and can't be converted to a null terminated string.
+        #[error(transparent)]
+        String(#[from] ssbh_lib::StringError),
+
         /// An error occurred while writing data.
         #[error(transparent)]
         Io(#[from] std::io::Error),
@@ -82,6 +86,13 @@ pub struct MatlData {
     pub entries: Vec<MatlEntryData>,
 }
 
+impl MatlData {
+    /// The `(major_version, minor_version)` pairs supported for reading and writing.
+    pub fn supported_versions() -> &'static [(u16, u16)] {
+        &[(1, 6)]
+    }
+}
+
 /// Data associated with a [MatlEntryV16].
 ///
 /// Parameters are grouped by their type like [vectors](struct.MatlEntryData.html#structfield.vectors)
@@ -102,6 +113,58 @@ pub struct MatlEntryData {
     // TODO: UV Transform?
 }
 
+impl MatlEntryData {
+    /// Returns the value of the first vector with the given `param_id`
+    /// or `None` if no vector uses that ID.
+    /**
+    ```rust
+    # use ssbh_data::matl_data::{MatlEntryData, ParamId, Vector4Param};
+    # use ssbh_lib::Vector4;
+    # let entry = MatlEntryData {
+    #     material_label: String::new(),
+    #     shader_label: String::new(),
+    #     blend_states: Vec::new(),
+    #     floats: Vec::new(),
+    #     booleans: Vec::new(),
+    #     vectors: vec![Vector4Param::new(ParamId::CustomVector0, Vector4::new(1.0, 2.0, 3.0, 4.0))],
+    #     rasterizer_states: Vec::new(),
+    #     samplers: Vec::new(),
+    #     textures: Vec::new(),
+    # };
+    assert_eq!(Some(Vector4::new(1.0, 2.0, 3.0, 4.0)), entry.get_vector4(ParamId::CustomVector0));
+    assert_eq!(None, entry.get_vector4(ParamId::CustomVector1));
+    ```
+     */
+    pub fn get_vector4(&self, param_id: ParamId) -> Option<Vector4> {
+        find_param(&self.vectors, param_id).copied()
+    }
+
+    /// Returns the value of the first float with the given `param_id`
+    /// or `None` if no float uses that ID.
+    pub fn get_float(&self, param_id: ParamId) -> Option<f32> {
+        find_param(&self.floats, param_id).copied()
+    }
+
+    /// Returns the value of the first boolean with the given `param_id`
+    /// or `None` if no boolean uses that ID.
+    pub fn get_boolean(&self, param_id: ParamId) -> Option<bool> {
+        find_param(&self.booleans, param_id).copied()
+    }
+
+    /// Returns the texture name of the first texture with the given `param_id`
+    /// or `None` if no texture uses that ID.
+    pub fn get_texture(&self, param_id: ParamId) -> Option<&str> {
+        find_param(&self.textures, param_id).map(String::as_str)
+    }
+}
+
+fn find_param<T>(params: &[ParamData<T>], param_id: ParamId) -> Option<&T> {
+    params
+        .iter()
+        .find(|p| p.param_id == param_id)
+        .map(|p| &p.data)
+}
+
 /// A material value identified by [param_id](struct.ParamData.html#structfield.param_id).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -153,6 +216,10 @@ pub struct SamplerData {
     pub lod_bias: f32,
     /// The amount of anisotropic filtering to used.
     /// A value of [None] disables anisotropic filtering.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
     pub max_anisotropy: Option<MaxAnisotropy>,
 }
 
@@ -410,7 +477,15 @@ impl TryFrom<&MatlData> for Matl {
     fn try_from(value: &MatlData) -> Result<Self, Self::Error> {
         match (value.major_version, value.minor_version) {
             (1, 6) => Ok(Self::V16 {
-                entries: value.entries.iter().map(Into::into).collect_vec().into(),
+                entries: value
+                    .entries
+                    .iter()
+                    .map(|e| {
+                        check_entry_strings(e)?;
+                        Ok(e.into())
+                    })
+                    .collect::<Result<Vec<_>, error::Error>>()?
+                    .into(),
             }),
             _ => Err(error::Error::UnsupportedVersion {
                 major_version: value.major_version,
@@ -420,6 +495,16 @@ impl TryFrom<&MatlData> for Matl {
     }
 }
 
+// Check for null bytes since the conversion to MatlEntryV16 can't fail.
+fn check_entry_strings(e: &MatlEntryData) -> Result<(), ssbh_lib::StringError> {
+    SsbhString::try_from_str(&e.material_label)?;
+    SsbhString::try_from_str(&e.shader_label)?;
+    for texture in &e.textures {
+        SsbhString::try_from_str(&texture.data)?;
+    }
+    Ok(())
+}
+
 impl From<&MatlEntryV16> for MatlEntryData {
     fn from(e: &MatlEntryV16) -> Self {
         Self {
@@ -598,6 +683,69 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn create_matl_interior_nul() {
+        let entry = MatlEntryData {
+            material_label: "a".into(),
+            shader_label: "b".into(),
+            blend_states: Vec::new(),
+            floats: Vec::new(),
+            booleans: Vec::new(),
+            vectors: Vec::new(),
+            rasterizer_states: Vec::new(),
+            samplers: Vec::new(),
+            textures: vec![TextureParam::new(ParamId::Texture0, "ab\0cd".into())],
+        };
+        let result = Matl::try_from(&MatlData {
+            major_version: 1,
+            minor_version: 6,
+            entries: vec![entry],
+        });
+
+        assert!(matches!(
+            result,
+            Err(error::Error::String(ssbh_lib::StringError::InteriorNul {
+                position: 2
+            }))
+        ));
+    }
+
+    #[test]
+    fn get_params_by_id() {
+        let entry = MatlEntryData {
+            material_label: "a".into(),
+            shader_label: "b".into(),
+            blend_states: Vec::new(),
+            floats: vec![FloatParam::new(ParamId::CustomFloat8, 0.7)],
+            booleans: vec![BooleanParam::new(ParamId::CustomBoolean1, true)],
+            vectors: vec![
+                Vector4Param::new(ParamId::CustomVector0, Vector4::new(0.0, 0.0, 0.0, 0.0)),
+                Vector4Param::new(ParamId::CustomVector13, Vector4::new(1.0, 2.0, 3.0, 4.0)),
+            ],
+            rasterizer_states: Vec::new(),
+            samplers: Vec::new(),
+            textures: vec![TextureParam::new(
+                ParamId::Texture0,
+                "alp_mario_001_col".into(),
+            )],
+        };
+
+        assert_eq!(
+            Some(Vector4::new(1.0, 2.0, 3.0, 4.0)),
+            entry.get_vector4(ParamId::CustomVector13)
+        );
+        assert_eq!(Some(0.7), entry.get_float(ParamId::CustomFloat8));
+        assert_eq!(Some(true), entry.get_boolean(ParamId::CustomBoolean1));
+        assert_eq!(
+            Some("alp_mario_001_col"),
+            entry.get_texture(ParamId::Texture0)
+        );
+
+        // Parameters with the wrong type are not returned.
+        assert_eq!(None, entry.get_float(ParamId::CustomVector13));
+        assert_eq!(None, entry.get_texture(ParamId::Texture1));
+    }
+
     #[test]
     fn create_empty_matl_data_1_6() {
         let data = MatlData::try_from(Matl::V16 {
@@ -9,6 +9,7 @@
 //!
 //! Bounding information is recalculated on export and is unlikely to match the original file
 //! due to algorithmic differences and floating point errors.
+use crate::adj_data::AdjEntryData;
 use ahash::{AHashMap, AHashSet};
 use binrw::io::Seek;
 use binrw::{io::Cursor, BinRead};
@@ -29,7 +30,7 @@ use ssbh_lib::{
     },
     SsbhByteBuffer,
 };
-use ssbh_lib::{Matrix3x3, SsbhArray, Vector3, Version};
+use ssbh_lib::{Matrix3x3, SsbhArray, SsbhString, Vector3, Version};
 use ssbh_write::SsbhWrite;
 use std::collections::{HashMap, HashSet};
 use std::convert::{TryFrom, TryInto};
@@ -128,9 +129,31 @@ pub mod error {
             mesh_object_subindex: u64,
         },
 
+        /// A string contains null bytes and can't be converted to a null terminated string.
+        #[error(transparent)]
+        String(#[from] ssbh_lib::StringError),
+
         /// An error occurred while writing data to a buffer.
         #[error(transparent)]
         Io(#[from] std::io::Error),
+
+        /// The component count is not supported by [VectorData](super::VectorData).
+        #[error(
+            "Component count {} is not supported. Expected 2, 3, or 4 components.",
+            component_count
+        )]
+        UnsupportedComponentCount { component_count: usize },
+
+        /// The length of the flattened data is not a multiple of the component count.
+        #[error(
+            "Flattened data length {} is not a multiple of the component count {}.",
+            length,
+            component_count
+        )]
+        FlatDataLengthMismatch {
+            length: usize,
+            component_count: usize,
+        },
     }
 
     /// Errors while reading mesh attribute data.
@@ -467,6 +490,93 @@ pub struct MeshData {
     pub objects: Vec<MeshObjectData>,
 }
 
+impl MeshData {
+    /// The `(major_version, minor_version)` pairs supported for reading and writing.
+    pub fn supported_versions() -> &'static [(u16, u16)] {
+        &[(1, 8), (1, 9), (1, 10)]
+    }
+
+    /// Converts the data and writes to `writer` without first buffering the entire file in memory.
+    /// The output is identical to [SsbhData::write](crate::SsbhData::write).
+    ///
+    /// The vertex buffers, index buffer, and rigging buffers are written after all other data.
+    /// The offsets for these buffers are patched after writing each buffer to the end of `writer`,
+    /// and each buffer is released as soon as it is written.
+    /// This avoids the extra copy of the file used by [SsbhData::write_to_file](crate::SsbhData::write_to_file)
+    /// and performs fewer seeks on `writer` than [SsbhData::write](crate::SsbhData::write).
+    pub fn write_streaming<W: Write + Seek>(&self, writer: &mut W) -> Result<(), error::Error> {
+        let mut mesh = create_mesh(self)?;
+
+        // Write the remaining data first since the buffers are stored at the end of the file.
+        match &mut mesh {
+            Mesh::V8(inner) => {
+                let buffers = MeshBuffers::take(inner);
+                mesh.write(writer)?;
+                buffers.write(writer)?;
+            }
+            Mesh::V9(inner) => {
+                let buffers = MeshBuffers::take(inner);
+                mesh.write(writer)?;
+                buffers.write(writer)?;
+            }
+            Mesh::V10(inner) => {
+                let buffers = MeshBuffers::take(inner);
+                mesh.write(writer)?;
+                buffers.write(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// The fields of a mesh that are written after all the other data in the file.
+struct MeshBuffers<W: Weight> {
+    vertex_buffers_offset: u64,
+    vertex_buffers: SsbhArray<SsbhByteBuffer>,
+    index_buffer: SsbhByteBuffer,
+    rigging_buffers: SsbhArray<RiggingGroup<W>>,
+}
+
+impl<W: Weight> MeshBuffers<W> {
+    fn take<A: Attribute>(inner: &mut MeshInner<A, W>) -> Self {
+        // The SSBH header and version take up 0x18 bytes before the mesh fields.
+        let vertex_buffers_offset = 0x18
+            + inner.model_name.size_in_bytes()
+            + inner.bounding_info.size_in_bytes()
+            + inner.unk1.size_in_bytes()
+            + inner.objects.size_in_bytes()
+            + inner.buffer_sizes.size_in_bytes()
+            + inner.polygon_index_size.size_in_bytes();
+
+        Self {
+            vertex_buffers_offset,
+            vertex_buffers: std::mem::replace(&mut inner.vertex_buffers, SsbhArray::new()),
+            index_buffer: std::mem::replace(&mut inner.index_buffer, SsbhByteBuffer::new()),
+            rigging_buffers: std::mem::replace(&mut inner.rigging_buffers, SsbhArray::new()),
+        }
+    }
+
+    fn write<T: Write + Seek>(self, writer: &mut T) -> std::io::Result<()> {
+        // Empty arrays don't write any data, so the buffers start at the end of the file.
+        let mut data_ptr = writer.seek(SeekFrom::End(0))?;
+
+        // Each field leaves the writer positioned at the next field.
+        // Release each buffer after writing to reduce memory usage.
+        writer.seek(SeekFrom::Start(self.vertex_buffers_offset))?;
+        self.vertex_buffers.ssbh_write(writer, &mut data_ptr)?;
+        drop(self.vertex_buffers);
+        self.index_buffer.ssbh_write(writer, &mut data_ptr)?;
+        drop(self.index_buffer);
+        self.rigging_buffers.ssbh_write(writer, &mut data_ptr)?;
+
+        // Match the alignment for the end of the file from Mesh.
+        let end = writer.seek(SeekFrom::End(0))?;
+        ssbh_write::write_padding(writer, (end.div_ceil(8) * 8 - end) as usize)?;
+        Ok(())
+    }
+}
+
 impl TryFrom<MeshData> for Mesh {
     type Error = error::Error;
 
@@ -599,6 +709,46 @@ impl MeshObjectData {
             Err(error::Error::AttributeDataLengthMismatch)
         }
     }
+
+    /// Recalculates the first normal attribute from the first position attribute
+    /// by averaging the normals of the adjacent faces in `adj` for each vertex.
+    /// See [calculate_smooth_normals_with_adjacency].
+    ///
+    /// Adjacency includes faces from vertices split along UV seams with the same position,
+    /// so the normals will be smooth across seams.
+    /// A `"Normal0"` attribute is added if there are no normal attributes.
+    /// The normals are not changed if there are no position attributes.
+    pub fn recalculate_normals_with_adjacency(&mut self, adj: &AdjEntryData) {
+        let positions = match self.positions.first() {
+            Some(positions) => &positions.data,
+            None => return,
+        };
+        let normals = calculate_smooth_normals_with_adjacency(
+            positions,
+            &adj.vertex_adjacency,
+            crate::adj_data::MAX_ADJACENT_VERTICES,
+        );
+
+        match self.normals.first_mut() {
+            Some(attribute) => {
+                // Preserve the component count and any existing w components.
+                attribute.data = match &attribute.data {
+                    VectorData::Vector4(old) => VectorData::Vector4(
+                        normals
+                            .iter()
+                            .enumerate()
+                            .map(|(i, [x, y, z])| [*x, *y, *z, old.get(i).map_or(0.0, |v| v[3])])
+                            .collect(),
+                    ),
+                    _ => VectorData::Vector3(normals),
+                };
+            }
+            None => self.normals.push(AttributeData {
+                name: "Normal0".to_string(),
+                data: VectorData::Vector3(normals),
+            }),
+        }
+    }
 }
 
 fn read_mesh_objects(mesh: &Mesh) -> Result<Vec<MeshObjectData>, Box<dyn Error>> {
@@ -772,14 +922,14 @@ fn create_rigging_buffers<W: Weight>(
         let mut buffers = Vec::new();
         for i in &mesh_object.bone_influences {
             let buffer = BoneBuffer {
-                bone_name: i.bone_name.clone().into(),
+                bone_name: SsbhString::try_from_str(&i.bone_name)?,
                 data: W::from_weights(&i.vertex_weights)?,
             };
             buffers.push(buffer);
         }
 
         let buffer = RiggingGroup {
-            mesh_object_name: mesh_object.name.clone().into(),
+            mesh_object_name: SsbhString::try_from_str(&mesh_object.name)?,
             mesh_object_subindex: mesh_object.subindex,
             flags,
             buffers: buffers.into(),
@@ -988,9 +1138,9 @@ fn create_mesh_object<A: Attribute, F: Fn(&MeshObjectData) -> MeshAttributes<A>>
     };
 
     let mesh_object = MeshObject {
-        name: data.name.clone().into(),
+        name: SsbhString::try_from_str(&data.name)?,
         subindex: data.subindex,
-        parent_bone_name: data.parent_bone_name.clone().into(),
+        parent_bone_name: SsbhString::try_from_str(&data.parent_bone_name)?,
         vertex_count: vertex_count as u32,
         vertex_index_count: data.vertex_indices.len() as u32,
         unk2: 3, // TODO: Does this mean triangle faces?
@@ -1157,6 +1307,119 @@ pub fn calculate_smooth_normals(positions: &VectorData, vertex_indices: &[u32])
     normals.iter().map(|t| t.to_array()).collect()
 }
 
+/// Calculates smooth per-vertex normals by averaging the normals of adjacent faces
+/// from vertex adjacency data like [AdjEntryData::vertex_adjacency].
+///
+/// Each vertex has `max_adjacent` entries with pairs of vertex indices for each adjacent face.
+/// Larger faces contribute more to the final normal.
+/// Adjacent vertex indices that are negative or out of range are ignored.
+/**
+```rust
+# use ssbh_data::mesh_data::{calculate_smooth_normals_with_adjacency, VectorData};
+# use ssbh_data::adj_data::{AdjEntryData, MAX_ADJACENT_VERTICES};
+let positions = vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+let adj = AdjEntryData::from_triangle_faces(0, &positions, &[0, 1, 2]).unwrap();
+
+let normals = calculate_smooth_normals_with_adjacency(
+    &VectorData::Vector3(positions),
+    &adj.vertex_adjacency,
+    MAX_ADJACENT_VERTICES,
+);
+assert_eq!(vec![[0.0, 0.0, 1.0]; 3], normals);
+```
+ */
+pub fn calculate_smooth_normals_with_adjacency(
+    positions: &VectorData,
+    vertex_adjacency: &[i16],
+    max_adjacent: usize,
+) -> Vec<[f32; 3]> {
+    let positions = positions.to_glam_vec3a();
+    let position = |i: i16| usize::try_from(i).ok().and_then(|i| positions.get(i));
+
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let adjacent = vertex_adjacency
+                .get(i * max_adjacent..(i + 1) * max_adjacent)
+                .unwrap_or_default();
+
+            adjacent
+                .chunks_exact(2)
+                .filter_map(|face| Some((position(face[0])?, position(face[1])?)))
+                .map(|(p1, p2)| (*p1 - *p).cross(*p2 - *p))
+                .fold(geometry_tools::glam::Vec3A::ZERO, |sum, n| sum + n)
+                .normalize_or_zero()
+                .to_array()
+        })
+        .collect()
+}
+
+/// Converts `position` to a key suitable for hashing or comparing positions for equality.
+/// Each component is bit cast to [u32] after replacing `-0.0` with `0.0` and all NaN values with [f32::NAN].
+/// This allows grouping duplicate vertices using a [HashMap](std::collections::HashMap) or [Eq].
+/**
+```rust
+# use ssbh_data::mesh_data::position_key;
+assert_eq!(position_key([0.0, 1.0]), position_key([-0.0, 1.0]));
+assert_ne!(position_key([0.0, 1.0]), position_key([0.0, 2.0]));
+```
+ */
+pub fn position_key<const N: usize>(position: [f32; N]) -> [u32; N] {
+    position.map(|f| {
+        if f.is_nan() {
+            f32::NAN.to_bits()
+        } else if f == 0.0 {
+            0.0f32.to_bits()
+        } else {
+            f.to_bits()
+        }
+    })
+}
+
+/// Strips the tags from a mesh object's full name that aren't necessary for identification.
+/// This is the name convention used for the mesh object names in [MeshExData](crate::meshex_data::MeshExData).
+///
+/// Everything starting from the first `"_VIS"` or `"_O_"` is removed.
+/// Names without either tag have a trailing `"Shape"` removed,
+/// which is Autodesk Maya's convention for shape nodes.
+/**
+```rust
+# use ssbh_data::mesh_data::strip_mesh_name_tags;
+assert_eq!("face_default", strip_mesh_name_tags("face_default_O_V_VISShape"));
+assert_eq!("FaceBaseM", strip_mesh_name_tags("FaceBaseM_O_OBJ_NSCShape"));
+assert_eq!("sampleRing", strip_mesh_name_tags("sampleRingShape"));
+assert_eq!("body", strip_mesh_name_tags("body"));
+```
+ */
+// TODO: Is there a cleaner way of writing this?
+pub fn strip_mesh_name_tags(full_name: &str) -> String {
+    let vis_index = full_name.find("_VIS");
+    let o_index = full_name.find("_O_");
+    match (vis_index, o_index) {
+        (None, None) => {
+            // Handle the special case where the name only contains shape.
+            if full_name.ends_with("Shape") {
+                full_name
+                    .rfind("Shape")
+                    .and_then(|end_index| full_name.get(..end_index))
+                    .unwrap_or(full_name)
+                    .to_string()
+            } else {
+                full_name.to_string()
+            }
+        }
+        _ => {
+            // Unwrap first since we don't want None < Some.
+            let end_index = std::cmp::min(
+                vis_index.unwrap_or(full_name.len()),
+                o_index.unwrap_or(full_name.len()),
+            );
+            full_name.get(..end_index).unwrap_or(full_name).to_string()
+        }
+    }
+}
+
 /// Calculates smooth per-vertex tangents by averaging over the vertices in each face.
 /// See [geometry_tools::vectors::calculate_tangents](geometry_tools::vectors::calculate_tangents).
 pub fn calculate_tangents_vec4(
@@ -1267,8 +1530,98 @@ pub fn read_data<R: Read + Seek, TIn: BinRead<Args = ()>, TOut: From<TIn>>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_hex_eq;
     use hexlit::hex;
 
+    fn assert_normals_eq(expected: &[[f32; 3]], actual: &VectorData) {
+        match actual {
+            VectorData::Vector3(actual) => {
+                assert_eq!(expected.len(), actual.len());
+                for (e, a) in expected.iter().zip(actual) {
+                    for (e, a) in e.iter().zip(a) {
+                        assert!((e - a).abs() < 0.0001, "{:?} != {:?}", expected, actual);
+                    }
+                }
+            }
+            _ => panic!("Expected Vector3 normals."),
+        }
+    }
+
+    #[test]
+    fn recalculate_normals_with_adjacency_folded_quad() {
+        // Two triangles sharing the edge from vertex 0 to vertex 2.
+        let positions = vec![
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ];
+        let vertex_indices = vec![0, 1, 2, 0, 2, 3];
+        let adj = AdjEntryData::from_triangle_faces(0, &positions, &vertex_indices).unwrap();
+
+        let mut object = MeshObjectData {
+            vertex_indices,
+            positions: vec![AttributeData {
+                name: "Position0".to_string(),
+                data: VectorData::Vector3(positions),
+            }],
+            ..Default::default()
+        };
+        object.recalculate_normals_with_adjacency(&adj);
+
+        // The shared edge vertices average the normals of both faces.
+        let s = 0.5f32.sqrt();
+        assert_eq!("Normal0", object.normals[0].name);
+        assert_normals_eq(
+            &[[s, 0.0, s], [0.0, 0.0, 1.0], [s, 0.0, s], [1.0, 0.0, 0.0]],
+            &object.normals[0].data,
+        );
+    }
+
+    #[test]
+    fn recalculate_normals_with_adjacency_split_vertex() {
+        // Vertex 0 and vertex 3 have the same position but are not shared by any faces.
+        let positions = vec![
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ];
+        let vertex_indices = vec![0, 1, 2, 3, 4, 5];
+        let adj = AdjEntryData::from_triangle_faces(0, &positions, &vertex_indices).unwrap();
+
+        let mut object = MeshObjectData {
+            vertex_indices,
+            positions: vec![AttributeData {
+                name: "Position0".to_string(),
+                data: VectorData::Vector3(positions),
+            }],
+            normals: vec![AttributeData {
+                name: "Normal0".to_string(),
+                data: VectorData::Vector4(vec![[0.0, 0.0, 0.0, 1.0]; 6]),
+            }],
+            ..Default::default()
+        };
+        object.recalculate_normals_with_adjacency(&adj);
+
+        // The split vertices have smooth normals across the seam.
+        let s = 0.5f32.sqrt();
+        match &object.normals[0].data {
+            VectorData::Vector4(normals) => {
+                assert_eq!(6, normals.len());
+                for i in [0, 3] {
+                    assert!((normals[i][0] - s).abs() < 0.0001);
+                    assert!(normals[i][1].abs() < 0.0001);
+                    assert!((normals[i][2] - s).abs() < 0.0001);
+                }
+                assert!(normals.iter().all(|n| n[3] == 1.0));
+            }
+            _ => panic!("Expected Vector4 normals."),
+        }
+    }
+
     #[test]
     fn read_data_count0() {
         let mut reader = Cursor::new(hex!("01020304"));
@@ -1588,6 +1941,102 @@ mod tests {
         ));
     }
 
+    fn write_streaming_object(name: &str, bone_name: &str) -> MeshObjectData {
+        MeshObjectData {
+            name: name.to_owned(),
+            positions: vec![AttributeData {
+                name: "Position0".to_owned(),
+                data: VectorData::Vector3(vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]),
+            }],
+            normals: vec![AttributeData {
+                name: "Normal0".to_owned(),
+                data: VectorData::Vector4(vec![[0.0, 0.0, 1.0, 0.0]; 3]),
+            }],
+            texture_coordinates: vec![AttributeData {
+                name: "map1".to_owned(),
+                data: VectorData::Vector2(vec![[0.0, 0.5], [0.5, 1.0], [1.0, 0.0]]),
+            }],
+            // Use an odd number of u16 indices to test buffer alignment.
+            vertex_indices: vec![0, 1, 2],
+            bone_influences: vec![BoneInfluence {
+                bone_name: bone_name.to_owned(),
+                vertex_weights: vec![VertexWeight {
+                    vertex_index: 1,
+                    vertex_weight: 0.5,
+                }],
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn write_streaming_matches_write() {
+        use crate::SsbhData;
+
+        for (major_version, minor_version) in MeshData::supported_versions() {
+            let data = MeshData {
+                major_version: *major_version,
+                minor_version: *minor_version,
+                objects: vec![
+                    write_streaming_object("a", "bone1"),
+                    write_streaming_object("b", "bone2"),
+                ],
+            };
+
+            let mut expected = Cursor::new(Vec::new());
+            data.write(&mut expected).unwrap();
+
+            let mut actual = Cursor::new(Vec::new());
+            data.write_streaming(&mut actual).unwrap();
+
+            assert_hex_eq!(expected.get_ref(), actual.get_ref());
+        }
+    }
+
+    #[test]
+    fn write_streaming_empty() {
+        use crate::SsbhData;
+
+        let data = MeshData {
+            major_version: 1,
+            minor_version: 10,
+            objects: Vec::new(),
+        };
+
+        let mut expected = Cursor::new(Vec::new());
+        data.write(&mut expected).unwrap();
+
+        let mut actual = Cursor::new(Vec::new());
+        data.write_streaming(&mut actual).unwrap();
+
+        assert_hex_eq!(expected.get_ref(), actual.get_ref());
+    }
+
+    #[test]
+    fn write_streaming_buffered_writer() {
+        use crate::SsbhData;
+        use std::io::BufWriter;
+
+        let data = MeshData {
+            major_version: 1,
+            minor_version: 10,
+            objects: vec![
+                write_streaming_object("a", "bone1"),
+                write_streaming_object("b", "bone2"),
+            ],
+        };
+
+        let mut expected = Cursor::new(Vec::new());
+        data.write(&mut expected).unwrap();
+
+        // Seeking flushes any buffered data before patching the buffer offsets.
+        let mut writer = BufWriter::with_capacity(16, Cursor::new(Vec::new()));
+        data.write_streaming(&mut writer).unwrap();
+        let actual = writer.into_inner().unwrap();
+
+        assert_hex_eq!(expected.get_ref(), actual.get_ref());
+    }
+
     #[test]
     fn create_mesh_1_10() {
         let mesh = create_mesh(&MeshData {
@@ -2213,4 +2662,26 @@ mod tests {
             })
         ));
     }
+
+    #[test]
+    fn position_key_signed_zero() {
+        assert_eq!(
+            position_key([0.0, 0.0, 0.0]),
+            position_key([-0.0, 0.0, -0.0])
+        );
+    }
+
+    #[test]
+    fn position_key_nan() {
+        assert_eq!(
+            position_key([f32::NAN, 1.0]),
+            position_key([-f32::NAN, 1.0])
+        );
+        assert_ne!(position_key([f32::NAN, 1.0]), position_key([0.0, 1.0]));
+    }
+
+    #[test]
+    fn position_key_distinct() {
+        assert_ne!(position_key([1.0, 2.0]), position_key([1.0, -2.0]));
+    }
 }
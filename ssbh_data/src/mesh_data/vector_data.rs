@@ -6,9 +6,10 @@ use half::f16;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use ssbh_lib::formats::mesh::{AttributeDataTypeV10, AttributeDataTypeV8};
+use ssbh_lib::Vector3;
 use std::ops::Mul;
 
-use super::{DataType, Half};
+use super::{error::Error, DataType, Half};
 
 /// The data for a vertex attribute.
 ///
@@ -48,6 +49,50 @@ impl VectorData {
         self.len() == 0
     }
 
+    /// Creates vectors with `component_count` many components from the flattened `values`.
+    /// The length of `values` must be a multiple of `component_count`.
+    /// The supported component counts are 2, 3, and 4.
+    /**
+    ```rust
+    # use ssbh_data::mesh_data::VectorData;
+    let data = VectorData::from_flat(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3).unwrap();
+    assert_eq!(VectorData::Vector3(vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), data);
+    ```
+     */
+    pub fn from_flat(values: &[f32], component_count: usize) -> Result<Self, Error> {
+        if !matches!(component_count, 2..=4) {
+            return Err(Error::UnsupportedComponentCount { component_count });
+        }
+        if !values.chunks_exact(component_count).remainder().is_empty() {
+            return Err(Error::FlatDataLengthMismatch {
+                length: values.len(),
+                component_count,
+            });
+        }
+
+        match component_count {
+            2 => Ok(VectorData::Vector2(chunk_flat(values))),
+            3 => Ok(VectorData::Vector3(chunk_flat(values))),
+            _ => Ok(VectorData::Vector4(chunk_flat(values))),
+        }
+    }
+
+    /// Flattens the components for all vectors into a single [Vec].
+    /**
+    ```rust
+    # use ssbh_data::mesh_data::VectorData;
+    let data = VectorData::Vector2(vec![[1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(vec![1.0, 2.0, 3.0, 4.0], data.to_flat());
+    ```
+     */
+    pub fn to_flat(&self) -> Vec<f32> {
+        match self {
+            VectorData::Vector2(v) => v.iter().flatten().copied().collect(),
+            VectorData::Vector3(v) => v.iter().flatten().copied().collect(),
+            VectorData::Vector4(v) => v.iter().flatten().copied().collect(),
+        }
+    }
+
     /// Pads the data to 4 components per vector with a specified w component.
     /// This includes replacing the w component for [VectorData::Vector4].
     /**
@@ -72,6 +117,92 @@ impl VectorData {
         }
     }
 
+    /// Converts the data to 3 components per vector.
+    /// The w component for [VectorData::Vector4] is removed,
+    /// and the z component for [VectorData::Vector2] is set to `fill`.
+    /**
+    ```rust
+    # use ssbh_data::mesh_data::VectorData;
+    let data = VectorData::Vector4(vec![[1.0, 2.0, 3.0, 4.0]]);
+    assert_eq!(VectorData::Vector3(vec![[1.0, 2.0, 3.0]]), data.to_vector3(0.0));
+
+    let data = VectorData::Vector2(vec![[1.0, 2.0]]);
+    assert_eq!(VectorData::Vector3(vec![[1.0, 2.0, 0.0]]), data.to_vector3(0.0));
+    ```
+     */
+    pub fn to_vector3(&self, fill: f32) -> VectorData {
+        match self {
+            VectorData::Vector2(data) => {
+                VectorData::Vector3(data.iter().map(|[x, y]| [*x, *y, fill]).collect())
+            }
+            VectorData::Vector3(data) => VectorData::Vector3(data.clone()),
+            VectorData::Vector4(data) => {
+                VectorData::Vector3(data.iter().map(|[x, y, z, _]| [*x, *y, *z]).collect())
+            }
+        }
+    }
+
+    /// Converts the data to 4 components per vector.
+    /// Missing components are set to `fill`.
+    /// Unlike [VectorData::to_vec4_with_w], the w component for [VectorData::Vector4] is preserved.
+    /**
+    ```rust
+    # use ssbh_data::mesh_data::VectorData;
+    let data = VectorData::Vector3(vec![[1.0, 2.0, 3.0]]);
+    assert_eq!(VectorData::Vector4(vec![[1.0, 2.0, 3.0, 1.0]]), data.to_vector4(1.0));
+    ```
+     */
+    pub fn to_vector4(&self, fill: f32) -> VectorData {
+        match self {
+            VectorData::Vector2(data) => {
+                VectorData::Vector4(data.iter().map(|[x, y]| [*x, *y, fill, fill]).collect())
+            }
+            VectorData::Vector3(data) => {
+                VectorData::Vector4(data.iter().map(|[x, y, z]| [*x, *y, *z, fill]).collect())
+            }
+            VectorData::Vector4(data) => VectorData::Vector4(data.clone()),
+        }
+    }
+
+    /// Computes the axis-aligned bounding box as `(min, max)` or `None` if there are no points.
+    /// The z component for [VectorData::Vector2] is treated as 0,
+    /// and the w component for [VectorData::Vector4] is ignored.
+    /**
+    ```rust
+    # use ssbh_data::mesh_data::VectorData;
+    # use ssbh_lib::Vector3;
+    let data = VectorData::Vector3(vec![[1.0, -2.0, 3.0], [-1.0, 2.0, 0.5]]);
+    assert_eq!(
+        Some((Vector3::new(-1.0, -2.0, 0.5), Vector3::new(1.0, 2.0, 3.0))),
+        data.bounding_box()
+    );
+
+    assert_eq!(None, VectorData::Vector2(Vec::new()).bounding_box());
+    ```
+     */
+    pub fn bounding_box(&self) -> Option<(Vector3, Vector3)> {
+        let points: Vec<_> = match self {
+            VectorData::Vector2(data) => data
+                .iter()
+                .map(|[x, y]| Vector3::new(*x, *y, 0f32))
+                .collect(),
+            VectorData::Vector3(data) => data
+                .iter()
+                .map(|[x, y, z]| Vector3::new(*x, *y, *z))
+                .collect(),
+            VectorData::Vector4(data) => data
+                .iter()
+                .map(|[x, y, z, _]| Vector3::new(*x, *y, *z))
+                .collect(),
+        };
+
+        let (first, rest) = points.split_first()?;
+        Some(
+            rest.iter()
+                .fold((*first, *first), |(min, max), p| (min.min(*p), max.max(*p))),
+        )
+    }
+
     pub(crate) fn to_glam_vec2(&self) -> Vec<geometry_tools::glam::Vec2> {
         match self {
             VectorData::Vector2(data) => data
@@ -162,6 +293,13 @@ impl VectorData {
     }
 }
 
+fn chunk_flat<const N: usize>(values: &[f32]) -> Vec<[f32; N]> {
+    values
+        .chunks_exact(N)
+        .map(|c| c.try_into().unwrap())
+        .collect()
+}
+
 #[derive(Debug, PartialEq)]
 pub enum VersionedVectorData {
     V8(Vec<VectorDataV8>),
@@ -403,6 +541,94 @@ mod tests {
 
     // TODO: Test conversions for versioned vector data.
 
+    #[test]
+    fn flat_round_trip() {
+        let values: Vec<f32> = (0..12).map(|i| i as f32).collect();
+        for component_count in [2, 3, 4] {
+            let data = VectorData::from_flat(&values, component_count).unwrap();
+            assert_eq!(12 / component_count, data.len());
+            assert_eq!(values, data.to_flat());
+        }
+    }
+
+    #[test]
+    fn vector3_to_vector4_round_trip() {
+        let data = VectorData::Vector3(vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let widened = data.to_vector4(1.0);
+        assert_eq!(
+            VectorData::Vector4(vec![[1.0, 2.0, 3.0, 1.0], [4.0, 5.0, 6.0, 1.0]]),
+            widened
+        );
+        assert_eq!(data, widened.to_vector3(0.0));
+    }
+
+    #[test]
+    fn vector2_to_vector3_and_vector4() {
+        let data = VectorData::Vector2(vec![[1.0, 2.0]]);
+        assert_eq!(
+            VectorData::Vector3(vec![[1.0, 2.0, 0.5]]),
+            data.to_vector3(0.5)
+        );
+        assert_eq!(
+            VectorData::Vector4(vec![[1.0, 2.0, 0.5, 0.5]]),
+            data.to_vector4(0.5)
+        );
+    }
+
+    #[test]
+    fn bounding_box_vector4_ignores_w() {
+        let data = VectorData::Vector4(vec![
+            [1.0, 2.0, 3.0, 100.0],
+            [-4.0, 5.0, -6.0, -100.0],
+            [0.0, -7.0, 8.0, 0.0],
+        ]);
+        assert_eq!(
+            Some((Vector3::new(-4.0, -7.0, -6.0), Vector3::new(1.0, 5.0, 8.0))),
+            data.bounding_box()
+        );
+    }
+
+    #[test]
+    fn bounding_box_vector2_zero_z() {
+        let data = VectorData::Vector2(vec![[1.0, -2.0], [3.0, 4.0]]);
+        assert_eq!(
+            Some((Vector3::new(1.0, -2.0, 0.0), Vector3::new(3.0, 4.0, 0.0))),
+            data.bounding_box()
+        );
+    }
+
+    #[test]
+    fn from_flat_empty() {
+        assert_eq!(
+            VectorData::Vector4(Vec::new()),
+            VectorData::from_flat(&[], 4).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_flat_invalid_length() {
+        let result = VectorData::from_flat(&[1.0, 2.0, 3.0, 4.0], 3);
+        assert!(matches!(
+            result,
+            Err(Error::FlatDataLengthMismatch {
+                length: 4,
+                component_count: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn from_flat_invalid_component_count() {
+        for component_count in [0, 1, 5] {
+            let result = VectorData::from_flat(&[1.0; 10], component_count);
+            assert!(matches!(
+                result,
+                Err(Error::UnsupportedComponentCount { component_count: c }) if c == component_count
+            ));
+        }
+    }
+
     #[test]
     fn read_vector_data_count0() {
         let mut reader = Cursor::new(hex!("01020304"));
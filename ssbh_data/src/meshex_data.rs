@@ -4,21 +4,53 @@
 //! Unmodified files are not guaranteed to be binary identical after saving.
 //! Some bounding information is recalculated on export and is unlikely to match the original file
 //! due to algorithmic differences and floating point errors.
-use crate::mesh_data::MeshObjectData;
-use geometry_tools::bounding::{
-    calculate_bounding_sphere_from_points, calculate_bounding_sphere_from_spheres,
-};
+use crate::mesh_data::{strip_mesh_name_tags, MeshObjectData};
+use geometry_tools::bounding::calculate_bounding_sphere_from_points;
 use itertools::Itertools;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 pub use ssbh_lib::formats::mesh::BoundingSphere;
 use ssbh_lib::formats::meshex::AllData;
-use ssbh_lib::{formats::meshex::MeshEx, Ptr64, Vector3};
+use ssbh_lib::{formats::meshex::MeshEx, CString, Ptr64, Vector3, Vector4};
+
+pub mod error {
+    use thiserror::Error;
+
+    /// Errors while converting or modifying [MeshExData](super::MeshExData).
+    #[derive(Debug, Error)]
+    pub enum Error {
+        /// The group index mapping does not assign a unique new index to each group.
+        #[error(
+            "The mapping is not a permutation of the {} group indices.",
+            group_count
+        )]
+        InvalidGroupMapping { group_count: usize },
+
+        /// An entry for the group has no corresponding entry flags.
+        #[error(
+            "Entry {} for mesh object group {} is out of range for the entry flags.",
+            entry_index,
+            group_index
+        )]
+        EntryFlagIndexOutOfRange {
+            group_index: usize,
+            entry_index: usize,
+        },
+
+        /// A string contains null bytes and can't be converted to a null terminated string.
+        #[error(transparent)]
+        String(#[from] ssbh_lib::StringError),
+
+        /// An error occurred while writing data to a buffer.
+        #[error(transparent)]
+        Io(#[from] std::io::Error),
+    }
+}
 
 /// The data associated with a [MeshEx] file.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct MeshExData {
     pub mesh_object_groups: Vec<MeshObjectGroupData>,
 }
@@ -36,8 +68,19 @@ pub struct MeshObjectGroupData {
     pub mesh_object_name: String,
     /// Rendering flags for each of the [MeshObjectData] in this group.
     pub entry_flags: Vec<EntryFlags>,
+    /// Unknown values for each of the [MeshObjectData] in this group.
+    /// This is usually `(0.0, 1.0, 0.0)` but some stage models use different values.
+    /// Missing elements default to `(0.0, 1.0, 0.0)` when converting to [MeshEx].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub entry_unk1: Vec<Vector3>,
 }
 
+const DEFAULT_ENTRY_UNK1: Vector3 = Vector3 {
+    x: 0.0,
+    y: 1.0,
+    z: 0.0,
+};
+
 /// Rendering related toggles for a [MeshObjectData].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -48,6 +91,29 @@ pub struct EntryFlags {
     // TODO: Preserve remaining flags?
 }
 
+impl Default for EntryFlags {
+    /// Enables both model rendering and shadow casting.
+    fn default() -> Self {
+        Self {
+            draw_model: true,
+            cast_shadow: true,
+        }
+    }
+}
+
+/// Counts of the [EntryFlags] for all groups in a [MeshExData].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct FlagSummary {
+    /// The total number of entries.
+    pub total: usize,
+    /// The number of entries with [EntryFlags::draw_model] disabled.
+    pub hidden: usize,
+    /// The number of entries with [EntryFlags::cast_shadow] disabled.
+    pub no_shadow: usize,
+}
+
 impl MeshExData {
     /// Groups `objects` by name and calculates bounding information.
     /// Flags are set to a default value.
@@ -77,156 +143,243 @@ impl MeshExData {
                         },
                         mesh_object_full_name: name.clone(),
                         mesh_object_name: strip_mesh_name_tags(name),
-                        entry_flags: group
-                            .iter()
-                            .map(|_| EntryFlags {
-                                draw_model: true,
-                                cast_shadow: true,
-                            })
-                            .collect(),
+                        entry_flags: group.iter().map(|_| EntryFlags::default()).collect(),
+                        entry_unk1: group.iter().map(|_| DEFAULT_ENTRY_UNK1).collect(),
                     }
                 })
                 .collect(),
         }
     }
+
+    /// Iterates over the [EntryFlags] for all groups as `(mesh_object_full_name, entry_index, flags)`.
+    /// The `entry_index` is the index of the flags within the group.
+    /**
+    ```rust
+    # use ssbh_data::meshex_data::MeshExData;
+    # fn list(data: &MeshExData) {
+    for (name, i, flags) in data.iter_entries() {
+        println!("{name}[{i}]: draw_model = {}", flags.draw_model);
+    }
+    # }
+    ```
+     */
+    pub fn iter_entries(&self) -> impl Iterator<Item = (&str, usize, &EntryFlags)> {
+        self.mesh_object_groups.iter().flat_map(|g| {
+            g.entry_flags
+                .iter()
+                .enumerate()
+                .map(move |(i, flags)| (g.mesh_object_full_name.as_str(), i, flags))
+        })
+    }
+
+    /// Counts the hidden and non shadow casting entries for all groups.
+    /**
+    ```rust
+    # use ssbh_data::meshex_data::MeshExData;
+    # fn report(data: &MeshExData) {
+    let summary = data.flag_summary();
+    println!("{} of {} meshes are hidden", summary.hidden, summary.total);
+    # }
+    ```
+     */
+    pub fn flag_summary(&self) -> FlagSummary {
+        self.iter_entries()
+            .fold(FlagSummary::default(), |summary, (_, _, flags)| {
+                FlagSummary {
+                    total: summary.total + 1,
+                    hidden: summary.hidden + usize::from(!flags.draw_model),
+                    no_shadow: summary.no_shadow + usize::from(!flags.cast_shadow),
+                }
+            })
+    }
+
+    /// Reorders the [mesh_object_groups](#structfield.mesh_object_groups),
+    /// where `mapping[old_index]` is the new index for the group.
+    /// The `mapping` must contain a unique new index for every group.
+    pub fn reorder_groups(&mut self, mapping: &[usize]) -> Result<(), error::Error> {
+        if mapping.len() != self.mesh_object_groups.len() || !crate::is_permutation(mapping) {
+            return Err(error::Error::InvalidGroupMapping {
+                group_count: self.mesh_object_groups.len(),
+            });
+        }
+
+        let mut groups: Vec<_> = std::mem::take(&mut self.mesh_object_groups)
+            .into_iter()
+            .zip(mapping)
+            .collect();
+        groups.sort_by_key(|(_, i)| **i);
+        self.mesh_object_groups = groups.into_iter().map(|(g, _)| g).collect();
+        Ok(())
+    }
 }
 
 // TODO: Add methods to create MeshExData from points.
 
-// TODO: Is there a cleaner way of writing this?
-// TODO: Should this be public?
-fn strip_mesh_name_tags(full_name: &str) -> String {
-    // Strip portions of a mesh object's name that aren't necessary for identification.
-    // This includes Autodesk Maya's convention of appending "Shape".
-    // Names can contain multiple tags like "face_default_O_V_VISShape" -> "face_default".
-    let vis_index = full_name.find("_VIS");
-    let o_index = full_name.find("_O_");
-    match (vis_index, o_index) {
-        (None, None) => {
-            // Handle the special case where the name only contains shape.
-            if full_name.ends_with("Shape") {
-                full_name
-                    .rfind("Shape")
-                    .and_then(|end_index| full_name.get(..end_index))
-                    .unwrap_or(full_name)
-                    .to_string()
-            } else {
-                full_name.to_string()
-            }
-        }
-        _ => {
-            // Unwrap first since we don't want None < Some.
-            let end_index = std::cmp::min(
-                vis_index.unwrap_or(full_name.len()),
-                o_index.unwrap_or(full_name.len()),
-            );
-            full_name.get(..end_index).unwrap_or(full_name).to_string()
-        }
+/// Calculates a sphere that encloses all of the `spheres`.
+/// Each sphere is stored as the center in xyz and the radius in w.
+/// Returns [Vector4::ZERO] if `spheres` is empty.
+/**
+```rust
+use ssbh_data::meshex_data::merge_bounding_spheres;
+use ssbh_lib::Vector4;
+
+let sphere = merge_bounding_spheres(&[
+    Vector4::new(-2.0, 0.0, 0.0, 1.0),
+    Vector4::new(2.0, 0.0, 0.0, 1.0),
+]);
+assert_eq!(Vector4::new(0.0, 0.0, 0.0, 3.0), sphere);
+```
+ */
+pub fn merge_bounding_spheres(spheres: &[Vector4]) -> Vector4 {
+    spheres
+        .iter()
+        .copied()
+        .reduce(merge_two_spheres)
+        .unwrap_or(Vector4::ZERO)
+}
+
+fn merge_two_spheres(a: Vector4, b: Vector4) -> Vector4 {
+    let center_a = glam::Vec3::new(a.x, a.y, a.z);
+    let center_b = glam::Vec3::new(b.x, b.y, b.z);
+    let distance = center_a.distance(center_b);
+
+    // Check if either sphere already contains the other.
+    if distance + b.w <= a.w {
+        return a;
+    }
+    if distance + a.w <= b.w {
+        return b;
     }
+
+    // The new diameter spans from the far side of a to the far side of b.
+    let radius = (distance + a.w + b.w) / 2.0;
+    let center = center_a + (center_b - center_a) * ((radius - a.w) / distance);
+    Vector4::new(center.x, center.y, center.z, radius)
 }
 
-impl From<MeshEx> for MeshExData {
-    fn from(m: MeshEx) -> Self {
-        Self::from(&m)
+impl TryFrom<MeshEx> for MeshExData {
+    type Error = error::Error;
+
+    fn try_from(m: MeshEx) -> Result<Self, Self::Error> {
+        Self::try_from(&m)
     }
 }
 
-impl From<&MeshEx> for MeshExData {
+impl TryFrom<&MeshEx> for MeshExData {
+    type Error = error::Error;
+
     // TODO: Should null pointers for non strings be considered an error?
-    fn from(m: &MeshEx) -> Self {
-        Self {
+    fn try_from(m: &MeshEx) -> Result<Self, Self::Error> {
+        let entries = m.entries.as_ref().map(|e| e.as_slice()).unwrap_or(&[]);
+        let entry_flags = m
+            .entry_flags
+            .as_ref()
+            .map(|f| f.0.as_slice())
+            .unwrap_or(&[]);
+
+        Ok(Self {
             mesh_object_groups: m
                 .mesh_object_groups
                 .as_ref()
                 .unwrap_or(&Vec::new())
                 .iter()
                 .enumerate()
-                .map(|(i, g)| MeshObjectGroupData {
-                    bounding_sphere: g.bounding_sphere,
-                    // Use empty strings for null pointers.
-                    mesh_object_full_name: g
-                        .mesh_object_full_name
-                        .as_ref()
-                        .map(|s| s.to_string_lossy())
-                        .unwrap_or_default(),
-                    mesh_object_name: g
-                        .mesh_object_name
-                        .as_ref()
-                        .map(|s| s.to_string_lossy())
-                        .unwrap_or_default(),
-                    entry_flags: m
-                        .entries
-                        .as_ref()
-                        .unwrap_or(&Vec::new())
+                .map(|(i, g)| {
+                    let entry_indices = entries
                         .iter()
                         .positions(|e| e.mesh_object_group_index as usize == i)
-                        .filter_map(|entry_index| {
-                            // TODO: Return an error for invalid indices?
-                            let entry_flags = m.entry_flags.as_ref()?.0.get(entry_index)?;
-                            Some(EntryFlags {
-                                draw_model: entry_flags.draw_model(),
-                                cast_shadow: entry_flags.cast_shadow(),
+                        .collect_vec();
+
+                    Ok(MeshObjectGroupData {
+                        bounding_sphere: g.bounding_sphere,
+                        // Use empty strings for null pointers.
+                        mesh_object_full_name: g
+                            .mesh_object_full_name
+                            .as_ref()
+                            .map(|s| s.to_string_lossy())
+                            .unwrap_or_default(),
+                        mesh_object_name: g
+                            .mesh_object_name
+                            .as_ref()
+                            .map(|s| s.to_string_lossy())
+                            .unwrap_or_default(),
+                        entry_flags: entry_indices
+                            .iter()
+                            .map(|entry_index| {
+                                let flags = entry_flags.get(*entry_index).ok_or(
+                                    error::Error::EntryFlagIndexOutOfRange {
+                                        group_index: i,
+                                        entry_index: *entry_index,
+                                    },
+                                )?;
+                                Ok(EntryFlags {
+                                    draw_model: flags.draw_model(),
+                                    cast_shadow: flags.cast_shadow(),
+                                })
                             })
-                        })
-                        .collect(),
+                            .collect::<Result<_, error::Error>>()?,
+                        entry_unk1: entry_indices.iter().map(|i| entries[*i].unk1).collect(),
+                    })
                 })
-                .collect(),
-        }
+                .collect::<Result<_, error::Error>>()?,
+        })
     }
 }
 
-impl From<MeshExData> for MeshEx {
-    fn from(m: MeshExData) -> Self {
-        Self::from(&m)
+impl TryFrom<MeshExData> for MeshEx {
+    type Error = error::Error;
+
+    fn try_from(m: MeshExData) -> Result<Self, Self::Error> {
+        Self::try_from(&m)
     }
 }
 
-impl From<&MeshExData> for MeshEx {
-    fn from(m: &MeshExData) -> Self {
-        let all_sphere = calculate_bounding_sphere_from_spheres(
+impl TryFrom<&MeshExData> for MeshEx {
+    type Error = error::Error;
+
+    fn try_from(m: &MeshExData) -> Result<Self, Self::Error> {
+        let all_sphere = merge_bounding_spheres(
             &m.mesh_object_groups
                 .iter()
                 .map(|g| {
-                    (
-                        geometry_tools::glam::Vec3A::new(
-                            g.bounding_sphere.center.x,
-                            g.bounding_sphere.center.y,
-                            g.bounding_sphere.center.z,
-                        ),
-                        g.bounding_sphere.radius,
-                    )
+                    let center = g.bounding_sphere.center;
+                    Vector4::new(center.x, center.y, center.z, g.bounding_sphere.radius)
                 })
                 .collect_vec(),
         );
-        Self {
+        Ok(Self {
             all_data: Ptr64::new(AllData {
                 bounding_sphere: BoundingSphere {
-                    center: Vector3::new(all_sphere.0.x, all_sphere.0.y, all_sphere.0.z),
-                    radius: all_sphere.1,
+                    center: Vector3::new(all_sphere.x, all_sphere.y, all_sphere.z),
+                    radius: all_sphere.w,
                 },
-                name: Ptr64::new("All".into()),
+                name: Ptr64::new(CString::try_new("All")?),
             }),
             mesh_object_groups: Ptr64::new(
                 m.mesh_object_groups
                     .iter()
-                    .map(|g| ssbh_lib::formats::meshex::MeshObjectGroup {
-                        bounding_sphere: g.bounding_sphere,
-                        mesh_object_full_name: Ptr64::new(g.mesh_object_full_name.as_str().into()),
-                        mesh_object_name: Ptr64::new(g.mesh_object_name.as_str().into()),
+                    .map(|g| {
+                        Ok(ssbh_lib::formats::meshex::MeshObjectGroup {
+                            bounding_sphere: g.bounding_sphere,
+                            mesh_object_full_name: Ptr64::new(CString::try_new(
+                                &g.mesh_object_full_name,
+                            )?),
+                            mesh_object_name: Ptr64::new(CString::try_new(&g.mesh_object_name)?),
+                        })
                     })
-                    .collect(),
+                    .collect::<Result<_, error::Error>>()?,
             ),
             entries: Ptr64::new(
                 m.mesh_object_groups
                     .iter()
                     .enumerate()
                     .flat_map(|(i, g)| {
-                        g.entry_flags
-                            .iter()
-                            .map(move |_| ssbh_lib::formats::meshex::MeshEntry {
+                        g.entry_flags.iter().enumerate().map(move |(j, _)| {
+                            ssbh_lib::formats::meshex::MeshEntry {
                                 mesh_object_group_index: i as u32,
-                                unk1: Vector3::new(0.0, 1.0, 0.0),
-                            })
+                                unk1: g.entry_unk1.get(j).copied().unwrap_or(DEFAULT_ENTRY_UNK1),
+                            }
+                        })
                     })
                     .collect(),
             ),
@@ -243,7 +396,7 @@ impl From<&MeshExData> for MeshEx {
                     .collect(),
             )),
             unk1: 0, // TODO: Preserve this value?
-        }
+        })
     }
 }
 
@@ -257,6 +410,48 @@ mod tests {
         Ptr64, Vector3,
     };
 
+    #[test]
+    fn convert_meshex_data_entry_flag_out_of_range() {
+        let group = |name: &str| MeshObjectGroup {
+            bounding_sphere: BoundingSphere {
+                center: Vector3::ZERO,
+                radius: 1.0,
+            },
+            mesh_object_full_name: Ptr64::new(name.into()),
+            mesh_object_name: Ptr64::new(name.into()),
+        };
+        let entry = |mesh_object_group_index| MeshEntry {
+            mesh_object_group_index,
+            unk1: Vector3::new(0.0, 1.0, 0.0),
+        };
+
+        // The entry for the second group has no flags.
+        let meshex = MeshEx {
+            all_data: Ptr64::new(AllData {
+                bounding_sphere: BoundingSphere {
+                    center: Vector3::ZERO,
+                    radius: 1.0,
+                },
+                name: Ptr64::new("All".into()),
+            }),
+            mesh_object_groups: Ptr64::new(vec![group("a"), group("b")]),
+            entries: Ptr64::new(vec![entry(0), entry(1)]),
+            entry_flags: Ptr64::new(ssbh_lib::formats::meshex::EntryFlags(vec![
+                ssbh_lib::formats::meshex::EntryFlag::new(),
+            ])),
+            unk1: 0,
+        };
+
+        let result = MeshExData::try_from(&meshex);
+        assert!(matches!(
+            result,
+            Err(error::Error::EntryFlagIndexOutOfRange {
+                group_index: 1,
+                entry_index: 1
+            })
+        ));
+    }
+
     #[test]
     fn convert_meshex_data() {
         let meshex = MeshEx {
@@ -332,6 +527,7 @@ mod tests {
                             cast_shadow: false,
                         },
                     ],
+                    entry_unk1: vec![Vector3::new(0.0, 1.0, 0.0); 2],
                 },
                 MeshObjectGroupData {
                     bounding_sphere: BoundingSphere {
@@ -344,13 +540,14 @@ mod tests {
                         draw_model: true,
                         cast_shadow: true,
                     }],
+                    entry_unk1: vec![Vector3::new(0.0, 1.0, 0.0)],
                 },
             ],
         };
 
-        assert_eq!(data, MeshExData::from(&meshex));
+        assert_eq!(data, MeshExData::try_from(&meshex).unwrap());
 
-        let new_meshex = MeshEx::from(&data);
+        let new_meshex = MeshEx::try_from(&data).unwrap();
         // TODO: Test the all data bounding sphere?
         assert_eq!(
             "All",
@@ -503,6 +700,10 @@ mod tests {
             ],
             data.mesh_object_groups[0].entry_flags
         );
+        assert_eq!(
+            vec![Vector3::new(0.0, 1.0, 0.0); 2],
+            data.mesh_object_groups[0].entry_unk1
+        );
         // TODO: Create a better test for this by checking the sphere contains an AABB?
         assert!(data.mesh_object_groups[0].bounding_sphere.radius > 1.0);
 
@@ -524,6 +725,342 @@ mod tests {
         );
     }
 
+    #[test]
+    fn convert_meshex_data_custom_unk1() {
+        let data = MeshExData {
+            mesh_object_groups: vec![MeshObjectGroupData {
+                bounding_sphere: BoundingSphere {
+                    center: Vector3::ZERO,
+                    radius: 1.0,
+                },
+                mesh_object_full_name: "a_VIS".to_string(),
+                mesh_object_name: "a".to_string(),
+                entry_flags: vec![
+                    EntryFlags {
+                        draw_model: true,
+                        cast_shadow: true,
+                    };
+                    2
+                ],
+                entry_unk1: vec![Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.5, 0.5, 0.0)],
+            }],
+        };
+
+        let meshex = MeshEx::try_from(&data).unwrap();
+        let entries = meshex.entries.as_ref().unwrap();
+        assert_eq!(Vector3::new(1.0, 0.0, 0.0), entries[0].unk1);
+        assert_eq!(Vector3::new(0.5, 0.5, 0.0), entries[1].unk1);
+
+        assert_eq!(data, MeshExData::try_from(&meshex).unwrap());
+    }
+
+    #[test]
+    fn convert_meshex_data_missing_unk1() {
+        let data = MeshExData {
+            mesh_object_groups: vec![MeshObjectGroupData {
+                bounding_sphere: BoundingSphere {
+                    center: Vector3::ZERO,
+                    radius: 1.0,
+                },
+                mesh_object_full_name: "a_VIS".to_string(),
+                mesh_object_name: "a".to_string(),
+                entry_flags: vec![EntryFlags {
+                    draw_model: true,
+                    cast_shadow: true,
+                }],
+                entry_unk1: Vec::new(),
+            }],
+        };
+
+        let meshex = MeshEx::try_from(&data).unwrap();
+        assert_eq!(
+            Vector3::new(0.0, 1.0, 0.0),
+            meshex.entries.as_ref().unwrap()[0].unk1
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn meshex_data_json_missing_unk1() {
+        // Data saved before entry_unk1 was added should still load.
+        let json = r#"{
+            "mesh_object_groups": [{
+                "bounding_sphere": {"center": {"x": 0.0, "y": 0.0, "z": 0.0}, "radius": 1.0},
+                "mesh_object_full_name": "a_VIS",
+                "mesh_object_name": "a",
+                "entry_flags": [{"draw_model": true, "cast_shadow": true}]
+            }]
+        }"#;
+
+        let data: MeshExData = serde_json::from_str(json).unwrap();
+        assert!(data.mesh_object_groups[0].entry_unk1.is_empty());
+
+        let meshex = MeshEx::try_from(&data).unwrap();
+        assert_eq!(DEFAULT_ENTRY_UNK1, meshex.entries.as_ref().unwrap()[0].unk1);
+    }
+
+    #[test]
+    fn default_entry_flags() {
+        assert_eq!(
+            EntryFlags {
+                draw_model: true,
+                cast_shadow: true
+            },
+            EntryFlags::default()
+        );
+    }
+
+    #[test]
+    fn default_meshex_data() {
+        assert!(MeshExData::default().mesh_object_groups.is_empty());
+    }
+
+    #[test]
+    fn iter_meshex_data_entries() {
+        let group = |name: &str, flags: Vec<EntryFlags>| MeshObjectGroupData {
+            bounding_sphere: BoundingSphere {
+                center: Vector3::ZERO,
+                radius: 1.0,
+            },
+            mesh_object_full_name: name.to_string(),
+            mesh_object_name: name.to_string(),
+            entry_unk1: vec![DEFAULT_ENTRY_UNK1; flags.len()],
+            entry_flags: flags,
+        };
+        let flags = |draw_model| EntryFlags {
+            draw_model,
+            cast_shadow: true,
+        };
+
+        let data = MeshExData {
+            mesh_object_groups: vec![
+                group("a", vec![flags(true), flags(false)]),
+                group("b", Vec::new()),
+                group("c", vec![flags(false)]),
+            ],
+        };
+
+        let entries: Vec<_> = data.iter_entries().collect();
+        assert_eq!(
+            data.mesh_object_groups
+                .iter()
+                .map(|g| g.entry_flags.len())
+                .sum::<usize>(),
+            entries.len()
+        );
+        assert_eq!(
+            vec![
+                ("a", 0, &flags(true)),
+                ("a", 1, &flags(false)),
+                ("c", 0, &flags(false))
+            ],
+            entries
+        );
+    }
+
+    #[test]
+    fn create_meshex_interior_nul() {
+        let data = MeshExData {
+            mesh_object_groups: vec![MeshObjectGroupData {
+                bounding_sphere: BoundingSphere {
+                    center: Vector3::ZERO,
+                    radius: 1.0,
+                },
+                mesh_object_full_name: "a\0_VIS".to_string(),
+                mesh_object_name: "a".to_string(),
+                entry_flags: vec![EntryFlags::default()],
+                entry_unk1: vec![DEFAULT_ENTRY_UNK1],
+            }],
+        };
+        assert!(matches!(
+            MeshEx::try_from(&data),
+            Err(error::Error::String(ssbh_lib::StringError::InteriorNul {
+                position: 1
+            }))
+        ));
+    }
+
+    #[test]
+    fn meshex_flag_summary() {
+        let group = |name: &str, entry_flags: Vec<EntryFlags>| MeshObjectGroupData {
+            bounding_sphere: BoundingSphere {
+                center: Vector3::ZERO,
+                radius: 1.0,
+            },
+            mesh_object_full_name: name.to_string(),
+            mesh_object_name: name.to_string(),
+            entry_unk1: vec![DEFAULT_ENTRY_UNK1; entry_flags.len()],
+            entry_flags,
+        };
+        let flags = |draw_model, cast_shadow| EntryFlags {
+            draw_model,
+            cast_shadow,
+        };
+
+        let data = MeshExData {
+            mesh_object_groups: vec![
+                group("a", vec![flags(true, true), flags(false, true)]),
+                group("b", Vec::new()),
+                group("c", vec![flags(false, false), flags(true, false)]),
+            ],
+        };
+        assert_eq!(
+            FlagSummary {
+                total: 4,
+                hidden: 2,
+                no_shadow: 2
+            },
+            data.flag_summary()
+        );
+        assert_eq!(FlagSummary::default(), MeshExData::default().flag_summary());
+    }
+
+    #[test]
+    fn reorder_meshex_groups() {
+        let group = |name: &str| MeshObjectGroupData {
+            bounding_sphere: BoundingSphere {
+                center: Vector3::ZERO,
+                radius: 1.0,
+            },
+            mesh_object_full_name: name.to_string(),
+            mesh_object_name: name.to_string(),
+            entry_flags: vec![EntryFlags::default()],
+            entry_unk1: vec![DEFAULT_ENTRY_UNK1],
+        };
+
+        let mut data = MeshExData {
+            mesh_object_groups: vec![group("a"), group("b"), group("c")],
+        };
+        data.reorder_groups(&[1, 0, 2]).unwrap();
+        assert_eq!(
+            vec![group("b"), group("a"), group("c")],
+            data.mesh_object_groups
+        );
+
+        assert!(matches!(
+            data.reorder_groups(&[1, 0]),
+            Err(error::Error::InvalidGroupMapping { group_count: 3 })
+        ));
+        assert!(matches!(
+            data.reorder_groups(&[1, 1, 2]),
+            Err(error::Error::InvalidGroupMapping { group_count: 3 })
+        ));
+    }
+
+    #[test]
+    fn clone_meshex_data_independent() {
+        let data = MeshExData {
+            mesh_object_groups: vec![MeshObjectGroupData {
+                bounding_sphere: BoundingSphere {
+                    center: Vector3::ZERO,
+                    radius: 1.0,
+                },
+                mesh_object_full_name: "a_VIS".to_string(),
+                mesh_object_name: "a".to_string(),
+                entry_flags: vec![EntryFlags {
+                    draw_model: true,
+                    cast_shadow: true,
+                }],
+                entry_unk1: vec![Vector3::new(0.0, 1.0, 0.0)],
+            }],
+        };
+
+        let mut variant = data.clone();
+        assert_eq!(data, variant);
+
+        variant.mesh_object_groups[0].mesh_object_name = "b".to_string();
+        variant.mesh_object_groups[0].entry_flags[0].draw_model = false;
+
+        assert_eq!("a", data.mesh_object_groups[0].mesh_object_name);
+        assert!(data.mesh_object_groups[0].entry_flags[0].draw_model);
+        assert_ne!(data, variant);
+    }
+
+    fn sphere_contains(outer: Vector4, inner: Vector4) -> bool {
+        let distance = glam::Vec3::new(outer.x, outer.y, outer.z)
+            .distance(glam::Vec3::new(inner.x, inner.y, inner.z));
+        distance + inner.w <= outer.w + 0.0001
+    }
+
+    #[test]
+    fn create_meshex_all_data_contains_groups() {
+        let group = |name: &str, center: Vector3, radius: f32| MeshObjectGroupData {
+            bounding_sphere: BoundingSphere { center, radius },
+            mesh_object_full_name: name.to_string(),
+            mesh_object_name: name.to_string(),
+            entry_flags: vec![EntryFlags {
+                draw_model: true,
+                cast_shadow: true,
+            }],
+            entry_unk1: Vec::new(),
+        };
+        let data = MeshExData {
+            mesh_object_groups: vec![
+                group("a", Vector3::new(-4.0, 0.0, 0.0), 1.0),
+                group("b", Vector3::new(3.0, 4.0, 0.0), 2.0),
+                group("c", Vector3::new(0.0, 0.0, 5.0), 0.5),
+            ],
+        };
+
+        let meshex = MeshEx::try_from(&data).unwrap();
+        let all_sphere = meshex.all_data.as_ref().unwrap().bounding_sphere;
+        let all_sphere = Vector4::new(
+            all_sphere.center.x,
+            all_sphere.center.y,
+            all_sphere.center.z,
+            all_sphere.radius,
+        );
+
+        for g in &data.mesh_object_groups {
+            let center = g.bounding_sphere.center;
+            let sphere = Vector4::new(center.x, center.y, center.z, g.bounding_sphere.radius);
+            assert!(sphere_contains(all_sphere, sphere));
+        }
+    }
+
+    #[test]
+    fn merge_bounding_spheres_empty() {
+        assert_eq!(Vector4::ZERO, merge_bounding_spheres(&[]));
+    }
+
+    #[test]
+    fn merge_bounding_spheres_single() {
+        let sphere = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(sphere, merge_bounding_spheres(&[sphere]));
+    }
+
+    #[test]
+    fn merge_bounding_spheres_disjoint() {
+        let a = Vector4::new(0.0, 0.0, 0.0, 1.0);
+        let b = Vector4::new(3.0, 4.0, 0.0, 1.0);
+        let merged = merge_bounding_spheres(&[a, b]);
+
+        assert!(sphere_contains(merged, a));
+        assert!(sphere_contains(merged, b));
+        assert_eq!(Vector4::new(1.5, 2.0, 0.0, 3.5), merged);
+    }
+
+    #[test]
+    fn merge_bounding_spheres_contained() {
+        let outer = Vector4::new(0.0, 0.0, 0.0, 5.0);
+        let inner = Vector4::new(1.0, 0.0, 0.0, 1.0);
+        assert_eq!(outer, merge_bounding_spheres(&[inner, outer]));
+        assert_eq!(outer, merge_bounding_spheres(&[outer, inner]));
+    }
+
+    #[test]
+    fn merge_bounding_spheres_multiple() {
+        let spheres = [
+            Vector4::new(-5.0, 0.0, 0.0, 1.0),
+            Vector4::new(5.0, 0.0, 0.0, 2.0),
+            Vector4::new(0.0, 7.0, 1.0, 0.5),
+        ];
+        let merged = merge_bounding_spheres(&spheres);
+        for sphere in spheres {
+            assert!(sphere_contains(merged, sphere));
+        }
+    }
+
     #[test]
     fn strip_meshex_names() {
         // Generated from a dump of numshexb file entries.
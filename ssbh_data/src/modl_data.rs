@@ -19,7 +19,9 @@ for entry in modl.entries {
 ```
  */
 
-use ssbh_lib::{formats::modl::*, Version};
+use crate::mesh_data::MeshObjectData;
+use ssbh_lib::{formats::modl::*, SsbhString, SsbhString8, Version};
+use std::collections::HashSet;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -35,15 +37,194 @@ pub struct ModlData {
     pub model_name: String,
     pub skeleton_file_name: String,
     pub material_file_names: Vec<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
     pub animation_file_name: Option<String>,
     pub mesh_file_name: String,
     pub entries: Vec<ModlEntryData>,
 }
 
+pub mod error {
+    use thiserror::Error;
+
+    /// Errors while converting [ModlData](super::ModlData) to [Modl](super::Modl).
+    #[derive(Debug, Error)]
+    pub enum Error {
+        /// A string contains null bytes and can't be converted to a null terminated string.
+        #[error(transparent)]
+        String(#[from] ssbh_lib::StringError),
+
+        /// An error occurred while writing data to a buffer.
+        #[error(transparent)]
+        Io(#[from] std::io::Error),
+    }
+
+    /// Errors for invalid references in [ModlEntryData](super::ModlEntryData).
+    #[derive(Debug, Error, PartialEq, Eq, Clone)]
+    pub enum ModlValidationError {
+        /// The material label does not match any of the material labels.
+        #[error(
+            "Entry {} references the material label {:?}, which does not exist.",
+            entry_index,
+            material_label
+        )]
+        MissingMaterial {
+            entry_index: usize,
+            material_label: String,
+        },
+
+        /// The mesh object name and subindex do not match any of the mesh objects.
+        #[error(
+            "Entry {} references the mesh object {:?} with subindex {}, which does not exist.",
+            entry_index,
+            mesh_object_name,
+            mesh_object_subindex
+        )]
+        MissingMeshObject {
+            entry_index: usize,
+            mesh_object_name: String,
+            mesh_object_subindex: u64,
+        },
+    }
+}
+
+impl ModlData {
+    /// The `(major_version, minor_version)` pairs supported for reading and writing.
+    pub fn supported_versions() -> &'static [(u16, u16)] {
+        &[(1, 7)]
+    }
+
+    /// Creates an entry for each of the `objects` using its name and subindex
+    /// and assigns `default_material` as the material label.
+    /// The file names use the default names for the model files like `"model.numshb"`.
+    /**
+    ```rust
+    # use ssbh_data::modl_data::ModlData;
+    # use ssbh_data::mesh_data::MeshObjectData;
+    let objects = vec![MeshObjectData {
+        name: "body".to_string(),
+        subindex: 0,
+        ..Default::default()
+    }];
+    let modl = ModlData::from_mesh_objects(&objects, "skin");
+    assert_eq!("body", modl.entries[0].mesh_object_name);
+    assert_eq!("skin", modl.entries[0].material_label);
+    ```
+     */
+    pub fn from_mesh_objects(objects: &[MeshObjectData], default_material: &str) -> Self {
+        Self {
+            major_version: 1,
+            minor_version: 7,
+            model_name: "model".to_string(),
+            skeleton_file_name: "model.nusktb".to_string(),
+            material_file_names: vec!["model.numatb".to_string()],
+            animation_file_name: None,
+            mesh_file_name: "model.numshb".to_string(),
+            entries: objects
+                .iter()
+                .map(|o| ModlEntryData {
+                    mesh_object_name: o.name.clone(),
+                    mesh_object_subindex: o.subindex,
+                    material_label: default_material.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Checks that each entry references one of the given material labels
+    /// and one of the given mesh object names and subindices.
+    /// All invalid entries are returned in order.
+    ///
+    /// Invalid references are a common cause of invisible or untextured meshes in game.
+    /// The values can be collected from a [MatlData](crate::matl_data::MatlData)
+    /// and [MeshData](crate::mesh_data::MeshData).
+    /**
+    ```rust
+    # use ssbh_data::modl_data::{ModlData, ModlEntryData};
+    # fn validate(modl: &ModlData) {
+    let materials = ["skin", "eye"];
+    let mesh_objects = [("body", 0), ("body", 1), ("eyes", 0)];
+    for error in modl.validate(&materials, &mesh_objects).err().unwrap_or_default() {
+        println!("{}", error);
+    }
+    # }
+    ```
+     */
+    pub fn validate(
+        &self,
+        materials: &[&str],
+        mesh_objects: &[(&str, u64)],
+    ) -> Result<(), Vec<error::ModlValidationError>> {
+        let mut errors = Vec::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            if !materials.contains(&entry.material_label.as_str()) {
+                errors.push(error::ModlValidationError::MissingMaterial {
+                    entry_index: i,
+                    material_label: entry.material_label.clone(),
+                });
+            }
+
+            if !mesh_objects
+                .contains(&(entry.mesh_object_name.as_str(), entry.mesh_object_subindex))
+            {
+                errors.push(error::ModlValidationError::MissingMeshObject {
+                    entry_index: i,
+                    mesh_object_name: entry.mesh_object_name.clone(),
+                    mesh_object_subindex: entry.mesh_object_subindex,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Removes entries with the same mesh object name and subindex as a later entry
+    /// and returns the number of removed entries.
+    /// The last material assignment for each mesh object is kept,
+    /// and the remaining entries preserve their original order.
+    /**
+    ```rust
+    # use ssbh_data::modl_data::{ModlData, ModlEntryData};
+    # fn dedup(modl: &mut ModlData) {
+    let removed = modl.dedup_entries();
+    println!("Removed {} duplicate entries", removed);
+    # }
+    ```
+     */
+    pub fn dedup_entries(&mut self) -> usize {
+        let mut mesh_objects = HashSet::new();
+        let mut is_last = vec![false; self.entries.len()];
+        for (i, entry) in self.entries.iter().enumerate().rev() {
+            is_last[i] = mesh_objects.insert((&entry.mesh_object_name, entry.mesh_object_subindex));
+        }
+
+        let count = self.entries.len();
+        let mut is_last = is_last.into_iter();
+        self.entries.retain(|_| is_last.next().unwrap_or_default());
+        count - self.entries.len()
+    }
+
+    /// Sorts the entries by mesh object name and then by subindex
+    /// to produce a canonical ordering for comparing files.
+    /// Entries for the same mesh object preserve their original order.
+    pub fn sort_entries(&mut self) {
+        self.entries.sort_by(|a, b| {
+            (&a.mesh_object_name, a.mesh_object_subindex)
+                .cmp(&(&b.mesh_object_name, b.mesh_object_subindex))
+        });
+    }
+}
+
 /// Data associated with a [ModlEntry].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct ModlEntryData {
     pub mesh_object_name: String,
     pub mesh_object_subindex: u64,
@@ -86,38 +267,61 @@ impl From<&Modl> for ModlData {
     }
 }
 
-impl From<ModlData> for Modl {
-    fn from(m: ModlData) -> Self {
-        Self::from(&m)
+impl TryFrom<ModlData> for Modl {
+    type Error = error::Error;
+
+    fn try_from(m: ModlData) -> Result<Self, Self::Error> {
+        Self::try_from(&m)
     }
 }
 
-impl From<&ModlData> for Modl {
-    fn from(m: &ModlData) -> Self {
-        Self::V17 {
-            model_name: m.model_name.clone().into(),
-            skeleton_file_name: m.skeleton_file_name.clone().into(),
-            material_file_names: m.material_file_names.iter().map(Into::into).collect(),
-            animation_file_name: m.animation_file_name.as_ref().map(Into::into).into(),
-            mesh_file_name: m.mesh_file_name.as_str().into(),
-            entries: m.entries.iter().map(Into::into).collect(),
-        }
+impl TryFrom<&ModlData> for Modl {
+    type Error = error::Error;
+
+    fn try_from(m: &ModlData) -> Result<Self, Self::Error> {
+        Ok(Self::V17 {
+            model_name: SsbhString::try_from_str(&m.model_name)?,
+            skeleton_file_name: SsbhString::try_from_str(&m.skeleton_file_name)?,
+            material_file_names: m
+                .material_file_names
+                .iter()
+                .map(|f| SsbhString::try_from_str(f))
+                .collect::<Result<Vec<_>, _>>()?
+                .into(),
+            animation_file_name: m
+                .animation_file_name
+                .as_deref()
+                .map(SsbhString::try_from_str)
+                .transpose()?
+                .into(),
+            mesh_file_name: SsbhString8::try_from_str(&m.mesh_file_name)?,
+            entries: m
+                .entries
+                .iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?
+                .into(),
+        })
     }
 }
 
-impl From<ModlEntryData> for ModlEntry {
-    fn from(m: ModlEntryData) -> Self {
-        Self::from(&m)
+impl TryFrom<ModlEntryData> for ModlEntry {
+    type Error = error::Error;
+
+    fn try_from(m: ModlEntryData) -> Result<Self, Self::Error> {
+        Self::try_from(&m)
     }
 }
 
-impl From<&ModlEntryData> for ModlEntry {
-    fn from(m: &ModlEntryData) -> Self {
-        Self {
-            mesh_object_name: m.mesh_object_name.as_str().into(),
+impl TryFrom<&ModlEntryData> for ModlEntry {
+    type Error = error::Error;
+
+    fn try_from(m: &ModlEntryData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mesh_object_name: SsbhString::try_from_str(&m.mesh_object_name)?,
             mesh_object_subindex: m.mesh_object_subindex,
-            material_label: m.material_label.as_str().into(),
-        }
+            material_label: SsbhString::try_from_str(&m.material_label)?,
+        })
     }
 }
 
@@ -142,6 +346,97 @@ mod tests {
     use super::*;
     use ssbh_lib::SsbhString;
 
+    fn entry(mesh_object_name: &str, mesh_object_subindex: u64, material: &str) -> ModlEntryData {
+        ModlEntryData {
+            mesh_object_name: mesh_object_name.to_string(),
+            mesh_object_subindex,
+            material_label: material.to_string(),
+        }
+    }
+
+    #[test]
+    fn dedup_entries_last_wins() {
+        let mut data = modl_data(vec![
+            entry("a", 0, "mat1"),
+            entry("b", 0, "mat2"),
+            entry("a", 1, "mat3"),
+            entry("a", 0, "mat4"),
+        ]);
+
+        assert_eq!(1, data.dedup_entries());
+        assert_eq!(
+            vec![
+                entry("b", 0, "mat2"),
+                entry("a", 1, "mat3"),
+                entry("a", 0, "mat4")
+            ],
+            data.entries
+        );
+        assert_eq!(0, data.dedup_entries());
+    }
+
+    #[test]
+    fn entries_hash_set_duplicates() {
+        let entries: HashSet<_> = [
+            entry("a", 0, "mat1"),
+            entry("a", 1, "mat1"),
+            entry("a", 0, "mat1"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(2, entries.len());
+    }
+
+    #[test]
+    fn sort_entries_deterministic() {
+        let mut data1 = modl_data(vec![
+            entry("b", 0, "mat1"),
+            entry("a", 1, "mat2"),
+            entry("a", 0, "mat3"),
+        ]);
+        let mut data2 = modl_data(vec![
+            entry("a", 0, "mat3"),
+            entry("b", 0, "mat1"),
+            entry("a", 1, "mat2"),
+        ]);
+
+        data1.sort_entries();
+        data2.sort_entries();
+
+        let expected = vec![
+            entry("a", 0, "mat3"),
+            entry("a", 1, "mat2"),
+            entry("b", 0, "mat1"),
+        ];
+        assert_eq!(expected, data1.entries);
+        assert_eq!(expected, data2.entries);
+    }
+
+    #[test]
+    fn modl_supported_versions() {
+        assert!(ModlData::supported_versions().contains(&(1, 7)));
+    }
+
+    #[test]
+    fn create_modl_interior_nul() {
+        let mut data = modl_data(vec![entry("a", 0, "b\0c")]);
+        assert!(matches!(
+            Modl::try_from(&data),
+            Err(error::Error::String(ssbh_lib::StringError::InteriorNul {
+                position: 1
+            }))
+        ));
+
+        data.entries.clear();
+        data.animation_file_name = Some("model\0.nuanmb".to_string());
+        assert!(matches!(
+            Modl::try_from(&data),
+            Err(error::Error::String(ssbh_lib::StringError::InteriorNul {
+                position: 5
+            }))
+        ));
+    }
+
     #[test]
     fn create_modl() {
         let data = ModlData {
@@ -159,7 +454,7 @@ mod tests {
             }],
         };
 
-        let ssbh: Modl = data.into();
+        let ssbh = Modl::try_from(data).unwrap();
         match ssbh {
             Modl::V17 {
                 model_name,
@@ -221,6 +516,127 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn modl_data_json_missing_animation_file_name() {
+        let data = ModlData {
+            major_version: 1,
+            minor_version: 7,
+            model_name: "a".to_string(),
+            skeleton_file_name: "b".to_string(),
+            material_file_names: vec!["f1".to_string()],
+            animation_file_name: None,
+            mesh_file_name: "d".to_string(),
+            entries: Vec::new(),
+        };
+
+        let json = serde_json::to_string(&data).unwrap();
+        assert!(!json.contains("animation_file_name"));
+
+        let result: ModlData = serde_json::from_str(&json).unwrap();
+        assert_eq!(data, result);
+    }
+
+    #[test]
+    fn from_mesh_objects_same_name_different_subindex() {
+        let objects = vec![
+            MeshObjectData {
+                name: "a".to_string(),
+                subindex: 0,
+                ..Default::default()
+            },
+            MeshObjectData {
+                name: "a".to_string(),
+                subindex: 1,
+                ..Default::default()
+            },
+        ];
+
+        assert_eq!(
+            modl_data(vec![entry("a", 0, "mat"), entry("a", 1, "mat")]),
+            ModlData::from_mesh_objects(&objects, "mat")
+        );
+    }
+
+    fn modl_data(entries: Vec<ModlEntryData>) -> ModlData {
+        ModlData {
+            major_version: 1,
+            minor_version: 7,
+            model_name: "model".to_string(),
+            skeleton_file_name: "model.nusktb".to_string(),
+            material_file_names: vec!["model.numatb".to_string()],
+            animation_file_name: None,
+            mesh_file_name: "model.numshb".to_string(),
+            entries,
+        }
+    }
+
+    #[test]
+    fn validate_modl_data() {
+        let modl = modl_data(vec![
+            ModlEntryData {
+                mesh_object_name: "a".to_string(),
+                mesh_object_subindex: 0,
+                material_label: "m1".to_string(),
+            },
+            ModlEntryData {
+                mesh_object_name: "a".to_string(),
+                mesh_object_subindex: 1,
+                material_label: "m2".to_string(),
+            },
+        ]);
+
+        assert_eq!(Ok(()), modl.validate(&["m1", "m2"], &[("a", 0), ("a", 1)]));
+    }
+
+    #[test]
+    fn validate_modl_data_missing_material() {
+        let modl = modl_data(vec![
+            ModlEntryData {
+                mesh_object_name: "a".to_string(),
+                mesh_object_subindex: 0,
+                material_label: "m1".to_string(),
+            },
+            ModlEntryData {
+                mesh_object_name: "a".to_string(),
+                mesh_object_subindex: 1,
+                material_label: "m3".to_string(),
+            },
+        ]);
+
+        assert_eq!(
+            Err(vec![error::ModlValidationError::MissingMaterial {
+                entry_index: 1,
+                material_label: "m3".to_string()
+            }]),
+            modl.validate(&["m1", "m2"], &[("a", 0), ("a", 1)])
+        );
+    }
+
+    #[test]
+    fn validate_modl_data_missing_mesh_object() {
+        let modl = modl_data(vec![ModlEntryData {
+            mesh_object_name: "a".to_string(),
+            mesh_object_subindex: 1,
+            material_label: "m3".to_string(),
+        }]);
+
+        assert_eq!(
+            Err(vec![
+                error::ModlValidationError::MissingMaterial {
+                    entry_index: 0,
+                    material_label: "m3".to_string()
+                },
+                error::ModlValidationError::MissingMeshObject {
+                    entry_index: 0,
+                    mesh_object_name: "a".to_string(),
+                    mesh_object_subindex: 1
+                }
+            ]),
+            modl.validate(&["m1"], &[("a", 0)])
+        );
+    }
+
     #[test]
     fn create_modl_entry_data() {
         let ssbh = ModlEntry {
@@ -247,7 +663,7 @@ mod tests {
             material_label: "b".into(),
         };
 
-        let ssbh: ModlEntry = data.into();
+        let ssbh = ModlEntry::try_from(data).unwrap();
         assert_eq!("a", ssbh.mesh_object_name.to_str().unwrap());
         assert_eq!(2, ssbh.mesh_object_subindex);
         assert_eq!("b", ssbh.material_label.to_str().unwrap());
@@ -16,7 +16,7 @@ use glam::Mat4;
 pub use ssbh_lib::formats::skel::BillboardType;
 use ssbh_lib::{
     formats::skel::{Skel, SkelBoneEntry, SkelEntryFlags},
-    Matrix4x4, Version,
+    Matrix4x4, SsbhString, Version,
 };
 
 use thiserror::Error;
@@ -52,6 +52,10 @@ pub struct BoneData {
     /// For using existing world transformations, see [calculate_relative_transform].
     pub transform: [[f32; 4]; 4],
     /// The index of the parent bone in the bones collection or [None] if this is a root bone with no parents.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
     pub parent_index: Option<usize>,
     // TODO: Make this an Option for clarity?
     pub billboard_type: BillboardType,
@@ -80,6 +84,10 @@ pub mod error {
         #[error(transparent)]
         BoneTransform(#[from] BoneTransformError),
 
+        /// A string contains null bytes and can't be converted to a null terminated string.
+        #[error(transparent)]
+        String(#[from] ssbh_lib::StringError),
+
         /// An error occurred while writing data to a buffer.
         #[error(transparent)]
         Io(#[from] std::io::Error),
@@ -163,20 +171,23 @@ impl TryFrom<&SkelData> for Skel {
                 .bones
                 .iter()
                 .enumerate()
-                .map(|(i, b)| SkelBoneEntry {
-                    name: b.name.clone().into(),
-                    index: i as u16,
-                    parent_index: match b.parent_index {
-                        Some(index) => index as i16,
-                        None => -1,
-                    },
-                    // TODO: Preserve or calculate flags?
-                    flags: SkelEntryFlags {
-                        unk1: 1,
-                        billboard_type: b.billboard_type,
-                    },
+                .map(|(i, b)| {
+                    Ok(SkelBoneEntry {
+                        name: SsbhString::try_from_str(&b.name)?,
+                        index: i as u16,
+                        parent_index: match b.parent_index {
+                            Some(index) => index as i16,
+                            None => -1,
+                        },
+                        // TODO: Preserve or calculate flags?
+                        flags: SkelEntryFlags {
+                            unk1: 1,
+                            billboard_type: b.billboard_type,
+                        },
+                    })
                 })
-                .collect(),
+                .collect::<Result<Vec<_>, error::Error>>()?
+                .into(),
             world_transforms: world_transforms
                 .iter()
                 .map(Matrix4x4::from_cols_array)
@@ -235,6 +246,11 @@ fn create_bone_data(b: &SkelBoneEntry, transform: &Matrix4x4) -> BoneData {
 }
 
 impl SkelData {
+    /// The `(major_version, minor_version)` pairs supported for reading and writing.
+    pub fn supported_versions() -> &'static [(u16, u16)] {
+        &[(1, 0)]
+    }
+
     /// Calculates the world transform for `bone` by accumulating the transform with the parents transform recursively.
     /// Returns the resulting matrix in column-major order.
     /// # Examples
@@ -345,6 +361,28 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn create_skel_interior_nul() {
+        let data = SkelData {
+            major_version: 1,
+            minor_version: 0,
+            bones: vec![BoneData {
+                name: "ab\0cd".into(),
+                transform: [[0.0; 4]; 4],
+                parent_index: None,
+                billboard_type: BillboardType::Disabled,
+            }],
+        };
+
+        let result = Skel::try_from(data);
+        assert!(matches!(
+            result,
+            Err(error::Error::String(ssbh_lib::StringError::InteriorNul {
+                position: 2
+            }))
+        ));
+    }
+
     #[test]
     fn create_skel_mario_three_bone_chain() {
         // The first three bones of /fighter/mario/model/body/c00/model.nusktb.
@@ -22,6 +22,7 @@ const SSBH_BYTE_BUFFER_MAX_INITIAL_CAPACITY: usize = 104857600;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, PartialEq, Eq)]
 pub struct SsbhByteBuffer {
     #[cfg_attr(
@@ -137,6 +138,7 @@ struct Transforms {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug)]
 pub struct SsbhArray<T> {
     pub elements: Vec<T>,
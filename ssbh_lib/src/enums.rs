@@ -58,6 +58,7 @@ pub struct EnumData {
 ///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug)]
 pub struct SsbhEnum64<T: DataType> {
     pub data: RelPtr64<T>,
@@ -163,6 +164,18 @@ macro_rules! ssbh_enum {
             }
 
         }
+
+        // The derive macro doesn't support enums generated by macro_rules.
+        #[cfg(feature = "deepsize")]
+        impl deepsize::DeepSizeOf for $name {
+            fn deep_size_of_children(&self, context: &mut deepsize::Context) -> usize {
+                match self {
+                    $(
+                        Self::$variant(v) => v.deep_size_of_children(context)
+                    ),*
+                }
+            }
+        }
     };
 }
 
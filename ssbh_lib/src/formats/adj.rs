@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 /// Adjacency data for a [MeshObject](crate::mesh::MeshObject).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct AdjEntry {
     /// The index of the [MeshObject](crate::mesh::MeshObject).
@@ -27,6 +28,7 @@ pub struct AdjEntry {
 #[binread]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, PartialEq, Eq)]
 pub struct Adj {
     #[br(temp)]
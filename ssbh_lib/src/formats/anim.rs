@@ -21,6 +21,7 @@ use strum::{Display, EnumString, EnumVariantNames, FromRepr};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct TrackV2 {
     pub name: SsbhString,
@@ -33,6 +34,7 @@ pub struct TrackV2 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct Node {
     pub name: SsbhString,
@@ -41,6 +43,7 @@ pub struct Node {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct Group {
     pub group_type: GroupType,
@@ -51,6 +54,7 @@ pub struct Group {
 /// Compatible with file version 1.2, 2.0, and 2.1.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[br(import(major_version: u16, minor_version: u16))]
 pub enum Anim {
@@ -98,6 +102,47 @@ pub enum Anim {
     },
 }
 
+impl Anim {
+    /// Lists the node name, track name, track type, compression type, and frame count for each track
+    /// in group and node order without reading the track data.
+    /// Version 1.2 tracks are not organized into nodes, so the result is empty for [Anim::V12].
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::formats::anim::{Anim, CompressionType, TrackTypeV2};
+    # fn list(anim: &Anim) {
+    for (node_name, track_name, track_type, compression_type, frame_count) in anim.list_tracks() {
+        println!("{node_name}.{track_name}: {track_type:?} {compression_type:?} {frame_count}");
+    }
+    # }
+    ```
+    */
+    pub fn list_tracks(
+        &self,
+    ) -> impl Iterator<Item = (String, String, TrackTypeV2, CompressionType, u32)> + '_ {
+        let groups: &[Group] = match self {
+            Anim::V12 { .. } => &[],
+            Anim::V20 { groups, .. } => &groups.elements,
+            Anim::V21 { groups, .. } => &groups.elements,
+        };
+
+        groups
+            .iter()
+            .flat_map(|g| g.nodes.elements.iter())
+            .flat_map(|n| {
+                n.tracks.elements.iter().map(move |t| {
+                    (
+                        n.name.to_string_lossy(),
+                        t.name.to_string_lossy(),
+                        t.flags.track_type,
+                        t.flags.compression_type,
+                        t.frame_count,
+                    )
+                })
+            })
+    }
+}
+
 impl Version for Anim {
     fn major_minor_version(&self) -> (u16, u16) {
         match self {
@@ -110,6 +155,7 @@ impl Version for Anim {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct TrackV1 {
     pub name: SsbhString,
@@ -119,6 +165,7 @@ pub struct TrackV1 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct Property {
     pub name: SsbhString,
@@ -129,6 +176,7 @@ pub struct Property {
 // TODO: Is this interpolation data?
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct UnkData {
     pub unk1: SsbhArray<UnkItem1>,
@@ -137,6 +185,7 @@ pub struct UnkData {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct UnkItem1 {
     pub unk1: u64,                   // TODO: Always 2?
@@ -145,6 +194,7 @@ pub struct UnkItem1 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct UnkItem2 {
     pub unk1: SsbhString,            // TODO: node name?
@@ -154,6 +204,7 @@ pub struct UnkItem2 {
 // TODO: These appear to be start and end frame indices.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct UnkSubItem {
     pub unk1: u32,
@@ -162,6 +213,7 @@ pub struct UnkSubItem {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[ssbhwrite(pad_after = 2)]
 pub struct TrackFlags {
@@ -208,8 +260,17 @@ pub struct TransformFlags {
 
 ssbh_write::ssbh_write_modular_bitfield_impl!(TransformFlags, 4);
 
+// The bitfield is stored inline, so there are no heap allocations.
+#[cfg(feature = "deepsize")]
+impl deepsize::DeepSizeOf for TransformFlags {
+    fn deep_size_of_children(&self, _context: &mut deepsize::Context) -> usize {
+        0
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u64))]
 #[ssbhwrite(repr(u64))]
@@ -221,6 +282,11 @@ pub enum TrackTypeV1 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
+#[cfg_attr(
+    feature = "strum",
+    derive(FromRepr, Display, EnumVariantNames, EnumString)
+)]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u8))]
 #[ssbhwrite(repr(u8))]
@@ -235,6 +301,11 @@ pub enum TrackTypeV2 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
+#[cfg_attr(
+    feature = "strum",
+    derive(FromRepr, Display, EnumVariantNames, EnumString)
+)]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u8))]
 #[ssbhwrite(repr(u8))]
@@ -260,6 +331,7 @@ pub enum CompressionType {
 /// This often corresponds with [TrackTypeV2] like [GroupType::Transform] and [TrackTypeV2::Transform].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[cfg_attr(
     feature = "strum",
     derive(FromRepr, Display, EnumVariantNames, EnumString)
@@ -316,4 +388,137 @@ mod tests {
         // Version 2.10 is aligned to 8 bytes.
         assert_eq!(0, buffer.into_inner().len() % 8);
     }
+
+    fn track(name: &str, track_type: TrackTypeV2, compression_type: CompressionType) -> TrackV2 {
+        TrackV2 {
+            name: name.into(),
+            flags: TrackFlags {
+                track_type,
+                compression_type,
+            },
+            frame_count: 3,
+            transform_flags: TransformFlags::new(),
+            data_offset: 0,
+            data_size: 0,
+        }
+    }
+
+    #[test]
+    fn list_tracks_v20() {
+        let anim = Anim::V20 {
+            final_frame_index: 2.0,
+            unk1: 1,
+            unk2: 3,
+            name: "a".into(),
+            groups: vec![
+                Group {
+                    group_type: GroupType::Transform,
+                    nodes: vec![Node {
+                        name: "A".into(),
+                        tracks: vec![track(
+                            "Transform",
+                            TrackTypeV2::Transform,
+                            CompressionType::Compressed,
+                        )]
+                        .into(),
+                    }]
+                    .into(),
+                },
+                Group {
+                    group_type: GroupType::Material,
+                    nodes: vec![Node {
+                        name: "B".into(),
+                        tracks: vec![
+                            track(
+                                "CustomFloat0",
+                                TrackTypeV2::Float,
+                                CompressionType::Constant,
+                            ),
+                            track(
+                                "CustomBoolean1",
+                                TrackTypeV2::Boolean,
+                                CompressionType::Direct,
+                            ),
+                        ]
+                        .into(),
+                    }]
+                    .into(),
+                },
+            ]
+            .into(),
+            buffer: SsbhByteBuffer::new(),
+        };
+
+        assert_eq!(
+            vec![
+                (
+                    "A".to_string(),
+                    "Transform".to_string(),
+                    TrackTypeV2::Transform,
+                    CompressionType::Compressed,
+                    3
+                ),
+                (
+                    "B".to_string(),
+                    "CustomFloat0".to_string(),
+                    TrackTypeV2::Float,
+                    CompressionType::Constant,
+                    3
+                ),
+                (
+                    "B".to_string(),
+                    "CustomBoolean1".to_string(),
+                    TrackTypeV2::Boolean,
+                    CompressionType::Direct,
+                    3
+                ),
+            ],
+            anim.list_tracks().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn list_tracks_v12() {
+        let anim = Anim::V12 {
+            name: "a".into(),
+            unk1: 0,
+            final_frame_index: 0.0,
+            unk2: 0,
+            tracks: SsbhArray::new(),
+            buffers: SsbhArray::new(),
+        };
+        assert_eq!(0, anim.list_tracks().count());
+    }
+
+    #[cfg(feature = "strum")]
+    #[test]
+    fn track_type_v2_string_round_trip() {
+        for (value, name) in [
+            (TrackTypeV2::Transform, "Transform"),
+            (TrackTypeV2::UvTransform, "UvTransform"),
+            (TrackTypeV2::Float, "Float"),
+            (TrackTypeV2::PatternIndex, "PatternIndex"),
+            (TrackTypeV2::Boolean, "Boolean"),
+            (TrackTypeV2::Vector4, "Vector4"),
+        ] {
+            assert_eq!(name, value.to_string());
+            assert_eq!(value, name.parse::<TrackTypeV2>().unwrap());
+        }
+        assert!("Vector3".parse::<TrackTypeV2>().is_err());
+    }
+
+    #[cfg(feature = "strum")]
+    #[test]
+    fn compression_type_string_round_trip() {
+        for (value, name) in [
+            (CompressionType::Direct, "Direct"),
+            (CompressionType::ConstTransform, "ConstTransform"),
+            (CompressionType::Compressed, "Compressed"),
+            (CompressionType::Constant, "Constant"),
+        ] {
+            assert_eq!(name, value.to_string());
+            assert_eq!(value, name.parse::<CompressionType>().unwrap());
+        }
+        assert!("Uncompressed".parse::<CompressionType>().is_err());
+    }
 }
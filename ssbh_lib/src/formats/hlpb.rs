@@ -14,6 +14,7 @@ use ssbh_write::SsbhWrite;
 /// Compatible with file version 1.1.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Clone)]
 #[br(import(major_version: u16, minor_version: u16))]
 pub enum Hlpb {
@@ -50,6 +51,7 @@ impl Version for Hlpb {
 /// This is similar to the aim constraint in Autodesk Maya.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Clone)]
 pub struct AimConstraint {
     /// The name of the constraint like `"nuHelperBoneRotateAim1"`.
@@ -81,6 +83,7 @@ pub struct AimConstraint {
 /// This is similar to the orient constraint in Autodesk Maya.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Clone)]
 pub struct OrientConstraint {
     /// The name of the constraint like `"nuHelperBoneRotateInterp1"`.
@@ -114,6 +117,7 @@ pub struct OrientConstraint {
 /// The type of bone constraint for entries in [constraint_types](enum.Hlpb.html#variant.V11.field.constraint_types).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
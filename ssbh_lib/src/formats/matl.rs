@@ -20,6 +20,7 @@ use strum::{Display, EnumString, EnumVariantNames, FromRepr};
 /// A named material value.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct AttributeV15 {
     /// Determines how the value in [param](#structfield.param) will be used by the shader.
@@ -31,6 +32,7 @@ pub struct AttributeV15 {
 /// A named material value.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct AttributeV16 {
     /// Determines how the value in [param](#structfield.param) will be used by the shader.
@@ -42,6 +44,7 @@ pub struct AttributeV16 {
 /// A named collection of material values for a specified shader.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct MatlEntryV15 {
     /// The name of this material.
@@ -60,6 +63,7 @@ pub struct MatlEntryV15 {
 /// A named collection of material values for a specified shader.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct MatlEntryV16 {
     /// The name of this material.
@@ -79,6 +83,7 @@ pub struct MatlEntryV16 {
 /// Compatible with file version 1.5 and 1.6.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite)]
 #[br(import(major_version: u16, minor_version: u16))]
 pub enum Matl {
@@ -140,6 +145,7 @@ ssbh_enum!(
 /// page on Github.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[cfg_attr(
     feature = "strum",
     derive(FromRepr, Display, EnumVariantNames, EnumString)
@@ -525,6 +531,7 @@ pub enum ParamId {
     derive(FromRepr, Display, EnumVariantNames, EnumString)
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -540,6 +547,7 @@ pub enum FillMode {
     derive(FromRepr, Display, EnumVariantNames, EnumString)
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -551,6 +559,7 @@ pub enum CullMode {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, PartialEq, Eq)]
 pub struct RasterizerStateV15 {
     pub unk1: u32,
@@ -559,6 +568,7 @@ pub struct RasterizerStateV15 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, PartialEq)]
 #[ssbhwrite(pad_after = 4)]
 pub struct RasterizerStateV16 {
@@ -578,6 +588,7 @@ pub struct RasterizerStateV16 {
     derive(FromRepr, Display, EnumVariantNames, EnumString)
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -594,6 +605,7 @@ pub enum WrapMode {
     derive(FromRepr, Display, EnumVariantNames, EnumString)
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -609,6 +621,7 @@ pub enum MinFilter {
     derive(FromRepr, Display, EnumVariantNames, EnumString)
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -624,6 +637,7 @@ pub enum MagFilter {
     derive(FromRepr, Display, EnumVariantNames, EnumString)
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -635,6 +649,7 @@ pub enum FilteringType {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, PartialEq)]
 pub struct Sampler {
     pub wraps: WrapMode,
@@ -659,6 +674,7 @@ pub struct Sampler {
     derive(FromRepr, Display, EnumVariantNames, EnumString)
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -672,6 +688,7 @@ pub enum MaxAnisotropy {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, PartialEq)]
 pub struct UvTransform {
     pub x: f32, // TODO: this is probably the same as the anim data type
@@ -688,6 +705,7 @@ pub struct UvTransform {
     derive(FromRepr, Display, EnumVariantNames, EnumString)
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -708,6 +726,7 @@ pub enum BlendFactor {
 /// Determines the alpha blending settings to use when rendering.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, PartialEq, Eq)]
 pub struct BlendStateV15 {
     pub unk1: u64,
@@ -724,6 +743,7 @@ pub struct BlendStateV15 {
 /// Determines the alpha blending settings to use when rendering.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, PartialEq, Eq)]
 #[ssbhwrite(pad_after = 8)]
 pub struct BlendStateV16 {
@@ -20,6 +20,7 @@ use ssbh_write::SsbhWrite;
 /// Compatible with file version 1.8, 1.9, and 1.10.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[br(import(major_version: u16, minor_version: u16))]
 #[ssbhwrite(pad_after = 16, align_after = 8)]
@@ -52,6 +53,7 @@ impl Version for Mesh {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct MeshInner<A: BinRead<Args = ()> + SsbhWrite, W1: BinRead<Args = ()> + SsbhWrite> {
     pub model_name: SsbhString,
@@ -73,6 +75,7 @@ pub struct MeshInner<A: BinRead<Args = ()> + SsbhWrite, W1: BinRead<Args = ()> +
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, PartialEq, Eq)]
 pub struct AttributeV8 {
     pub usage: AttributeUsageV8,
@@ -85,6 +88,7 @@ pub struct AttributeV8 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, PartialEq, Eq)]
 pub struct AttributeV9 {
     pub usage: AttributeUsageV9,
@@ -99,6 +103,7 @@ pub struct AttributeV9 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, PartialEq, Eq)]
 pub struct AttributeV10 {
     pub usage: AttributeUsageV9,
@@ -113,6 +118,7 @@ pub struct AttributeV10 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, Default)]
 pub struct BoundingInfo {
     pub bounding_sphere: BoundingSphere,
@@ -122,6 +128,7 @@ pub struct BoundingInfo {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Default)]
 pub struct BoundingSphere {
     pub center: Vector3,
@@ -132,6 +139,7 @@ pub struct BoundingSphere {
 /// This is equivalent to an axis-aligned bounding box (abbreviated AABB) for the XYZ axes.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, Default)]
 pub struct BoundingVolume {
     pub min: Vector3,
@@ -140,6 +148,7 @@ pub struct BoundingVolume {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, Default)]
 pub struct OrientedBoundingBox {
     pub center: Vector3,
@@ -149,6 +158,7 @@ pub struct OrientedBoundingBox {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Copy, Clone)]
 #[ssbhwrite(pad_after = 6)]
 pub struct RiggingFlags {
@@ -162,6 +172,7 @@ pub struct RiggingFlags {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct BoneBuffer<W2: BinRead<Args = ()> + SsbhWrite> {
     pub bone_name: SsbhString,
@@ -172,6 +183,7 @@ pub struct BoneBuffer<W2: BinRead<Args = ()> + SsbhWrite> {
 /// determined by [mesh_object_name](#structfield.mesh_object_name) and [mesh_object_subindex](#structfield.mesh_object_subindex).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct RiggingGroup<W3: BinRead<Args = ()> + SsbhWrite> {
     pub mesh_object_name: SsbhString,
@@ -182,6 +194,7 @@ pub struct RiggingGroup<W3: BinRead<Args = ()> + SsbhWrite> {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[br(import(major_version: u16, minor_version: u16))]
 pub enum Attributes {
@@ -198,6 +211,7 @@ pub enum Attributes {
 /// The type of array element for the vertex skin weights stored in the [SsbhByteBuffer] for [VertexWeightV8].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct VertexWeightV8 {
     pub vertex_index: u32,
@@ -215,6 +229,7 @@ pub struct VertexWeightV10 {
 /// In addition to organizing the model into logical components, material and rigging data are assigned per [MeshObject].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct MeshObject<A: BinRead<Args = ()> + SsbhWrite> {
     /// The name of the [MeshObject] such as `"c00BodyShape"`.
@@ -266,6 +281,7 @@ pub struct MeshObject<A: BinRead<Args = ()> + SsbhWrite> {
 /// Flags for controlling depth testing.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[ssbhwrite(pad_after = 2)]
 pub struct DepthFlags {
@@ -279,6 +295,7 @@ pub struct DepthFlags {
 /// Possible values for [draw_element_type](struct.MeshObject.html#structfield.draw_element_type).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -293,6 +310,7 @@ pub enum DrawElementType {
 /// This determines the stride and offset between attributes.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -315,6 +333,7 @@ pub enum AttributeDataTypeV10 {
 /// This determines the stride and offset between attributes.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -337,6 +356,7 @@ pub enum AttributeDataTypeV8 {
 /// [attribute_names](struct.MeshAttributeV10.html#structfield.attribute_names) when determing the usage in some cases.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
@@ -353,6 +373,7 @@ pub enum AttributeUsageV9 {
 /// Attributes with an identical usage should each have a unique [subindex](struct.MeshAttributeV8.html#structfield.subindex).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u32))]
 #[ssbhwrite(repr(u32))]
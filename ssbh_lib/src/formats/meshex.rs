@@ -1,7 +1,7 @@
-use binrw::io::SeekFrom;
+use binrw::io::{Read, Seek, SeekFrom};
 
 use crate::mesh::BoundingSphere;
-use crate::{CString, Ptr64, Vector3};
+use crate::{CString, Ptr64, ReadError, Vector3};
 use binrw::{binread, BinRead};
 use modular_bitfield::prelude::*;
 
@@ -12,6 +12,7 @@ use ssbh_write::SsbhWrite;
 // TODO: How does MeshEx handle empty strings?
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[ssbhwrite(alignment = 16)]
 pub struct MeshEntry {
@@ -24,6 +25,7 @@ pub struct MeshEntry {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[ssbhwrite(alignment = 16)]
 pub struct AllData {
@@ -33,6 +35,7 @@ pub struct AllData {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[ssbhwrite(alignment = 16)]
 pub struct MeshObjectGroup {
@@ -66,8 +69,17 @@ pub struct EntryFlag {
 
 ssbh_write::ssbh_write_modular_bitfield_impl!(EntryFlag, 2);
 
+// The bitfield is stored inline, so there are no heap allocations.
+#[cfg(feature = "deepsize")]
+impl deepsize::DeepSizeOf for EntryFlag {
+    fn deep_size_of_children(&self, _context: &mut deepsize::Context) -> usize {
+        0
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[ssbhwrite(alignment = 16)]
 #[br(import(count: usize))]
@@ -77,6 +89,7 @@ pub struct EntryFlags(#[br(count = count)] pub Vec<EntryFlag>);
 #[binread]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug)]
 pub struct MeshEx {
     #[br(temp)]
@@ -103,6 +116,55 @@ pub struct MeshEx {
     pub unk1: u32,
 }
 
+// Only the header fields needed to read the mesh object groups.
+#[binread]
+struct MeshObjectGroups {
+    #[br(temp, pad_before = 12)]
+    mesh_object_group_count: u32,
+
+    #[br(pad_before = 8, count = mesh_object_group_count)]
+    mesh_object_groups: Ptr64<Vec<MeshObjectGroup>>,
+}
+
+impl MeshEx {
+    /// Reads the `(mesh_object_full_name, mesh_object_name)` for each of the
+    /// [mesh_object_groups](#structfield.mesh_object_groups) from `reader`.
+    /// This skips reading the entries and entry flags, which is faster than [MeshEx::read]
+    /// when only the names are needed.
+    /**
+    ```rust no_run
+    # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    use ssbh_lib::formats::meshex::MeshEx;
+
+    let mut reader = std::io::Cursor::new(std::fs::read("model.numshexb")?);
+    for (full_name, name) in MeshEx::read_mesh_object_names(&mut reader)? {
+        println!("{} {}", full_name, name);
+    }
+    # Ok(()) }
+    ```
+     */
+    pub fn read_mesh_object_names<R: Read + Seek>(
+        reader: &mut R,
+    ) -> Result<Vec<(String, String)>, ReadError> {
+        let groups = crate::read_tracked::<MeshObjectGroups, _>(reader)?;
+        Ok(groups
+            .mesh_object_groups
+            .as_ref()
+            .map(|groups| {
+                groups
+                    .iter()
+                    .map(|g| {
+                        let name = |s: &Ptr64<CString<4>>| {
+                            s.as_ref().map(|s| s.to_string_lossy()).unwrap_or_default()
+                        };
+                        (name(&g.mesh_object_full_name), name(&g.mesh_object_name))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
 impl SsbhWrite for MeshEx {
     fn ssbh_write<W: std::io::Write + std::io::Seek>(
         &self,
@@ -171,3 +233,54 @@ impl SsbhWrite for MeshEx {
         64
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use binrw::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn read_meshex_mesh_object_names() {
+        let group = |full_name: &str, name: &str| MeshObjectGroup {
+            bounding_sphere: BoundingSphere {
+                center: Vector3::ZERO,
+                radius: 1.0,
+            },
+            mesh_object_full_name: Ptr64::new(full_name.into()),
+            mesh_object_name: Ptr64::new(name.into()),
+        };
+        let entry = |i| MeshEntry {
+            mesh_object_group_index: i,
+            unk1: Vector3::ZERO,
+        };
+        let meshex = MeshEx {
+            all_data: Ptr64::new(AllData {
+                bounding_sphere: BoundingSphere {
+                    center: Vector3::ZERO,
+                    radius: 1.0,
+                },
+                name: Ptr64::new("All".into()),
+            }),
+            mesh_object_groups: Ptr64::new(vec![
+                group("a_VIS_O_OBJShape", "a"),
+                group("b_VIS_O_OBJShape", "b"),
+            ]),
+            entries: Ptr64::new(vec![entry(0), entry(1)]),
+            entry_flags: Ptr64::new(EntryFlags(vec![EntryFlag::new(); 2])),
+            unk1: 0,
+        };
+
+        let mut writer = Cursor::new(Vec::new());
+        meshex.write(&mut writer).unwrap();
+        writer.set_position(0);
+
+        assert_eq!(
+            vec![
+                ("a_VIS_O_OBJShape".to_string(), "a".to_string()),
+                ("b_VIS_O_OBJShape".to_string(), "b".to_string())
+            ],
+            MeshEx::read_mesh_object_names(&mut writer).unwrap()
+        );
+    }
+}
@@ -10,6 +10,7 @@ use ssbh_write::SsbhWrite;
 /// Associates a [MatlEntry](crate::formats::matl::MatlEntryV16) with a [MeshObject](crate::formats::mesh::MeshObject).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct ModlEntry {
     /// The `name` of the [MeshObject](crate::formats::mesh::MeshObject).
@@ -26,6 +27,7 @@ pub struct ModlEntry {
 /// Compatible with file version 1.7.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[br(import(major_version: u16, minor_version: u16))]
 pub enum Modl {
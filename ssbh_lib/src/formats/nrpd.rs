@@ -16,6 +16,7 @@ use strum::{Display, EnumString, EnumVariantNames, FromRepr};
 // TODO: Strings at the end don't need to be aligned?
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 #[br(import(major_version: u16, minor_version: u16))]
 pub enum Nrpd {
@@ -71,6 +72,7 @@ ssbh_enum!(
     derive(FromRepr, Display, EnumVariantNames, EnumString)
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, Clone, Copy, PartialEq, Eq)]
 #[br(repr(u64))]
 #[ssbhwrite(repr(u64))]
@@ -91,6 +93,7 @@ pub enum UnkFormat {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct Framebuffer0 {
     pub name: SsbhString,
@@ -103,6 +106,7 @@ pub struct Framebuffer0 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct Framebuffer1 {
     pub name: SsbhString,
@@ -115,6 +119,7 @@ pub struct Framebuffer1 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct UniformBuffer {
     pub name: SsbhString,
@@ -125,6 +130,7 @@ pub struct UniformBuffer {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct Framebuffer3 {
     pub name: SsbhString,
@@ -138,6 +144,7 @@ pub struct Framebuffer3 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct Framebuffer4 {
     pub name: SsbhString,
@@ -148,6 +155,7 @@ pub struct Framebuffer4 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct NrpdSampler {
     pub name: SsbhString,
@@ -157,6 +165,7 @@ pub struct NrpdSampler {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct NrpdRasterizerState {
     pub name: SsbhString,
@@ -171,6 +180,7 @@ pub struct NrpdRasterizerState {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct NrpdBlendState {
     pub name: SsbhString,
@@ -191,6 +201,7 @@ pub struct NrpdBlendState {
 // TODO: There is only a single instance of this struct?
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct DepthState {
     pub name: SsbhString,
@@ -236,6 +247,7 @@ ssbh_enum!(
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct RenderPassData0 {
     unk1: SsbhString,
@@ -245,6 +257,7 @@ pub struct RenderPassData0 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct RenderPassData1 {
     unk1: SsbhString,
@@ -253,6 +266,7 @@ pub struct RenderPassData1 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct RenderPassData2 {
     unk1: SsbhString,
@@ -266,6 +280,7 @@ pub struct RenderPassData2 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct RenderPassData3 {
     unk1: SsbhString,
@@ -278,6 +293,7 @@ pub struct RenderPassData3 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct RenderPassData8 {
     unk1: SsbhString,
@@ -290,6 +306,7 @@ pub struct RenderPassData8 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct RenderPassData19 {
     unk1: SsbhString,
@@ -298,6 +315,7 @@ pub struct RenderPassData19 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct Unk8Data {
     unk1: u32,
@@ -306,6 +324,7 @@ pub struct Unk8Data {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct ColorClear {
     name: SsbhString,
@@ -315,6 +334,7 @@ pub struct ColorClear {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct DepthStencilClear {
     name: SsbhString,
@@ -324,6 +344,7 @@ pub struct DepthStencilClear {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct Viewport {
     name: SsbhString,
@@ -337,6 +358,7 @@ pub struct Viewport {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct RenderPassData13 {
     unk1: SsbhString,
@@ -350,6 +372,7 @@ pub struct RenderPassData13 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 #[ssbhwrite(pad_after = 8)]
 pub struct RenderPassContainer {
@@ -369,6 +392,7 @@ ssbh_enum!(
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq)]
 pub struct Unk3Data {
     pub unk1: SsbhString,
@@ -381,6 +405,7 @@ pub struct Unk3Data {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct StringPair {
     pub item1: SsbhString,
@@ -389,6 +414,7 @@ pub struct StringPair {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct UnkItem1 {
     pub unk1: SsbhString,
@@ -397,6 +423,7 @@ pub struct UnkItem1 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct UnkItem20 {
     pub unk1: SsbhString,
@@ -405,6 +432,7 @@ pub struct UnkItem20 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct UnkItem21 {
     pub unk1: SsbhString,
@@ -414,6 +442,7 @@ pub struct UnkItem21 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct UnkItem22 {
     pub unk1: SsbhString,
@@ -427,6 +456,7 @@ pub struct UnkItem22 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct UnkItem24 {
     pub unk1: SsbhString,
@@ -444,6 +474,7 @@ ssbh_enum!(
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug, BinRead, SsbhWrite, PartialEq, Eq)]
 pub struct UnkItem3 {
     pub name: SsbhString,
@@ -12,6 +12,7 @@ use ssbh_write::SsbhWrite;
 /// The [name](#structfield.name) and [attribute_name](#structfield.attribute_name) should match the values for a corresponding [AttributeV10][crate::formats::mesh::AttributeV10].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct VertexAttribute {
     pub name: SsbhString,
@@ -21,6 +22,7 @@ pub struct VertexAttribute {
 /// A required material parameter. The [param_id](#structfield.param_id) and [parameter_name](#structfield.parameter_name) match one of the variants in [ParamId](crate::formats::matl::ParamId).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[ssbhwrite(pad_after = 8)]
 pub struct MaterialParameter {
@@ -33,6 +35,7 @@ pub struct MaterialParameter {
 /// Describes the shaders used for each of the stages in the rendering pipeline.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct ShaderStages {
     pub vertex_shader: SsbhString,
@@ -47,6 +50,7 @@ pub struct ShaderStages {
 /// Each [ShaderProgramV0] has a corresponding shader program object in the underlying rendering API such as OpenGL, Vulkan, etc.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct ShaderProgramV0 {
     /// The unique identifier of the shader program, including its [render_pass](#structfield.render_pass).
@@ -64,6 +68,7 @@ pub struct ShaderProgramV0 {
 /// Each [ShaderProgramV1] has a corresponding shader program object in the underlying rendering API such as OpenGL, Vulkan, etc.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct ShaderProgramV1 {
     /// The unique identifier of the shader program, including its [render_pass](#structfield.render_pass).
@@ -81,6 +86,7 @@ pub struct ShaderProgramV1 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct UnkItem {
     pub name: SsbhString,
@@ -93,6 +99,7 @@ pub struct UnkItem {
 /// Compatible with file version 1.0 and 1.1.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[br(import(major_version: u16, minor_version: u16))]
 pub enum Nufx {
@@ -106,6 +113,7 @@ pub enum Nufx {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct NufxV0 {
     pub programs: SsbhArray<ShaderProgramV0>,
@@ -114,6 +122,7 @@ pub struct NufxV0 {
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 pub struct NufxV1 {
     pub programs: SsbhArray<ShaderProgramV1>,
@@ -16,6 +16,7 @@ use ssbh_write::SsbhWrite;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, PartialEq, Eq)]
 #[ssbhwrite(pad_after = 2)]
 pub struct SkelEntryFlags {
@@ -28,6 +29,7 @@ pub struct SkelEntryFlags {
 /// [index](#structfield.index) and [parent_index](#structfield.parent_index) determine the skeleton's bone hierarchy.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, PartialEq, Eq)]
 pub struct SkelBoneEntry {
     /// The name of the bone.
@@ -45,6 +47,7 @@ pub struct SkelBoneEntry {
 /// Compatible with file version 1.0.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite)]
 #[br(import(major_version: u16, minor_version: u16))]
 pub enum Skel {
@@ -90,6 +93,7 @@ impl Version for Skel {
 // https://www.flipcode.com/archives/Billboarding-Excerpt_From_iReal-Time_Renderingi_2E.shtml
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[cfg_attr(
     feature = "strum",
     derive(FromRepr, Display, EnumVariantNames, EnumString)
@@ -125,14 +125,32 @@ mod vectors;
 pub use vectors::{Color4f, Matrix3x3, Matrix4x4, Vector3, Vector4};
 
 mod strings;
-pub use strings::{CString, CString1, SsbhString, SsbhString8};
+pub use strings::{CString, CString1, SsbhString, SsbhString8, StringError};
 
 mod enums;
 pub use enums::{DataType, SsbhEnum64};
 
 pub(crate) use enums::ssbh_enum;
 
-/// Common imports for supported formats.
+/// Common imports for supported formats, math types, and pointer types.
+/**
+```rust
+use ssbh_lib::prelude::*;
+
+let position: Ptr16<Vector3> = Ptr16::new(Vector3::new(1.0, 2.0, 3.0));
+let color: Ptr32<Color4f> = Ptr32::null();
+let transform: Ptr64<Matrix4x4> = Ptr64::new(Matrix4x4::identity());
+let rotation: RelPtr64<Matrix3x3> = RelPtr64::new(Matrix3x3::identity());
+let vector = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+fn read_modl(ssbh: &SsbhFile) -> Option<&Modl> {
+    match &ssbh.data {
+        Ssbh::Modl(modl) => Some(&modl.data),
+        _ => None,
+    }
+}
+```
+ */
 pub mod prelude {
     pub use crate::formats::adj::Adj;
     pub use crate::formats::anim::Anim;
@@ -145,6 +163,8 @@ pub mod prelude {
     pub use crate::formats::nufx::Nufx;
     pub use crate::formats::shdr::Shdr;
     pub use crate::formats::skel::Skel;
+    pub use crate::{Color4f, Matrix3x3, Matrix4x4, Vector3, Vector4};
+    pub use crate::{Ptr16, Ptr32, Ptr64, RelPtr64};
     pub use crate::{Ssbh, SsbhFile};
 }
 
@@ -172,18 +192,33 @@ impl SsbhFile {
     /// The entire file is buffered for performance.
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ReadSsbhError> {
         let mut file = Cursor::new(fs::read(path)?);
-        let ssbh = file.read_le::<SsbhFile>()?;
+        let ssbh = read_tracked::<SsbhFile, _>(&mut file)?;
         Ok(ssbh)
     }
 
     /// Tries to read one of the SSBH types from `reader`.
     /// For best performance when opening from a file, use `from_file` instead.
     pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, ReadSsbhError> {
-        let ssbh = reader.read_le::<SsbhFile>()?;
+        let ssbh = read_tracked::<SsbhFile, _>(reader)?;
 
         Ok(ssbh)
     }
 
+    /// Tries to read one of the SSBH types from `path` using a memory mapped file.
+    /// This avoids copying the entire file into memory and can be faster than `from_file`
+    /// when scanning many large files.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated by this or any other process while reading.
+    /// Modifying the file while it is mapped is undefined behavior.
+    #[cfg(feature = "mmap")]
+    pub unsafe fn from_mmap<P: AsRef<Path>>(path: P) -> Result<Self, ReadSsbhError> {
+        let file = fs::File::open(path)?;
+        let mmap = memmap2::Mmap::map(&file)?;
+        let ssbh = read_tracked::<SsbhFile, _>(&mut Cursor::new(&mmap[..]))?;
+        Ok(ssbh)
+    }
+
     /// Writes the data to the given writer.
     /// For best performance when writing to a file, use `write_to_file` instead.
     pub fn write<W: std::io::Write + Seek>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -200,12 +235,29 @@ impl SsbhFile {
     }
 }
 
+/// Errors while reading files that include the offset where reading failed.
+#[derive(Debug, Error)]
+pub enum ReadError {
+    /// The data could not be parsed.
+    /// The `offset` is the furthest byte position read before the error occurred.
+    /// This is the length of the readable data for truncated files.
+    #[error("Failed to read data at offset 0x{offset:X}: {source}")]
+    BinRead {
+        offset: u64,
+        source: binrw::error::Error,
+    },
+
+    /// An error occurred while trying to access the reader.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
 /// Errors while reading SSBH files.
 #[derive(Debug, Error)]
 pub enum ReadSsbhError {
     /// An error occurred while trying to read the file.
     #[error(transparent)]
-    BinRead(#[from] binrw::error::Error),
+    Read(#[from] ReadError),
 
     /// An error occurred while trying to read the file.
     #[error(transparent)]
@@ -222,8 +274,16 @@ macro_rules! ssbh_read_write_impl {
             /// Tries to read the current SSBH type from `path`.
             /// The entire file is buffered for performance.
             pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ReadSsbhError> {
-                let mut file = Cursor::new(fs::read(path)?);
-                let ssbh = file.read_le::<SsbhFile>()?;
+                let bytes = fs::read(path)?;
+                // Avoid parsing the entire file if the magic is for a different SSBH type.
+                if let Some(file_type) = sniff_format(&bytes) {
+                    if file_type.magic() != $magic {
+                        return Err(ReadSsbhError::InvalidSsbhType);
+                    }
+                }
+
+                let mut file = Cursor::new(bytes);
+                let ssbh = read_tracked::<SsbhFile, _>(&mut file)?;
                 match ssbh.data {
                     $ty2(v) => Ok(v.data),
                     _ => Err(ReadSsbhError::InvalidSsbhType),
@@ -233,7 +293,7 @@ macro_rules! ssbh_read_write_impl {
             /// Tries to read the current SSBH type from `reader`.
             /// For best performance when opening from a file, use `from_file` instead.
             pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, ReadSsbhError> {
-                let ssbh = reader.read_le::<SsbhFile>()?;
+                let ssbh = read_tracked::<SsbhFile, _>(reader)?;
                 match ssbh.data {
                     $ty2(v) => Ok(v.data),
                     _ => Err(ReadSsbhError::InvalidSsbhType),
@@ -263,18 +323,16 @@ macro_rules! read_write_impl {
         impl $ty {
             /// Tries to read the type from `path`.
             /// The entire file is buffered for performance.
-            pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+            pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ReadError> {
                 let mut file = Cursor::new(fs::read(path)?);
-                let value = file.read_le::<$ty>()?;
+                let value = read_tracked::<$ty, _>(&mut file)?;
                 Ok(value)
             }
 
             /// Tries to read the type from `reader`.
             /// For best performance when opening from a file, use `from_file` instead.
-            pub fn read<R: Read + Seek>(
-                reader: &mut R,
-            ) -> Result<Self, Box<dyn std::error::Error>> {
-                let value = reader.read_le::<$ty>()?;
+            pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, ReadError> {
+                let value = read_tracked::<$ty, _>(reader)?;
                 Ok(value)
             }
 
@@ -309,6 +367,52 @@ ssbh_read_write_impl!(prelude::Shdr, Ssbh::Shdr, b"RDHS");
 read_write_impl!(prelude::MeshEx);
 read_write_impl!(prelude::Adj);
 
+// Reads little endian data while recording the furthest position read.
+// Errors for derived types don't always include a position,
+// and the reader is often seeked back to the start of the type on failure.
+fn read_tracked<T: BinRead<Args = ()>, R: Read + Seek>(reader: &mut R) -> Result<T, ReadError> {
+    let mut reader = PositionTracker::new(reader)?;
+    reader.read_le::<T>().map_err(|source| ReadError::BinRead {
+        offset: reader.max_position,
+        source,
+    })
+}
+
+struct PositionTracker<'a, R> {
+    inner: &'a mut R,
+    position: u64,
+    max_position: u64,
+}
+
+impl<'a, R: Seek> PositionTracker<'a, R> {
+    fn new(inner: &'a mut R) -> std::io::Result<Self> {
+        let position = inner.stream_position()?;
+        Ok(Self {
+            inner,
+            position,
+            max_position: position,
+        })
+    }
+}
+
+impl<R: Read> Read for PositionTracker<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        if count > 0 {
+            self.position += count as u64;
+            self.max_position = self.max_position.max(self.position);
+        }
+        Ok(count)
+    }
+}
+
+impl<R: Seek> Seek for PositionTracker<'_, R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.position = self.inner.seek(pos)?;
+        Ok(self.position)
+    }
+}
+
 pub(crate) fn absolute_offset_checked(
     position: u64,
     relative_offset: u64,
@@ -342,6 +446,7 @@ impl Offset for u64 {}
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Ptr<P: Offset, T>(
@@ -413,6 +518,7 @@ impl<P: Offset, T> core::ops::DerefMut for Ptr<P, T> {
 /// A 64 bit file pointer relative to the start of the pointer type.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct RelPtr64<T>(Option<T>);
@@ -506,6 +612,7 @@ pub struct SsbhFile {
 /// The associated magic and format for each SSBH type.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug)]
 pub enum Ssbh {
     #[br(magic = b"BPLH")]
@@ -536,11 +643,90 @@ pub enum Ssbh {
     Shdr(Versioned<shdr::Shdr>),
 }
 
+#[cfg(feature = "deepsize")]
+impl Ssbh {
+    /// Calculates the number of bytes allocated on the heap for strings, arrays, and other data.
+    /// This does not include the stack size of `self` and is based on the capacity of each allocation.
+    /// Unlike [SsbhWrite::size_in_bytes], this is the memory used by the parsed data rather than the size in the file.
+    pub fn heap_size(&self) -> usize {
+        deepsize::DeepSizeOf::deep_size_of(self) - std::mem::size_of_val(self)
+    }
+}
+
+/// The type of SSBH file as determined by [sniff_format].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SsbhFileType {
+    Hlpb,
+    Matl,
+    Modl,
+    Mesh,
+    Skel,
+    Anim,
+    Nrpd,
+    Nufx,
+    Shdr,
+}
+
+impl SsbhFileType {
+    /// The magic stored after the SSBH header for this type such as `b"HSEM"` for [SsbhFileType::Mesh].
+    pub fn magic(&self) -> &'static [u8; 4] {
+        match self {
+            SsbhFileType::Hlpb => b"BPLH",
+            SsbhFileType::Matl => b"LTAM",
+            SsbhFileType::Modl => b"LDOM",
+            SsbhFileType::Mesh => b"HSEM",
+            SsbhFileType::Skel => b"LEKS",
+            SsbhFileType::Anim => b"MINA",
+            SsbhFileType::Nrpd => b"DPRN",
+            SsbhFileType::Nufx => b"XFUN",
+            SsbhFileType::Shdr => b"RDHS",
+        }
+    }
+
+    fn from_magic(magic: &[u8]) -> Option<Self> {
+        match magic {
+            b"BPLH" => Some(SsbhFileType::Hlpb),
+            b"LTAM" => Some(SsbhFileType::Matl),
+            b"LDOM" => Some(SsbhFileType::Modl),
+            b"HSEM" => Some(SsbhFileType::Mesh),
+            b"LEKS" => Some(SsbhFileType::Skel),
+            b"MINA" => Some(SsbhFileType::Anim),
+            b"DPRN" => Some(SsbhFileType::Nrpd),
+            b"XFUN" => Some(SsbhFileType::Nufx),
+            b"RDHS" => Some(SsbhFileType::Shdr),
+            _ => None,
+        }
+    }
+}
+
+/// Determines the SSBH type from the leading bytes of a file without parsing the rest of the data.
+/// Returns [None] if `bytes` is too short or doesn't start with a known SSBH magic.
+///
+/// Formats without a magic like [MeshEx](crate::formats::meshex::MeshEx) and [Adj](crate::formats::adj::Adj)
+/// can't be detected and always return [None].
+/**
+```rust
+use ssbh_lib::{sniff_format, SsbhFileType};
+
+let bytes = b"HBSS\x40\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00HSEM";
+assert_eq!(Some(SsbhFileType::Mesh), sniff_format(bytes));
+assert_eq!(None, sniff_format(b"HBSS"));
+```
+ */
+pub fn sniff_format(bytes: &[u8]) -> Option<SsbhFileType> {
+    // The type magic follows the 16 byte SSBH header.
+    if bytes.get(..4)? != b"HBSS" {
+        return None;
+    }
+    SsbhFileType::from_magic(bytes.get(0x10..0x14)?)
+}
+
 /// A versioned file format with a [u16] major version and [u16] minor version.
 #[binread]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 pub struct Versioned<T: BinRead<Args = (u16, u16)>> {
     #[br(temp)]
     major_version: u16,
@@ -812,6 +998,64 @@ pub(crate) fn write_ssbh_header_and_data<W: Write + Seek>(
     }
 }
 
+/// Writes `ssbh` without storing any of the output and returns the total size in bytes.
+/// This checks that the data can be written without creating a file.
+/// Returns the first error that occurs while writing.
+/// # Examples
+/**
+```rust no_run
+# fn main() -> Result<(), Box<dyn std::error::Error>> {
+use ssbh_lib::{validate_ssbh, SsbhFile};
+
+let ssbh = SsbhFile::from_file("model.numdlb")?;
+let size = validate_ssbh(&ssbh.data)?;
+println!("The file will be {size} bytes.");
+# Ok(()) }
+```
+ */
+pub fn validate_ssbh(ssbh: &Ssbh) -> Result<u64, std::io::Error> {
+    let mut writer = CountingWriter::default();
+    write_ssbh_header_and_data(&mut writer, ssbh)?;
+    Ok(writer.len)
+}
+
+// Discards written bytes while tracking the length of the output.
+// Seeking and overwriting previous bytes is supported for writing offsets.
+#[derive(Default)]
+struct CountingWriter {
+    position: u64,
+    len: u64,
+}
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.position += buf.len() as u64;
+        self.len = self.len.max(self.position);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for CountingWriter {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        self.position = position.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Invalid seek to a negative or overflowing position.",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
+
 pub(crate) fn write_buffered<
     W: Write + Seek,
     F: Fn(&mut Cursor<Vec<u8>>) -> std::io::Result<()>,
@@ -825,7 +1069,7 @@ pub(crate) fn write_buffered<
     write_data(&mut cursor)?;
 
     writer.write_all(cursor.get_mut())?;
-    Ok(())
+    writer.flush()
 }
 
 // TODO: This can probably just be derived.
@@ -853,6 +1097,123 @@ mod tests {
     use super::*;
     use hexlit::hex;
 
+    #[test]
+    fn validate_ssbh_modl() {
+        let ssbh = Ssbh::Modl(Versioned {
+            data: modl::Modl::V17 {
+                model_name: "model".into(),
+                skeleton_file_name: "model.nusktb".into(),
+                material_file_names: vec!["model.numatb".into()].into(),
+                animation_file_name: RelPtr64::null(),
+                mesh_file_name: "model.numshb".into(),
+                entries: SsbhArray::new(),
+            },
+        });
+
+        let mut writer = Cursor::new(Vec::new());
+        write_ssbh_header_and_data(&mut writer, &ssbh).unwrap();
+
+        assert_eq!(
+            writer.into_inner().len() as u64,
+            validate_ssbh(&ssbh).unwrap()
+        );
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn read_ssbh_file_from_mmap() {
+        let ssbh = SsbhFile {
+            data: Ssbh::Modl(Versioned {
+                data: modl::Modl::V17 {
+                    model_name: "model".into(),
+                    skeleton_file_name: "model.nusktb".into(),
+                    material_file_names: vec!["model.numatb".into()].into(),
+                    animation_file_name: RelPtr64::null(),
+                    mesh_file_name: "model.numshb".into(),
+                    entries: SsbhArray::new(),
+                },
+            }),
+        };
+
+        let path = std::env::temp_dir().join("ssbh_lib_read_ssbh_file_from_mmap.numdlb");
+        ssbh.write_to_file(&path).unwrap();
+
+        let result = unsafe { SsbhFile::from_mmap(&path) };
+        std::fs::remove_file(&path).unwrap();
+
+        match result.unwrap().data {
+            Ssbh::Modl(modl) => match modl.data {
+                modl::Modl::V17 { model_name, .. } => {
+                    assert_eq!("model", model_name.to_str().unwrap())
+                }
+            },
+            _ => panic!("unexpected SSBH type"),
+        }
+    }
+
+    #[cfg(feature = "deepsize")]
+    #[test]
+    fn ssbh_heap_size() {
+        let ssbh = Ssbh::Modl(Versioned {
+            data: modl::Modl::V17 {
+                model_name: SsbhString::try_from_str("a").unwrap(),
+                skeleton_file_name: SsbhString::try_from_str("bc").unwrap(),
+                material_file_names: vec![SsbhString::try_from_str("d").unwrap()].into(),
+                animation_file_name: RelPtr64::null(),
+                mesh_file_name: SsbhString8::try_from_str("ef").unwrap(),
+                entries: SsbhArray::new(),
+            },
+        });
+
+        // Each string's characters and the single element material file name array.
+        assert_eq!(
+            1 + 2 + std::mem::size_of::<SsbhString>() + 1 + 2,
+            ssbh.heap_size()
+        );
+    }
+
+    #[test]
+    fn counting_writer_seek() {
+        let mut writer = CountingWriter::default();
+        writer.write_all(&[0u8; 8]).unwrap();
+        writer.seek(SeekFrom::Start(2)).unwrap();
+        writer.write_all(&[1u8; 2]).unwrap();
+        assert_eq!(4, writer.position);
+        assert_eq!(8, writer.len);
+
+        assert!(writer.seek(SeekFrom::Current(-5)).is_err());
+        assert_eq!(10, writer.seek(SeekFrom::End(2)).unwrap());
+    }
+
+    #[test]
+    fn read_truncated_meshex() {
+        // The mesh object group count is missing.
+        let mut reader = Cursor::new(hex!(
+            40000000 00000000 // file length
+            01000000          // entry count
+        ));
+        let result = formats::meshex::MeshEx::read(&mut reader);
+        assert!(matches!(result, Err(ReadError::BinRead { offset: 12, .. })));
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("Failed to read data at offset 0xC:"));
+    }
+
+    #[test]
+    fn read_truncated_ssbh() {
+        // The SSBH type magic is incomplete.
+        let mut reader = Cursor::new(hex!(
+            48425353 00000000 00000000 00000000
+            4250
+        ));
+        let result = SsbhFile::read(&mut reader);
+        assert!(matches!(
+            result,
+            Err(ReadSsbhError::Read(ReadError::BinRead { offset: 0x12, .. }))
+        ));
+    }
+
     #[test]
     fn new_relptr64() {
         let ptr = RelPtr64::new(5u32);
@@ -1045,4 +1406,50 @@ mod tests {
         );
         assert_eq!(20, data_ptr);
     }
+
+    #[test]
+    fn sniff_format_ssbh_types() {
+        for file_type in [
+            SsbhFileType::Hlpb,
+            SsbhFileType::Matl,
+            SsbhFileType::Modl,
+            SsbhFileType::Mesh,
+            SsbhFileType::Skel,
+            SsbhFileType::Anim,
+            SsbhFileType::Nrpd,
+            SsbhFileType::Nufx,
+            SsbhFileType::Shdr,
+        ] {
+            let mut writer = Cursor::new(Vec::new());
+            write_ssbh_header(&mut writer, file_type.magic()).unwrap();
+            assert_eq!(Some(file_type), sniff_format(writer.get_ref()));
+        }
+    }
+
+    #[test]
+    fn sniff_format_matl() {
+        assert_eq!(
+            Some(SsbhFileType::Matl),
+            sniff_format(&hex!(
+                "48425353 40000000 00000000 00000000 4C54414D 01000600"
+            ))
+        );
+    }
+
+    #[test]
+    fn sniff_format_invalid() {
+        assert_eq!(None, sniff_format(&[]));
+        assert_eq!(None, sniff_format(b"HBSS"));
+        assert_eq!(
+            None,
+            sniff_format(&hex!("48425353 40000000 00000000 00000000 4C54414D")[..19])
+        );
+        // Unknown type magic.
+        assert_eq!(
+            None,
+            sniff_format(&hex!("48425353 40000000 00000000 00000000 41424344"))
+        );
+        // Adj files have no magic.
+        assert_eq!(None, sniff_format(&hex!("01000000 00000000 00000000")));
+    }
 }
@@ -2,6 +2,7 @@ use crate::RelPtr64;
 use binrw::BinRead;
 use ssbh_write::SsbhWrite;
 use std::{io::Read, str::FromStr};
+use thiserror::Error;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -9,6 +10,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// An N-byte aligned [CString] with position determined by a relative offset.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, SsbhWrite, PartialEq, Eq, Clone)]
 pub struct SsbhStringN<const N: usize>(RelPtr64<CString<N>>);
 
@@ -18,6 +20,25 @@ pub type SsbhString = SsbhStringN<4>;
 /// An 8-byte aligned [CString] with position determined by a relative offset.
 pub type SsbhString8 = SsbhStringN<8>;
 
+/// Errors while creating strings.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum StringError {
+    /// The string contains a null byte before the end of the string.
+    /// Null terminated strings can't represent interior null bytes.
+    #[error(
+        "Found a null byte at position {} before the end of the string.",
+        position
+    )]
+    InteriorNul { position: usize },
+}
+
+fn find_interior_nul(text: &str) -> Result<(), StringError> {
+    match text.bytes().position(|b| b == 0u8) {
+        Some(position) => Err(StringError::InteriorNul { position }),
+        None => Ok(()),
+    }
+}
+
 /// A null terminated string without additional alignment requirements.
 pub type CString1 = CString<1>;
 
@@ -43,6 +64,23 @@ impl<const N: usize> CString<N> {
         Self(bytes.iter().copied().take_while(|b| *b != 0u8).collect())
     }
 
+    /// Creates the string from `text` without truncating at the first null byte.
+    /// Returns an error if `text` contains any null bytes.
+    /**
+    ```rust
+    # use ssbh_lib::{CString, StringError};
+    assert_eq!(Some("abc"), CString::<4>::try_new("abc").unwrap().to_str());
+    assert_eq!(
+        Err(StringError::InteriorNul { position: 2 }),
+        CString::<4>::try_new("ab\0cd")
+    );
+    ```
+     */
+    pub fn try_new(text: &str) -> Result<Self, StringError> {
+        find_interior_nul(text)?;
+        Ok(Self(text.as_bytes().to_vec()))
+    }
+
     /// Converts the underlying buffer to a [str].
     /// The result will be [None] if the the conversion failed.
     pub fn to_str(&self) -> Option<&str> {
@@ -103,6 +141,13 @@ impl<'a, const N: usize> arbitrary::Arbitrary<'a> for CString<N> {
     }
 }
 
+#[cfg(feature = "deepsize")]
+impl<const N: usize> deepsize::DeepSizeOf for CString<N> {
+    fn deep_size_of_children(&self, context: &mut deepsize::Context) -> usize {
+        self.0.deep_size_of_children(context)
+    }
+}
+
 impl<const N: usize> FromStr for CString<N> {
     type Err = core::convert::Infallible;
 
@@ -161,6 +206,13 @@ impl<const N: usize> SsbhStringN<N> {
         Self(RelPtr64::new(CString::from_bytes(bytes)))
     }
 
+    /// Creates the string from `text` without truncating at the first null byte.
+    /// Returns an error if `text` contains any null bytes.
+    /// See [CString::try_new].
+    pub fn try_from_str(text: &str) -> Result<Self, StringError> {
+        Ok(Self(RelPtr64::new(CString::try_new(text)?)))
+    }
+
     /// Converts the underlying buffer to a [str].
     /// The result will be [None] if the offset is null or the conversion failed.
     pub fn to_str(&self) -> Option<&str> {
@@ -265,6 +317,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cstring_try_new() {
+        assert_eq!(Some("abc"), CString::<4>::try_new("abc").unwrap().to_str());
+        assert_eq!(Some(""), CString::<4>::try_new("").unwrap().to_str());
+        assert_eq!(
+            Err(StringError::InteriorNul { position: 2 }),
+            CString::<4>::try_new("ab\0cd")
+        );
+        assert_eq!(
+            Err(StringError::InteriorNul { position: 3 }),
+            CString::<4>::try_new("abc\0")
+        );
+    }
+
+    #[test]
+    fn ssbh_string_try_from_str() {
+        assert_eq!(
+            Some("abc"),
+            SsbhString::try_from_str("abc").unwrap().to_str()
+        );
+        assert_eq!(
+            Err(StringError::InteriorNul { position: 2 }),
+            SsbhString::try_from_str("ab\0cd")
+        );
+        assert_eq!(
+            "Found a null byte at position 2 before the end of the string.",
+            SsbhString8::try_from_str("ab\0cd").unwrap_err().to_string()
+        );
+    }
+
     #[test]
     fn ssbh_string_from_str() {
         let s = SsbhString::from_str("abc").unwrap();
@@ -277,6 +359,29 @@ mod tests {
         assert_eq!("abc", s.to_str().unwrap());
     }
 
+    #[test]
+    fn ssbh_write_str_matches_cstring() {
+        let mut expected = Cursor::new(Vec::new());
+        CString::<4>::from("All").write(&mut expected).unwrap();
+
+        let mut writer = Cursor::new(Vec::new());
+        "All".write(&mut writer).unwrap();
+        assert_eq!(expected.get_ref(), writer.get_ref());
+
+        let mut writer = Cursor::new(Vec::new());
+        "All".to_string().write(&mut writer).unwrap();
+        assert_eq!(expected.get_ref(), writer.get_ref());
+
+        assert_eq!(
+            CString::<4>::alignment_in_bytes(),
+            <&str>::alignment_in_bytes()
+        );
+        assert_eq!(
+            CString::<4>::alignment_in_bytes(),
+            String::alignment_in_bytes()
+        );
+    }
+
     #[test]
     fn ssbh_write_string() {
         let value = SsbhString::from("scouter1Shape");
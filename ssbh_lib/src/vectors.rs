@@ -1,13 +1,15 @@
-use binrw::BinRead;
+use binrw::{BinRead, BinWrite};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use ssbh_write::SsbhWrite;
+use std::ops::{Index, IndexMut, Neg};
 
 /// 3 contiguous floats for encoding XYZ or RGB data.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(BinRead, Debug, PartialEq, SsbhWrite, Clone, Copy, Default)]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
+#[derive(BinRead, BinWrite, Debug, PartialEq, SsbhWrite, Clone, Copy, Default)]
 pub struct Vector3 {
     pub x: f32,
     pub y: f32,
@@ -37,6 +39,25 @@ impl Vector3 {
         (*self).into()
     }
 
+    /// Returns the little endian bytes for the components as they would be stored in an SSBH file.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Vector3;
+    assert_eq!(
+        [0, 0, 128, 63, 0, 0, 0, 192, 0, 0, 0, 63],
+        Vector3::new(1.0, -2.0, 0.5).to_le_bytes()
+    );
+    ```
+     */
+    pub fn to_le_bytes(&self) -> [u8; 12] {
+        let mut bytes = [0u8; 12];
+        for (dst, f) in bytes.chunks_exact_mut(4).zip(self.to_array()) {
+            dst.copy_from_slice(&f.to_le_bytes());
+        }
+        bytes
+    }
+
     /// Creates a [Vector4] from `self` and the given `w` component.
     /// # Examples
     /**
@@ -93,6 +114,46 @@ impl Vector3 {
             f32::max(self.z, other.z),
         )
     }
+
+    /// Returns the component-wise clamp of the vector between `min` and `max`. See [f32::clamp].
+    ///
+    /// # Panics
+    /// Panics if any component of `min` is greater than the corresponding component of `max`
+    /// or if any component of `min` or `max` is NaN.
+    ///
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Vector3;
+    let a = Vector3::new(-1.0, 0.5, 2.0);
+    let min = Vector3::new(0.0, 0.0, 0.0);
+    let max = Vector3::new(1.0, 1.0, 1.0);
+
+    assert_eq!(a.clamp(min, max), Vector3::new(0.0, 0.5, 1.0));
+    ```
+     */
+    pub fn clamp(self, min: Vector3, max: Vector3) -> Self {
+        Self::new(
+            f32::clamp(self.x, min.x, max.x),
+            f32::clamp(self.y, min.y, max.y),
+            f32::clamp(self.z, min.z, max.z),
+        )
+    }
+
+    /// Returns the component-wise absolute value of the vector. See [f32::abs].
+    ///
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Vector3;
+    let a = Vector3::new(-1.0, 2.0, -3.0);
+
+    assert_eq!(a.abs(), Vector3::new(1.0, 2.0, 3.0));
+    ```
+     */
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
 }
 
 impl From<(f32, f32, f32)> for Vector3 {
@@ -127,9 +188,44 @@ impl From<Vector3> for [f32; 3] {
     }
 }
 
+impl Neg for Vector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Accesses the components in the order x, y, z.
+/// Panics if `index` is greater than 2.
+impl Index<usize> for Vector3 {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Index {index} is out of range for Vector3."),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vector3 {
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Index {index} is out of range for Vector3."),
+        }
+    }
+}
+
 /// A column-major 3x3 matrix of contiguous floats.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, PartialEq, SsbhWrite, Clone, Copy, Default)]
 pub struct Matrix3x3 {
     pub col1: Vector3,
@@ -209,10 +305,23 @@ impl Matrix3x3 {
     }
 }
 
+impl From<[[f32; 3]; 3]> for Matrix3x3 {
+    fn from(cols: [[f32; 3]; 3]) -> Self {
+        Self::from_cols_array(&cols)
+    }
+}
+
+impl From<Matrix3x3> for [[f32; 3]; 3] {
+    fn from(m: Matrix3x3) -> Self {
+        m.to_cols_array()
+    }
+}
+
 /// 4 contiguous floats for encoding XYZW or RGBA data.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(BinRead, Debug, PartialEq, SsbhWrite, Clone, Copy, Default)]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
+#[derive(BinRead, BinWrite, Debug, PartialEq, SsbhWrite, Clone, Copy, Default)]
 pub struct Vector4 {
     pub x: f32,
     pub y: f32,
@@ -248,6 +357,25 @@ impl Vector4 {
         (*self).into()
     }
 
+    /// Returns the little endian bytes for the components as they would be stored in an SSBH file.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Vector4;
+    assert_eq!(
+        [0, 0, 128, 63, 0, 0, 0, 192, 0, 0, 0, 63, 0, 0, 128, 63],
+        Vector4::new(1.0, -2.0, 0.5, 1.0).to_le_bytes()
+    );
+    ```
+     */
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        for (dst, f) in bytes.chunks_exact_mut(4).zip(self.to_array()) {
+            dst.copy_from_slice(&f.to_le_bytes());
+        }
+        bytes
+    }
+
     /// Returns the component-wise min of the two vectors. See [f32::min].
     ///     
     /// # Examples
@@ -289,6 +417,106 @@ impl Vector4 {
             f32::max(self.w, other.w),
         )
     }
+
+    /// Returns the component-wise clamp of the vector between `min` and `max`. See [f32::clamp].
+    ///
+    /// # Panics
+    /// Panics if any component of `min` is greater than the corresponding component of `max`
+    /// or if any component of `min` or `max` is NaN.
+    ///
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Vector4;
+    let a = Vector4::new(-1.0, 0.5, 2.0, 1.0);
+    let min = Vector4::new(0.0, 0.0, 0.0, 0.0);
+    let max = Vector4::new(1.0, 1.0, 1.0, 1.0);
+
+    assert_eq!(a.clamp(min, max), Vector4::new(0.0, 0.5, 1.0, 1.0));
+    ```
+     */
+    pub fn clamp(self, min: Vector4, max: Vector4) -> Self {
+        Self::new(
+            f32::clamp(self.x, min.x, max.x),
+            f32::clamp(self.y, min.y, max.y),
+            f32::clamp(self.z, min.z, max.z),
+            f32::clamp(self.w, min.w, max.w),
+        )
+    }
+
+    /// Returns the component-wise absolute value of the vector. See [f32::abs].
+    ///
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Vector4;
+    let a = Vector4::new(-1.0, 2.0, -3.0, -0.0);
+
+    assert_eq!(a.abs(), Vector4::new(1.0, 2.0, 3.0, 0.0));
+    ```
+     */
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
+    }
+
+    /// Returns the conjugate of the quaternion with components `(x, y, z, w)` by negating `x`, `y`, and `z`.
+    /// For unit quaternions, this is equivalent to [Vector4::quat_inverse].
+    ///
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Vector4;
+    let q = Vector4::new(0.5, 0.5, 0.5, 0.5);
+    assert_eq!(Vector4::new(-0.5, -0.5, -0.5, 0.5), q.quat_conjugate());
+    assert_eq!(Vector4::new(0.0, 0.0, 0.0, 1.0), q.quat_mul(q.quat_conjugate()));
+    ```
+     */
+    pub fn quat_conjugate(&self) -> Vector4 {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the inverse of the quaternion with components `(x, y, z, w)`
+    /// calculated as the conjugate divided by the squared length.
+    ///
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Vector4;
+    let q = Vector4::new(0.0, 0.0, 2.0, 0.0);
+    assert_eq!(Vector4::new(0.0, 0.0, -0.5, 0.0), q.quat_inverse());
+    assert_eq!(Vector4::new(0.0, 0.0, 0.0, 1.0), q.quat_mul(q.quat_inverse()));
+    ```
+     */
+    pub fn quat_inverse(&self) -> Vector4 {
+        let length_squared = self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w;
+        let conjugate = self.quat_conjugate();
+        Self::new(
+            conjugate.x / length_squared,
+            conjugate.y / length_squared,
+            conjugate.z / length_squared,
+            conjugate.w / length_squared,
+        )
+    }
+
+    /// Returns the quaternion product `self * other` for quaternions with components `(x, y, z, w)`.
+    /// The resulting rotation applies `other` followed by `self`.
+    ///
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Vector4;
+    let q = Vector4::new(0.5, 0.5, 0.5, 0.5);
+    assert_eq!(Vector4::new(0.0, 0.0, 0.0, 1.0), q.quat_mul(q.quat_inverse()));
+    ```
+     */
+    pub fn quat_mul(self, other: Vector4) -> Vector4 {
+        Self::new(
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        )
+    }
 }
 
 impl From<(f32, f32, f32, f32)> for Vector4 {
@@ -325,9 +553,46 @@ impl From<Vector4> for [f32; 4] {
     }
 }
 
+impl Neg for Vector4 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+/// Accesses the components in the order x, y, z, w.
+/// Panics if `index` is greater than 3.
+impl Index<usize> for Vector4 {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Index {index} is out of range for Vector4."),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vector4 {
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Index {index} is out of range for Vector4."),
+        }
+    }
+}
+
 /// 4 contiguous floats for encoding RGBA data.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, Clone, Copy, PartialEq, SsbhWrite)]
 pub struct Color4f {
     pub r: f32,
@@ -336,9 +601,69 @@ pub struct Color4f {
     pub a: f32,
 }
 
+impl Color4f {
+    /// Creates a color from 8-bit unsigned normalized RGBA values.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Color4f;
+    let color = Color4f::from_rgba8(128, 128, 128, 255);
+    assert_eq!(128.0 / 255.0, color.r);
+    assert_eq!(1.0, color.a);
+    assert_eq!([128, 128, 128, 255], color.to_rgba8());
+    ```
+     */
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self {
+            r: r as f32 / 255.0,
+            g: g as f32 / 255.0,
+            b: b as f32 / 255.0,
+            a: a as f32 / 255.0,
+        }
+    }
+
+    /// Converts the color to 8-bit unsigned normalized RGBA values.
+    /// Components are clamped to the range `0.0` to `1.0`.
+    /// # Examples
+    /**
+    ```rust
+    # use ssbh_lib::Color4f;
+    let color = Color4f { r: 0.5, g: 2.0, b: -1.0, a: 1.0 };
+    assert_eq!([128, 255, 0, 255], color.to_rgba8());
+    ```
+     */
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        let to_u8 = |f: f32| (f.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [to_u8(self.r), to_u8(self.g), to_u8(self.b), to_u8(self.a)]
+    }
+}
+
+impl From<Vector4> for Color4f {
+    fn from(v: Vector4) -> Self {
+        Self {
+            r: v.x,
+            g: v.y,
+            b: v.z,
+            a: v.w,
+        }
+    }
+}
+
+impl From<Color4f> for Vector4 {
+    fn from(c: Color4f) -> Self {
+        Self {
+            x: c.r,
+            y: c.g,
+            z: c.b,
+            w: c.a,
+        }
+    }
+}
+
 /// A column-major 4x4 matrix of contiguous floats.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "deepsize", derive(deepsize::DeepSizeOf))]
 #[derive(BinRead, Debug, PartialEq, SsbhWrite)]
 pub struct Matrix4x4 {
     pub col1: Vector4,
@@ -424,6 +749,217 @@ impl Matrix4x4 {
             col4: cols[3].into(),
         }
     }
+
+    /// Returns `true` if the last row is `[0, 0, 0, 1]` and the upper 3x3 matrix is invertible
+    /// with all comparisons using a tolerance of `epsilon`.
+    /// Affine matrices can be decomposed into scale, rotation, and translation.
+    /**
+    ```rust
+    use ssbh_lib::{Vector4, Matrix4x4};
+
+    assert!(Matrix4x4::identity().is_affine(1e-6));
+
+    // A perspective projection.
+    let m = Matrix4x4 {
+        col1: Vector4::new(1.0, 0.0, 0.0, 0.0),
+        col2: Vector4::new(0.0, 1.0, 0.0, 0.0),
+        col3: Vector4::new(0.0, 0.0, -1.0, -1.0),
+        col4: Vector4::new(0.0, 0.0, -0.2, 0.0),
+    };
+    assert!(!m.is_affine(1e-6));
+    ```
+    */
+    pub fn is_affine(&self, epsilon: f32) -> bool {
+        let last_row = [self.col1.w, self.col2.w, self.col3.w, self.col4.w];
+        last_row
+            .iter()
+            .zip([0.0, 0.0, 0.0, 1.0])
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+            && self.determinant3x3().abs() > epsilon
+    }
+
+    /// Returns `true` if the columns of the upper 3x3 matrix have the same length
+    /// with a tolerance of `epsilon`.
+    /// This ignores translation and any projection in the last row.
+    /**
+    ```rust
+    use ssbh_lib::{Vector4, Matrix4x4};
+
+    assert!(Matrix4x4::identity().has_uniform_scale(1e-6));
+
+    let m = Matrix4x4 {
+        col1: Vector4::new(2.0, 0.0, 0.0, 0.0),
+        col2: Vector4::new(0.0, 1.0, 0.0, 0.0),
+        col3: Vector4::new(0.0, 0.0, 1.0, 0.0),
+        col4: Vector4::new(0.0, 0.0, 0.0, 1.0),
+    };
+    assert!(!m.has_uniform_scale(1e-6));
+    ```
+    */
+    pub fn has_uniform_scale(&self, epsilon: f32) -> bool {
+        let length = |c: &Vector4| (c.x * c.x + c.y * c.y + c.z * c.z).sqrt();
+        let x = length(&self.col1);
+        let y = length(&self.col2);
+        let z = length(&self.col3);
+        (x - y).abs() <= epsilon && (x - z).abs() <= epsilon && (y - z).abs() <= epsilon
+    }
+
+    fn determinant3x3(&self) -> f32 {
+        let (a, b, c) = (&self.col1, &self.col2, &self.col3);
+        a.x * (b.y * c.z - c.y * b.z) - b.x * (a.y * c.z - c.y * a.z)
+            + c.x * (a.y * b.z - b.y * a.z)
+    }
+}
+
+impl From<[[f32; 4]; 4]> for Matrix4x4 {
+    fn from(cols: [[f32; 4]; 4]) -> Self {
+        Self::from_cols_array(&cols)
+    }
+}
+
+impl From<Matrix4x4> for [[f32; 4]; 4] {
+    fn from(m: Matrix4x4) -> Self {
+        m.to_cols_array()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec3> for Vector3 {
+    fn from(v: glam::Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vector3> for glam::Vec3 {
+    fn from(v: Vector3) -> Self {
+        glam::Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec4> for Vector4 {
+    fn from(v: glam::Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vector4> for glam::Vec4 {
+    fn from(v: Vector4) -> Self {
+        glam::Vec4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Mat3> for Matrix3x3 {
+    fn from(m: glam::Mat3) -> Self {
+        Self::from_cols_array(&m.to_cols_array_2d())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Matrix3x3> for glam::Mat3 {
+    fn from(m: Matrix3x3) -> Self {
+        glam::Mat3::from_cols_array_2d(&m.to_cols_array())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Mat4> for Matrix4x4 {
+    fn from(m: glam::Mat4) -> Self {
+        Self::from_cols_array(&m.to_cols_array_2d())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Matrix4x4> for glam::Mat4 {
+    fn from(m: Matrix4x4) -> Self {
+        glam::Mat4::from_cols_array_2d(&m.to_cols_array())
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector3<f32>> for Vector3 {
+    fn from(v: mint::Vector3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Vector3> for mint::Vector3<f32> {
+    fn from(v: Vector3) -> Self {
+        mint::Vector3 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector4<f32>> for Vector4 {
+    fn from(v: mint::Vector4<f32>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Vector4> for mint::Vector4<f32> {
+    fn from(v: Vector4) -> Self {
+        mint::Vector4 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: v.w,
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix3<f32>> for Matrix3x3 {
+    fn from(m: mint::ColumnMatrix3<f32>) -> Self {
+        Self {
+            col1: m.x.into(),
+            col2: m.y.into(),
+            col3: m.z.into(),
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Matrix3x3> for mint::ColumnMatrix3<f32> {
+    fn from(m: Matrix3x3) -> Self {
+        mint::ColumnMatrix3 {
+            x: m.col1.into(),
+            y: m.col2.into(),
+            z: m.col3.into(),
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix4<f32>> for Matrix4x4 {
+    fn from(m: mint::ColumnMatrix4<f32>) -> Self {
+        Self {
+            col1: m.x.into(),
+            col2: m.y.into(),
+            col3: m.z.into(),
+            col4: m.w.into(),
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Matrix4x4> for mint::ColumnMatrix4<f32> {
+    fn from(m: Matrix4x4) -> Self {
+        mint::ColumnMatrix4 {
+            x: m.col1.into(),
+            y: m.col2.into(),
+            z: m.col3.into(),
+            w: m.col4.into(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -435,6 +971,26 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn matrix4x4_degenerate_not_affine() {
+        let m = Matrix4x4::from_cols_array(&[
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [1.0, 2.0, 3.0, 1.0],
+        ]);
+        assert!(!m.is_affine(1e-6));
+
+        let m = Matrix4x4::from_cols_array(&[
+            [0.0, 2.0, 0.0, 0.0],
+            [-2.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 2.0, 0.0],
+            [1.0, 2.0, 3.0, 1.0],
+        ]);
+        assert!(m.is_affine(1e-6));
+        assert!(m.has_uniform_scale(1e-6));
+    }
+
     #[test]
     fn vector3_conversions() {
         assert_eq!((1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 3.0).into());
@@ -466,6 +1022,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn matrix3x3_array_conversions() {
+        let elements = [[1f32, 2f32, 3f32], [4f32, 5f32, 6f32], [7f32, 8f32, 9f32]];
+        let m = Matrix3x3::from(elements);
+        assert_eq!(Vector3::new(4.0, 5.0, 6.0), m.col2);
+        assert_eq!(elements, <[[f32; 3]; 3]>::from(m));
+    }
+
+    #[test]
+    fn matrix4x4_array_conversions() {
+        let elements = [
+            [1f32, 2f32, 3f32, 4f32],
+            [5f32, 6f32, 7f32, 8f32],
+            [9f32, 10f32, 11f32, 12f32],
+            [13f32, 14f32, 15f32, 16f32],
+        ];
+        let m: Matrix4x4 = elements.into();
+        assert_eq!(Vector4::new(13.0, 14.0, 15.0, 16.0), m.col4);
+        assert_eq!(elements, <[[f32; 4]; 4]>::from(m));
+    }
+
+    #[test]
+    fn quat_mul_compose_rotations() {
+        // Two 90 degree rotations about Z combine to a 180 degree rotation about Z.
+        let half_sqrt2 = std::f32::consts::FRAC_1_SQRT_2;
+        let q = Vector4::new(0.0, 0.0, half_sqrt2, half_sqrt2);
+        let result = q.quat_mul(q);
+        assert!(result.x.abs() < 1e-6);
+        assert!(result.y.abs() < 1e-6);
+        assert!((result.z - 1.0).abs() < 1e-6);
+        assert!(result.w.abs() < 1e-6);
+    }
+
+    #[test]
+    fn quat_mul_non_commutative() {
+        let x = Vector4::new(1.0, 0.0, 0.0, 0.0);
+        let y = Vector4::new(0.0, 1.0, 0.0, 0.0);
+        assert_eq!(Vector4::new(0.0, 0.0, 1.0, 0.0), x.quat_mul(y));
+        assert_eq!(Vector4::new(0.0, 0.0, -1.0, 0.0), y.quat_mul(x));
+    }
+
+    #[test]
+    fn vector3_neg_index() {
+        let mut v = -Vector3::new(1.0, -2.0, 3.0);
+        assert_eq!(Vector3::new(-1.0, 2.0, -3.0), v);
+        assert_eq!((-1.0, 2.0, -3.0), (v[0], v[1], v[2]));
+
+        v[1] = 5.0;
+        assert_eq!(Vector3::new(-1.0, 5.0, -3.0), v);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vector3_index_out_of_range() {
+        let _ = Vector3::ZERO[3];
+    }
+
+    #[test]
+    fn vector4_neg_index() {
+        let mut v = -Vector4::new(1.0, -2.0, 3.0, -4.0);
+        assert_eq!(Vector4::new(-1.0, 2.0, -3.0, 4.0), v);
+        assert_eq!((-1.0, 2.0, -3.0, 4.0), (v[0], v[1], v[2], v[3]));
+
+        v[3] = 5.0;
+        assert_eq!(Vector4::new(-1.0, 2.0, -3.0, 5.0), v);
+    }
+
+    #[test]
+    fn color4f_conversions() {
+        assert_eq!(
+            Vector4::new(0.1, 0.2, 0.3, 0.4),
+            Color4f {
+                r: 0.1,
+                g: 0.2,
+                b: 0.3,
+                a: 0.4
+            }
+            .into()
+        );
+        assert_eq!(
+            Color4f {
+                r: 0.1,
+                g: 0.2,
+                b: 0.3,
+                a: 0.4
+            },
+            Vector4::new(0.1, 0.2, 0.3, 0.4).into()
+        );
+    }
+
+    #[test]
+    fn color4f_rgba8() {
+        for i in 0..=255u8 {
+            assert_eq!([i, i, i, 255], Color4f::from_rgba8(i, i, i, 255).to_rgba8());
+        }
+    }
+
     #[test]
     fn read_vector3() {
         let mut reader = Cursor::new(hex!("0000803F 000000C0 0000003F"));
@@ -485,6 +1138,21 @@ mod tests {
         assert_eq!(1.0f32, value.w);
     }
 
+    #[test]
+    fn vector4_to_le_bytes() {
+        assert_eq!(
+            hex!("cdcccc3e 0000c03f 0000803f 0000803f"),
+            Vector4::new(0.4, 1.5, 1.0, 1.0).to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn vector4_to_le_bytes_round_trip() {
+        let value = Vector4::new(1.0, -2.0, 0.5, 1.0);
+        let mut reader = Cursor::new(value.to_le_bytes());
+        assert_eq!(value, reader.read_le::<Vector4>().unwrap());
+    }
+
     #[test]
     fn read_color4f() {
         let mut reader = Cursor::new(hex!("0000803E 0000003F 0000003E 0000803F"));
@@ -522,4 +1190,79 @@ mod tests {
         assert_eq!(Vector3::new(0f32, 1f32, 0f32), value.col2);
         assert_eq!(Vector3::new(0f32, 0f32, 1f32), value.col3);
     }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_vector_conversions() {
+        assert_eq!(
+            glam::Vec3::new(1.0, 2.0, 3.0),
+            glam::Vec3::from(Vector3::new(1.0, 2.0, 3.0))
+        );
+        assert_eq!(
+            Vector4::new(1.0, 2.0, 3.0, 4.0),
+            Vector4::from(glam::Vec4::new(1.0, 2.0, 3.0, 4.0))
+        );
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_matrix3x3_round_trip() {
+        let m = Matrix3x3::from_cols_array(&[
+            [1f32, 2f32, 3f32],
+            [4f32, 5f32, 6f32],
+            [7f32, 8f32, 9f32],
+        ]);
+        let glam_m = glam::Mat3::from(m);
+        assert_eq!(glam::Vec3::new(4.0, 5.0, 6.0), glam_m.y_axis);
+        assert_eq!(m, Matrix3x3::from(glam_m));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_matrix4x4_round_trip() {
+        let elements = [
+            [1f32, 2f32, 3f32, 4f32],
+            [5f32, 6f32, 7f32, 8f32],
+            [9f32, 10f32, 11f32, 12f32],
+            [13f32, 14f32, 15f32, 16f32],
+        ];
+        let glam_m = glam::Mat4::from(Matrix4x4::from_cols_array(&elements));
+        assert_eq!(glam::Vec4::new(13.0, 14.0, 15.0, 16.0), glam_m.w_axis);
+        assert_eq!(elements, Matrix4x4::from(glam_m).to_cols_array());
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_vector_conversions() {
+        let v: mint::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+        assert_eq!(mint::Vector3::from([1.0, 2.0, 3.0]), v);
+        assert_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::from(v));
+
+        let v: mint::Vector4<f32> = Vector4::new(1.0, 2.0, 3.0, 4.0).into();
+        assert_eq!(mint::Vector4::from([1.0, 2.0, 3.0, 4.0]), v);
+        assert_eq!(Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::from(v));
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_matrix3x3_round_trip() {
+        let elements = [[1f32, 2f32, 3f32], [4f32, 5f32, 6f32], [7f32, 8f32, 9f32]];
+        let mint_m = mint::ColumnMatrix3::from(Matrix3x3::from_cols_array(&elements));
+        assert_eq!(mint::Vector3::from([4.0, 5.0, 6.0]), mint_m.y);
+        assert_eq!(elements, Matrix3x3::from(mint_m).to_cols_array());
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_matrix4x4_round_trip() {
+        let elements = [
+            [1f32, 2f32, 3f32, 4f32],
+            [5f32, 6f32, 7f32, 8f32],
+            [9f32, 10f32, 11f32, 12f32],
+            [13f32, 14f32, 15f32, 16f32],
+        ];
+        let mint_m = mint::ColumnMatrix4::from(Matrix4x4::from_cols_array(&elements));
+        assert_eq!(mint::Vector4::from([13.0, 14.0, 15.0, 16.0]), mint_m.w);
+        assert_eq!(elements, Matrix4x4::from(mint_m).to_cols_array());
+    }
 }
@@ -0,0 +1,201 @@
+//! Structural comparisons of the JSON representation of parsed files.
+use serde_json::Value;
+use std::fmt::Display;
+
+/// A single difference between two JSON values.
+/// The `path` uses `.` for object fields and `[i]` for array elements like `V17.entries[0].material_label`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Difference {
+    /// The value is only present in the second value.
+    Added { path: String, value: Value },
+    /// The value is only present in the first value.
+    Removed { path: String, value: Value },
+    /// The value is present in both values but not equal.
+    Changed {
+        path: String,
+        old: Value,
+        new: Value,
+    },
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Difference::Added { path, value } => write!(f, "+ {}: {}", display_path(path), value),
+            Difference::Removed { path, value } => {
+                write!(f, "- {}: {}", display_path(path), value)
+            }
+            Difference::Changed { path, old, new } => {
+                write!(f, "~ {}: {} -> {}", display_path(path), old, new)
+            }
+        }
+    }
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() {
+        "(root)"
+    } else {
+        path
+    }
+}
+
+/// Recursively compares `old` and `new` and returns the differences in field and element order.
+/// Objects are compared by key, and arrays are compared by index.
+pub fn diff_values(old: &Value, new: &Value) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    diff_values_recursive(old, new, String::new(), &mut differences);
+    differences
+}
+
+fn diff_values_recursive(
+    old: &Value,
+    new: &Value,
+    path: String,
+    differences: &mut Vec<Difference>,
+) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let field_path = field_path(&path, key);
+                match new.get(key) {
+                    Some(new_value) => {
+                        diff_values_recursive(old_value, new_value, field_path, differences)
+                    }
+                    None => differences.push(Difference::Removed {
+                        path: field_path,
+                        value: old_value.clone(),
+                    }),
+                }
+            }
+            for (key, new_value) in new.iter().filter(|(k, _)| !old.contains_key(*k)) {
+                differences.push(Difference::Added {
+                    path: field_path(&path, key),
+                    value: new_value.clone(),
+                });
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for (i, old_value) in old.iter().enumerate() {
+                let element_path = format!("{path}[{i}]");
+                match new.get(i) {
+                    Some(new_value) => {
+                        diff_values_recursive(old_value, new_value, element_path, differences)
+                    }
+                    None => differences.push(Difference::Removed {
+                        path: element_path,
+                        value: old_value.clone(),
+                    }),
+                }
+            }
+            for (i, new_value) in new.iter().enumerate().skip(old.len()) {
+                differences.push(Difference::Added {
+                    path: format!("{path}[{i}]"),
+                    value: new_value.clone(),
+                });
+            }
+        }
+        _ => {
+            if old != new {
+                differences.push(Difference::Changed {
+                    path,
+                    old: old.clone(),
+                    new: new.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn field_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use ssbh_lib::{
+        formats::modl::{Modl, ModlEntry},
+        RelPtr64,
+    };
+
+    fn modl(material_labels: &[&str]) -> Modl {
+        Modl::V17 {
+            model_name: "model".into(),
+            skeleton_file_name: "model.nusktb".into(),
+            material_file_names: vec!["model.numatb".into()].into(),
+            animation_file_name: RelPtr64::null(),
+            mesh_file_name: "model.numshb".into(),
+            entries: material_labels
+                .iter()
+                .map(|label| ModlEntry {
+                    mesh_object_name: "object".into(),
+                    mesh_object_subindex: 0,
+                    material_label: (*label).into(),
+                })
+                .collect::<Vec<_>>()
+                .into(),
+        }
+    }
+
+    #[test]
+    fn diff_modl_material_label() {
+        let old = serde_json::to_value(modl(&["a", "b"])).unwrap();
+        let new = serde_json::to_value(modl(&["a", "c"])).unwrap();
+
+        let differences = diff_values(&old, &new);
+        assert_eq!(
+            vec![Difference::Changed {
+                path: "V17.entries[1].material_label".to_string(),
+                old: json!("b"),
+                new: json!("c"),
+            }],
+            differences
+        );
+        assert_eq!(
+            "~ V17.entries[1].material_label: \"b\" -> \"c\"",
+            differences[0].to_string()
+        );
+    }
+
+    #[test]
+    fn diff_equal_values() {
+        let value = serde_json::to_value(modl(&["a"])).unwrap();
+        assert!(diff_values(&value, &value).is_empty());
+    }
+
+    #[test]
+    fn diff_added_removed() {
+        let old = json!({"a": 1, "b": [1, 2]});
+        let new = json!({"b": [1], "c": true});
+
+        assert_eq!(
+            vec![
+                Difference::Removed {
+                    path: "a".to_string(),
+                    value: json!(1),
+                },
+                Difference::Removed {
+                    path: "b[1]".to_string(),
+                    value: json!(2),
+                },
+                Difference::Added {
+                    path: "c".to_string(),
+                    value: json!(true),
+                },
+            ],
+            diff_values(&old, &new)
+        );
+    }
+
+    #[test]
+    fn diff_root_changed() {
+        let differences = diff_values(&json!(1), &json!("a"));
+        assert_eq!("~ (root): 1 -> \"a\"", differences[0].to_string());
+    }
+}
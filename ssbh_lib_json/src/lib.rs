@@ -1,3 +1,6 @@
+pub mod diff;
+pub mod precision;
+
 #[cfg(test)]
 mod tests {
     use ssbh_lib::{CString, Ptr64, RelPtr64, SsbhArray, SsbhByteBuffer, SsbhString, SsbhString8};
@@ -1,12 +1,18 @@
 use serde::Serialize;
 use ssbh_lib::prelude::*;
+use ssbh_lib_json::diff::diff_values;
+use ssbh_lib_json::precision::to_string_pretty;
 use std::env;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
-fn read_data_write_json<T, E, P, F>(input_path: P, output_path: Option<&String>, read_t: F)
-where
+fn read_data_write_json<T, E, P, F>(
+    input_path: P,
+    output_path: Option<&String>,
+    float_precision: Option<usize>,
+    read_t: F,
+) where
     T: Serialize,
     P: AsRef<Path> + ToString,
     F: Fn(P) -> Result<T, E>,
@@ -21,14 +27,18 @@ where
     match read_t(input_path) {
         Ok(adjb) => {
             eprintln!("Parse: {:?}", parse_start_time.elapsed());
-            write_json(json_output_path, adjb);
+            write_json(json_output_path, adjb, float_precision);
         }
         Err(error) => eprintln!("{:?}", error),
     };
 }
 
-fn write_json<T: Sized + Serialize, P: AsRef<Path>>(output_path: P, object: T) {
-    let json = serde_json::to_string_pretty(&object).unwrap();
+fn write_json<T: Sized + Serialize, P: AsRef<Path>>(
+    output_path: P,
+    object: T,
+    float_precision: Option<usize>,
+) {
+    let json = to_string_pretty(&object, float_precision).unwrap();
 
     let mut output_file = std::fs::File::create(output_path).expect("unable to create file");
     output_file
@@ -77,12 +87,75 @@ fn write_data<T, P: AsRef<Path>, F: Fn(&T, P) -> std::io::Result<()>>(
     eprintln!("Export: {:?}", export_time.elapsed());
 }
 
+fn read_json_value(input: &str) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let extension = Path::new(input)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default();
+
+    let value = match extension {
+        "adjb" => serde_json::to_value(Adj::from_file(input)?)?,
+        "numshexb" => serde_json::to_value(MeshEx::from_file(input)?)?,
+        // Assume anything else is an SSBH file.
+        _ => serde_json::to_value(SsbhFile::from_file(input)?)?,
+    };
+    Ok(value)
+}
+
+fn diff_files(input1: &str, input2: &str) {
+    let (old, new) = match (read_json_value(input1), read_json_value(input2)) {
+        (Ok(old), Ok(new)) => (old, new),
+        (Err(error), _) | (_, Err(error)) => {
+            eprintln!("{:?}", error);
+            return;
+        }
+    };
+
+    let differences = diff_values(&old, &new);
+    if differences.is_empty() {
+        println!("No differences found.");
+    }
+    for difference in differences {
+        println!("{}", difference);
+    }
+}
+
+fn print_usage() {
+    eprintln!("Usage:");
+    eprintln!("\tssbh_lib_json <file>");
+    eprintln!("\tssbh_lib_json <file> <json output>");
+    eprintln!("\tssbh_lib_json <file> [json output] --float-precision <significant digits>");
+    eprintln!("\tssbh_lib_json diff <file1> <file2>");
+}
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    // Floats are only rounded when converting to JSON if the precision is specified.
+    let float_precision = match args.iter().position(|a| a == "--float-precision") {
+        Some(i) => match args.get(i + 1).and_then(|n| n.parse().ok()) {
+            Some(digits) => {
+                args.drain(i..i + 2);
+                Some(digits)
+            }
+            None => {
+                print_usage();
+                return;
+            }
+        },
+        None => None,
+    };
+
     if args.len() < 2 {
-        eprintln!("Usage:");
-        eprintln!("\tssbh_lib_json <file>");
-        eprintln!("\tssbh_lib_json <file> <json output>");
+        print_usage();
+        return;
+    }
+
+    if args[1] == "diff" {
+        match (args.get(2), args.get(3)) {
+            (Some(input1), Some(input2)) => diff_files(input1, input2),
+            _ => print_usage(),
+        }
         return;
     }
 
@@ -91,10 +164,10 @@ fn main() {
 
     // Try parsing one of the supported formats.
     match input_path.extension().unwrap().to_str().unwrap() {
-        "adjb" => read_data_write_json(input, args.get(2), Adj::from_file),
-        "numshexb" => read_data_write_json(input, args.get(2), MeshEx::from_file),
+        "adjb" => read_data_write_json(input, args.get(2), float_precision, Adj::from_file),
+        "numshexb" => read_data_write_json(input, args.get(2), float_precision, MeshEx::from_file),
         "json" => read_json_write_data(input_path, args.get(2)),
         // Assume anything else is an SSBH file.
-        _ => read_data_write_json(input, args.get(2), SsbhFile::from_file),
+        _ => read_data_write_json(input, args.get(2), float_precision, SsbhFile::from_file),
     };
 }
@@ -0,0 +1,427 @@
+//! Rounding of floating point values in the JSON representation of parsed files.
+use serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Recursively rounds all floating point numbers in `value` to `significant_digits` significant digits.
+/// Integers are not modified.
+pub fn round_floats(value: &mut Value, significant_digits: usize) {
+    match value {
+        Value::Number(n) if n.is_f64() => {
+            if let Some(rounded) = n.as_f64().and_then(|f| {
+                serde_json::Number::from_f64(round_significant(f, significant_digits))
+            }) {
+                *n = rounded;
+            }
+        }
+        Value::Array(values) => {
+            for v in values {
+                round_floats(v, significant_digits);
+            }
+        }
+        Value::Object(values) => {
+            for v in values.values_mut() {
+                round_floats(v, significant_digits);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn round_significant(f: f64, significant_digits: usize) -> f64 {
+    // Scientific notation rounds based on significant digits rather than decimal places.
+    format!("{:.*e}", significant_digits.saturating_sub(1), f)
+        .parse()
+        .unwrap_or(f)
+}
+
+/// Serializes `object` as pretty printed JSON.
+/// Floating point values are rounded to `float_precision` significant digits if present.
+/// This is only intended for display since rounding may not preserve the original values.
+///
+/// Values are rounded while serializing, so fields are written in the same order as the unrounded output.
+pub fn to_string_pretty<T: Serialize>(
+    object: &T,
+    float_precision: Option<usize>,
+) -> serde_json::Result<String> {
+    match float_precision {
+        Some(digits) => serde_json::to_string_pretty(&Rounded {
+            value: object,
+            digits,
+        }),
+        None => serde_json::to_string_pretty(object),
+    }
+}
+
+// Serializes the value with all floating point values rounded to digits significant digits.
+struct Rounded<'a, T: ?Sized> {
+    value: &'a T,
+    digits: usize,
+}
+
+impl<'a, T: Serialize + ?Sized> Serialize for Rounded<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(RoundingSerializer {
+            inner: serializer,
+            digits: self.digits,
+        })
+    }
+}
+
+// Forwards everything to the inner serializer except for rounding floats.
+struct RoundingSerializer<S> {
+    inner: S,
+    digits: usize,
+}
+
+impl<S> RoundingSerializer<S> {
+    fn rounded<'a, T: ?Sized>(&self, value: &'a T) -> Rounded<'a, T> {
+        Rounded {
+            value,
+            digits: self.digits,
+        }
+    }
+}
+
+macro_rules! forward_serialize {
+    ($($method:ident($ty:ty)),*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for RoundingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = RoundingCompound<S::SerializeSeq>;
+    type SerializeTuple = RoundingCompound<S::SerializeTuple>;
+    type SerializeTupleStruct = RoundingCompound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = RoundingCompound<S::SerializeTupleVariant>;
+    type SerializeMap = RoundingCompound<S::SerializeMap>;
+    type SerializeStruct = RoundingCompound<S::SerializeStruct>;
+    type SerializeStructVariant = RoundingCompound<S::SerializeStructVariant>;
+
+    forward_serialize!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str)
+    );
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        let digits = self.digits;
+        self.inner
+            .serialize_f32(round_significant(v as f64, digits) as f32)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        let digits = self.digits;
+        self.inner.serialize_f64(round_significant(v, digits))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        let value = self.rounded(value);
+        self.inner.serialize_some(&value)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let value = self.rounded(value);
+        self.inner.serialize_newtype_struct(name, &value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let value = self.rounded(value);
+        self.inner
+            .serialize_newtype_variant(name, variant_index, variant, &value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let inner = self.inner.serialize_seq(len)?;
+        Ok(RoundingCompound {
+            inner,
+            digits: self.digits,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        let inner = self.inner.serialize_tuple(len)?;
+        Ok(RoundingCompound {
+            inner,
+            digits: self.digits,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        let inner = self.inner.serialize_tuple_struct(name, len)?;
+        Ok(RoundingCompound {
+            inner,
+            digits: self.digits,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        let inner = self
+            .inner
+            .serialize_tuple_variant(name, variant_index, variant, len)?;
+        Ok(RoundingCompound {
+            inner,
+            digits: self.digits,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let inner = self.inner.serialize_map(len)?;
+        Ok(RoundingCompound {
+            inner,
+            digits: self.digits,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        let inner = self.inner.serialize_struct(name, len)?;
+        Ok(RoundingCompound {
+            inner,
+            digits: self.digits,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        let inner = self
+            .inner
+            .serialize_struct_variant(name, variant_index, variant, len)?;
+        Ok(RoundingCompound {
+            inner,
+            digits: self.digits,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+// Rounds the elements or fields of sequences, maps, and structs.
+struct RoundingCompound<C> {
+    inner: C,
+    digits: usize,
+}
+
+impl<C> RoundingCompound<C> {
+    fn rounded<'a, T: ?Sized>(&self, value: &'a T) -> Rounded<'a, T> {
+        Rounded {
+            value,
+            digits: self.digits,
+        }
+    }
+}
+
+macro_rules! rounding_compound_impl {
+    ($($trait:ident::$method:ident),*) => {
+        $(
+            impl<C: $trait> $trait for RoundingCompound<C> {
+                type Ok = C::Ok;
+                type Error = C::Error;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+                    let value = self.rounded(value);
+                    self.inner.$method(&value)
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+rounding_compound_impl!(
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field
+);
+
+impl<C: SerializeMap> SerializeMap for RoundingCompound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        let key = self.rounded(key);
+        self.inner.serialize_key(&key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let value = self.rounded(value);
+        self.inner.serialize_value(&value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+macro_rules! rounding_struct_impl {
+    ($($trait:ident),*) => {
+        $(
+            impl<C: $trait> $trait for RoundingCompound<C> {
+                type Ok = C::Ok;
+                type Error = C::Error;
+
+                fn serialize_field<T: Serialize + ?Sized>(
+                    &mut self,
+                    key: &'static str,
+                    value: &T,
+                ) -> Result<(), Self::Error> {
+                    let value = self.rounded(value);
+                    self.inner.serialize_field(key, &value)
+                }
+
+                fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+                    self.inner.skip_field(key)
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+rounding_struct_impl!(SerializeStruct, SerializeStructVariant);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use ssbh_lib::{Color4f, Vector3, Vector4};
+
+    #[test]
+    fn round_vector3_three_digits() {
+        let mut value = serde_json::to_value(Vector3::new(0.740741, -1.0, 12345.678)).unwrap();
+        round_floats(&mut value, 3);
+        assert_eq!(json!({"x": 0.741, "y": -1.0, "z": 12300.0}), value);
+        assert_eq!(
+            r#"{"x":0.741,"y":-1.0,"z":12300.0}"#,
+            serde_json::to_string(&value).unwrap()
+        );
+    }
+
+    #[test]
+    fn round_floats_integers_unchanged() {
+        let mut value = json!({"a": [12345, 0.123456], "b": "1.23456"});
+        round_floats(&mut value, 2);
+        assert_eq!(json!({"a": [12345, 0.12], "b": "1.23456"}), value);
+    }
+
+    #[test]
+    fn to_string_pretty_rounded_values() {
+        let vector = Vector3::new(0.740741, -1.0, 12345.678);
+        assert_eq!(
+            "{\n  \"x\": 0.741,\n  \"y\": -1.0,\n  \"z\": 12300.0\n}",
+            to_string_pretty(&vector, Some(3)).unwrap()
+        );
+    }
+
+    #[test]
+    fn to_string_pretty_preserves_field_order() {
+        // The w and a fields aren't in alphabetical order.
+        let value = (
+            Vector4::new(0.740741, 1.0, 2.0, 3.33333),
+            vec![Some(Color4f {
+                r: 0.1234,
+                g: 0.5,
+                b: 0.0,
+                a: 1.0,
+            })],
+        );
+        let expected = (
+            Vector4::new(0.741, 1.0, 2.0, 3.33),
+            vec![Some(Color4f {
+                r: 0.123,
+                g: 0.5,
+                b: 0.0,
+                a: 1.0,
+            })],
+        );
+        assert_eq!(
+            serde_json::to_string_pretty(&expected).unwrap(),
+            to_string_pretty(&value, Some(3)).unwrap()
+        );
+    }
+
+    #[test]
+    fn to_string_pretty_no_precision() {
+        let vector = Vector3::new(0.740741, 1.0, 2.0);
+        assert_eq!(
+            serde_json::to_string_pretty(&vector).unwrap(),
+            to_string_pretty(&vector, None).unwrap()
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    cell::Cell,
     io::{Seek, Write},
     num::{
         NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
@@ -8,6 +9,36 @@ use std::{
 
 pub use ssbh_write_derive::SsbhWrite;
 
+/// Options for customizing the output of [SsbhWrite::ssbh_write_with_options].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SsbhWriteOptions {
+    /// The byte used for the padding and alignment generated by
+    /// `#[ssbhwrite(pad_after = ...)]` and `#[ssbhwrite(align_after = ...)]`.
+    /// SSBH files use `0`, but values like `0xFF` make padding easier to spot in a hex editor.
+    pub pad_byte: u8,
+}
+
+thread_local! {
+    static PAD_BYTE: Cell<u8> = const { Cell::new(0) };
+}
+
+// Restores the previous pad byte even if writing panics.
+struct PadByteGuard(u8);
+
+impl Drop for PadByteGuard {
+    fn drop(&mut self) {
+        PAD_BYTE.with(|b| b.set(self.0));
+    }
+}
+
+/// Writes `count` many padding bytes using the pad byte for the current write.
+/// This is used by the code generated by the [SsbhWrite] derive.
+#[doc(hidden)]
+pub fn write_padding<W: Write>(writer: &mut W, count: usize) -> std::io::Result<()> {
+    let pad_byte = PAD_BYTE.with(|b| b.get());
+    writer.write_all(&vec![pad_byte; count])
+}
+
 /// A trait for writing types that are part of SSBH formats.
 pub trait SsbhWrite: Sized {
     /// Writes the byte representation of `self` to `writer`.
@@ -56,6 +87,66 @@ pub trait SsbhWrite: Sized {
         Ok(())
     }
 
+    /// Writes the byte representation of `self` to `writer` and then flushes `writer`.
+    /// This ensures buffered writers like [std::io::BufWriter] write all the bytes
+    /// without needing to be flushed or dropped by the caller.
+    /**
+    ```rust
+    use ssbh_write::SsbhWrite;
+    use std::io::{BufWriter, Cursor};
+
+    let mut writer = BufWriter::new(Cursor::new(Vec::new()));
+    1u32.write_and_flush(&mut writer).unwrap();
+    assert_eq!(&[1, 0, 0, 0], &writer.get_ref().get_ref()[..]);
+    ```
+     */
+    fn write_and_flush<W: std::io::Write + std::io::Seek>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        self.write(writer)?;
+        writer.flush()
+    }
+
+    /// Writes the byte representation of `self` to `writer` using the given `options`.
+    /// The options only apply to the current thread for the duration of the write.
+    /**
+    ```rust
+    use ssbh_write::{SsbhWrite, SsbhWriteOptions};
+
+    #[derive(SsbhWrite)]
+    struct MyStruct {
+        #[ssbhwrite(pad_after = 2)]
+        x: u8,
+        y: u8,
+    }
+
+    let mut writer = std::io::Cursor::new(Vec::new());
+    let options = SsbhWriteOptions { pad_byte: 0xFF };
+    MyStruct { x: 1, y: 2 }.ssbh_write_with_options(&mut writer, &options).unwrap();
+    assert_eq!(vec![1, 0xFF, 0xFF, 2], writer.into_inner());
+    ```
+     */
+    fn ssbh_write_with_options<W: std::io::Write + std::io::Seek>(
+        &self,
+        writer: &mut W,
+        options: &SsbhWriteOptions,
+    ) -> std::io::Result<()> {
+        let _guard = PadByteGuard(PAD_BYTE.with(|b| b.replace(options.pad_byte)));
+        self.write(writer)
+    }
+
+    /// Writes the byte representation of `self` to a `writer` that does not support seeking.
+    ///
+    /// The data is first written to an in memory buffer and then copied to `writer`.
+    /// This requires storing the entire output in memory.
+    /// Prefer [write](crate::SsbhWrite::write) for writers that already implement [std::io::Seek].
+    fn write_buffered<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        self.write(&mut buffer)?;
+        writer.write_all(buffer.get_ref())
+    }
+
     /// The offset in bytes between successive elements in an array of this type.
     /// This should include any alignment or padding.
     fn size_in_bytes(&self) -> u64 {
@@ -100,6 +191,11 @@ impl<T: SsbhWrite, const N: usize> SsbhWrite for [T; N] {
     fn size_in_bytes(&self) -> u64 {
         self.as_slice().size_in_bytes()
     }
+
+    fn alignment_in_bytes() -> u64 {
+        // Use the underlying type's alignment.
+        T::alignment_in_bytes()
+    }
 }
 
 impl<T: SsbhWrite> SsbhWrite for &[T] {
@@ -123,11 +219,8 @@ impl<T: SsbhWrite> SsbhWrite for &[T] {
     }
 
     fn size_in_bytes(&self) -> u64 {
-        // TODO: This won't work for Vec<Option<T>> since only the first element is checked.
-        match self.first() {
-            Some(element) => self.len() as u64 * element.size_in_bytes(),
-            None => 0,
-        }
+        // Elements like Option<T> don't always have the same size.
+        self.iter().map(SsbhWrite::size_in_bytes).sum()
     }
 
     fn alignment_in_bytes() -> u64 {
@@ -277,11 +370,7 @@ impl<T: SsbhWrite> SsbhWrite for Vec<T> {
     }
 
     fn size_in_bytes(&self) -> u64 {
-        // Assume each element has the same size.
-        match self.first() {
-            Some(first) => self.len() as u64 * first.size_in_bytes(),
-            None => 0,
-        }
+        self.as_slice().size_in_bytes()
     }
 
     fn alignment_in_bytes() -> u64 {
@@ -290,12 +379,140 @@ impl<T: SsbhWrite> SsbhWrite for Vec<T> {
     }
 }
 
+/// Strings are written as UTF-8 bytes followed by a single null terminator.
+/// Any bytes after the first null byte are not written.
+/// Pointers to strings are 4-byte aligned to match `CString<4>` and `SsbhString` in ssbh_lib.
+/// The size includes the null terminator.
+impl SsbhWrite for &str {
+    fn ssbh_write<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        _data_ptr: &mut u64,
+    ) -> std::io::Result<()> {
+        writer.write_all(null_terminated_bytes(self))?;
+        writer.write_all(&[0u8])
+    }
+
+    fn size_in_bytes(&self) -> u64 {
+        null_terminated_bytes(self).len() as u64 + 1
+    }
+
+    fn alignment_in_bytes() -> u64 {
+        4
+    }
+}
+
+/// See the implementation for `&str`.
+impl SsbhWrite for String {
+    fn ssbh_write<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        data_ptr: &mut u64,
+    ) -> std::io::Result<()> {
+        self.as_str().ssbh_write(writer, data_ptr)
+    }
+
+    fn size_in_bytes(&self) -> u64 {
+        self.as_str().size_in_bytes()
+    }
+
+    fn alignment_in_bytes() -> u64 {
+        <&str>::alignment_in_bytes()
+    }
+}
+
+fn null_terminated_bytes(text: &str) -> &[u8] {
+    let bytes = text.as_bytes();
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    &bytes[..end]
+}
+
 // TODO: Implement tuples.
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::io::Cursor;
 
+    // Only valid for types that don't write any data after the value itself.
+    #[track_caller]
+    fn assert_size_matches<T: SsbhWrite>(value: &T) {
+        let mut writer = Cursor::new(Vec::new());
+        value.write(&mut writer).unwrap();
+        assert_eq!(writer.get_ref().len() as u64, value.size_in_bytes());
+    }
+
+    #[test]
+    fn size_in_bytes_matches_written_bytes() {
+        assert_size_matches(&());
+        assert_size_matches(&1u8);
+        assert_size_matches(&1u16);
+        assert_size_matches(&1u32);
+        assert_size_matches(&1u64);
+        assert_size_matches(&1u128);
+        assert_size_matches(&-1i8);
+        assert_size_matches(&-1i16);
+        assert_size_matches(&-1i32);
+        assert_size_matches(&-1i64);
+        assert_size_matches(&-1i128);
+        assert_size_matches(&1.0f32);
+        assert_size_matches(&1.0f64);
+        assert_size_matches(&NonZeroU8::new(1).unwrap());
+        assert_size_matches(&NonZeroI64::new(-1).unwrap());
+
+        assert_size_matches(&Some(1u32));
+        assert_size_matches(&Option::<u32>::None);
+        assert_size_matches(&Some(Some(1u16)));
+
+        assert_size_matches(&Vec::<u32>::new());
+        assert_size_matches(&vec![1u8, 2u8, 3u8]);
+        assert_size_matches(&vec![None, Some(1u8), Some(2u8)]);
+        assert_size_matches(&vec![Some(1u32), None, Some(2u32)]);
+        assert_size_matches(&vec![vec![1u16], Vec::new(), vec![2u16, 3u16]]);
+        assert_size_matches(&[Some(1u64), None]);
+        assert_size_matches(&[[1u8, 2u8], [3u8, 4u8]]);
+        assert_size_matches(&[(), ()]);
+
+        assert_size_matches(&"All");
+        assert_size_matches(&"");
+        assert_size_matches(&"ab\0cd");
+        assert_size_matches(&String::new());
+        assert_size_matches(&"All".to_string());
+    }
+
+    #[test]
+    fn write_str() {
+        let mut writer = Cursor::new(Vec::new());
+        "All".write(&mut writer).unwrap();
+        assert_eq!(b"All\0", &writer.get_ref()[..]);
+        assert_eq!(4, "All".size_in_bytes());
+        assert_eq!(4, <&str>::alignment_in_bytes());
+    }
+
+    #[test]
+    fn write_string_interior_null() {
+        let mut writer = Cursor::new(Vec::new());
+        "ab\0cd".to_string().write(&mut writer).unwrap();
+        assert_eq!(b"ab\0", &writer.get_ref()[..]);
+    }
+
+    #[test]
+    fn write_str_empty() {
+        let mut writer = Cursor::new(Vec::new());
+        "".write(&mut writer).unwrap();
+        assert_eq!(b"\0", &writer.get_ref()[..]);
+    }
+
+    #[test]
+    fn write_and_flush_buf_writer() {
+        let mut writer = std::io::BufWriter::new(Cursor::new(Vec::new()));
+        vec![1u8, 2u8, 3u8].write(&mut writer).unwrap();
+        assert!(writer.get_ref().get_ref().is_empty());
+
+        let mut writer = std::io::BufWriter::new(Cursor::new(Vec::new()));
+        vec![1u8, 2u8, 3u8].write_and_flush(&mut writer).unwrap();
+        assert_eq!(&[1u8, 2u8, 3u8], &writer.get_ref().get_ref()[..]);
+    }
+
     #[test]
     fn write_vec_empty() {
         let mut writer = Cursor::new(Vec::new());
@@ -349,6 +566,69 @@ mod tests {
         assert_eq!(1, value.size_in_bytes());
     }
 
+    #[test]
+    fn write_buffered_non_seek() {
+        // Only implement Write and not Seek.
+        struct WriteOnly(Vec<u8>);
+
+        impl std::io::Write for WriteOnly {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                std::io::Write::write(&mut self.0, buf)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer = WriteOnly(Vec::new());
+        let value = vec![1u32, 2u32];
+        value.write_buffered(&mut writer).unwrap();
+
+        assert_eq!(vec![1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8], writer.0);
+    }
+
+    #[test]
+    fn write_vec_option() {
+        let mut writer = Cursor::new(Vec::new());
+        let mut data_ptr = 0;
+
+        let value = vec![None, Some(1u8), Some(2u8)];
+        value.ssbh_write(&mut writer, &mut data_ptr).unwrap();
+
+        assert_eq!(vec![1u8, 2u8], writer.into_inner());
+        assert_eq!(2, data_ptr);
+        assert_eq!(2, value.size_in_bytes());
+    }
+
+    #[test]
+    fn write_array_option() {
+        let mut writer = Cursor::new(Vec::new());
+        let mut data_ptr = 0;
+
+        let value = [Some(1u32), None, Some(2u32)];
+        value.ssbh_write(&mut writer, &mut data_ptr).unwrap();
+
+        assert_eq!(vec![1u8, 0, 0, 0, 2, 0, 0, 0], writer.into_inner());
+        assert_eq!(8, data_ptr);
+        assert_eq!(8, value.size_in_bytes());
+        assert_eq!(4, <[Option<u32>; 3]>::alignment_in_bytes());
+    }
+
+    #[test]
+    fn write_nested_array() {
+        let mut writer = Cursor::new(Vec::new());
+        let mut data_ptr = 0;
+
+        let value = [[1u8, 2u8], [3u8, 4u8]];
+        value.ssbh_write(&mut writer, &mut data_ptr).unwrap();
+
+        assert_eq!(vec![1u8, 2, 3, 4], writer.into_inner());
+        assert_eq!(4, data_ptr);
+        assert_eq!(4, value.size_in_bytes());
+        assert_eq!(1, <[[u8; 2]; 2]>::alignment_in_bytes());
+    }
+
     #[test]
     fn write_option_none() {
         let mut writer = Cursor::new(Vec::new());
@@ -0,0 +1,45 @@
+use std::io::Cursor;
+
+use binrw::{BinRead, BinReaderExt};
+use ssbh_write::SsbhWrite;
+
+#[derive(Debug, PartialEq, BinRead, SsbhWrite)]
+#[ssbhwrite(discriminant(u16))]
+#[repr(u16)]
+enum TestEnum {
+    #[br(magic = 1u16)]
+    A(u8) = 1,
+    #[br(magic = 2u16)]
+    B { x: u32, y: u8 } = 2,
+}
+
+#[test]
+fn discriminant_named_variant() {
+    let value = TestEnum::B { x: 3, y: 4 };
+
+    let mut writer = Cursor::new(Vec::new());
+    value.write(&mut writer).unwrap();
+    assert_eq!(
+        vec![2u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8],
+        writer.get_ref().clone()
+    );
+
+    // The discriminant should be included in the computed size.
+    assert_eq!(7, value.size_in_bytes());
+
+    writer.set_position(0);
+    assert_eq!(value, writer.read_le::<TestEnum>().unwrap());
+}
+
+#[test]
+fn discriminant_unnamed_variant() {
+    let value = TestEnum::A(5);
+
+    let mut writer = Cursor::new(Vec::new());
+    value.write(&mut writer).unwrap();
+    assert_eq!(vec![1u8, 0u8, 5u8], writer.get_ref().clone());
+    assert_eq!(3, value.size_in_bytes());
+
+    writer.set_position(0);
+    assert_eq!(value, writer.read_le::<TestEnum>().unwrap());
+}
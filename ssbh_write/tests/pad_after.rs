@@ -137,3 +137,42 @@ fn pad_struct_field() {
     assert_eq!(vec![1u8, 2u8, 0u8, 0u8, 3u8], writer.into_inner());
     assert_eq!(5, TestStruct { x: 1, y: 2, z: 3 }.size_in_bytes());
 }
+
+#[test]
+fn pad_struct_last_field() {
+    #[derive(Debug, SsbhWrite)]
+    struct TestStruct {
+        x: u8,
+        #[ssbhwrite(pad_after = 3)]
+        y: u8,
+    }
+
+    let mut writer = Cursor::new(Vec::new());
+    let mut data_ptr = 0;
+
+    TestStruct { x: 1, y: 2 }
+        .ssbh_write(&mut writer, &mut data_ptr)
+        .unwrap();
+
+    assert_eq!(vec![1u8, 2u8, 0u8, 0u8, 0u8], writer.into_inner());
+    assert_eq!(5, TestStruct { x: 1, y: 2 }.size_in_bytes());
+    assert_eq!(5, data_ptr);
+}
+
+#[test]
+fn pad_tuple_struct_field() {
+    #[derive(Debug, SsbhWrite)]
+    #[ssbhwrite(alignment = 1)]
+    struct TestStruct(#[ssbhwrite(pad_after = 2)] u8, u16);
+
+    let mut writer = Cursor::new(Vec::new());
+    let mut data_ptr = 0;
+
+    TestStruct(1, 2)
+        .ssbh_write(&mut writer, &mut data_ptr)
+        .unwrap();
+
+    assert_eq!(vec![1u8, 0u8, 0u8, 2u8, 0u8], writer.into_inner());
+    assert_eq!(5, TestStruct(1, 2).size_in_bytes());
+    assert_eq!(1, TestStruct::alignment_in_bytes());
+}
@@ -0,0 +1,72 @@
+use std::io::Cursor;
+
+use ssbh_write::{SsbhWrite, SsbhWriteOptions};
+
+#[test]
+fn pad_byte_pad_after() {
+    #[derive(Debug, SsbhWrite)]
+    struct TestStruct {
+        x: u8,
+        #[ssbhwrite(pad_after = 2)]
+        y: u8,
+        z: u8,
+    }
+
+    let mut writer = Cursor::new(Vec::new());
+    TestStruct { x: 1, y: 2, z: 3 }
+        .ssbh_write_with_options(&mut writer, &SsbhWriteOptions { pad_byte: 0xFF })
+        .unwrap();
+
+    assert_eq!(vec![1u8, 2u8, 0xFFu8, 0xFFu8, 3u8], writer.into_inner());
+}
+
+#[test]
+fn pad_byte_align_after() {
+    #[derive(Debug, SsbhWrite)]
+    #[ssbhwrite(align_after = 4)]
+    struct TestStruct {
+        x: u8,
+    }
+
+    let mut writer = Cursor::new(Vec::new());
+    TestStruct { x: 1 }
+        .ssbh_write_with_options(&mut writer, &SsbhWriteOptions { pad_byte: 0xAB })
+        .unwrap();
+
+    assert_eq!(vec![1u8, 0xABu8, 0xABu8, 0xABu8], writer.into_inner());
+}
+
+#[test]
+fn pad_byte_restored_after_write() {
+    #[derive(Debug, SsbhWrite)]
+    #[ssbhwrite(pad_after = 1)]
+    struct TestStruct {
+        x: u8,
+    }
+
+    let mut writer = Cursor::new(Vec::new());
+    TestStruct { x: 1 }
+        .ssbh_write_with_options(&mut writer, &SsbhWriteOptions { pad_byte: 0xFF })
+        .unwrap();
+    assert_eq!(vec![1u8, 0xFFu8], writer.into_inner());
+
+    let mut writer = Cursor::new(Vec::new());
+    TestStruct { x: 1 }.write(&mut writer).unwrap();
+    assert_eq!(vec![1u8, 0u8], writer.into_inner());
+}
+
+#[test]
+fn default_options() {
+    #[derive(Debug, SsbhWrite)]
+    #[ssbhwrite(pad_after = 2)]
+    struct TestStruct {
+        x: u8,
+    }
+
+    let mut writer = Cursor::new(Vec::new());
+    TestStruct { x: 1 }
+        .ssbh_write_with_options(&mut writer, &SsbhWriteOptions::default())
+        .unwrap();
+
+    assert_eq!(vec![1u8, 0u8, 0u8], writer.into_inner());
+}
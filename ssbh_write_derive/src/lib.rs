@@ -20,6 +20,7 @@ struct WriteOptions {
     align_after: Option<usize>,
     alignment: Option<usize>,
     repr: Option<Ident>,
+    discriminant: Option<Ident>,
     magic: Option<LitByteStr>,
 }
 
@@ -27,8 +28,13 @@ struct TypeRepr {
     value: Ident,
 }
 
+struct TypeDiscriminant {
+    value: Ident,
+}
+
 mod kw {
     syn::custom_keyword!(repr);
+    syn::custom_keyword!(discriminant);
 }
 
 impl Parse for TypeRepr {
@@ -42,6 +48,24 @@ impl Parse for TypeRepr {
     }
 }
 
+impl Parse for TypeDiscriminant {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let _ident: kw::discriminant = input.parse()?;
+        let content;
+        parenthesized!(content in input);
+        let value = content.parse()?;
+
+        Ok(Self { value })
+    }
+}
+
+fn get_discriminant(attr: &Attribute) -> Option<Ident> {
+    match attr.parse_args::<TypeDiscriminant>() {
+        Ok(discriminant) => Some(discriminant.value),
+        Err(_) => None,
+    }
+}
+
 fn get_repr(attr: &Attribute) -> Option<Ident> {
     match attr.parse_args::<TypeRepr>() {
         Ok(type_repr) => Some(type_repr.value),
@@ -49,11 +73,11 @@ fn get_repr(attr: &Attribute) -> Option<Ident> {
     }
 }
 
-fn get_usize_arg(m: &MetaNameValue) -> Option<usize> {
+fn get_usize_arg(m: &MetaNameValue) -> usize {
     if let syn::Lit::Int(value) = &m.lit {
-        Some(value.base10_parse().unwrap())
+        value.base10_parse().unwrap()
     } else {
-        None
+        panic!("Expected an integer literal")
     }
 }
 
@@ -128,12 +152,21 @@ fn write_data_calculate_size_enum(
             let write_pad_after = write_pad_after(&variant_options);
             let write_align_after = write_aligned_after(&variant_options);
 
+            let write_discriminant = match &write_options.discriminant {
+                Some(ty) => match variant_discriminant(variant) {
+                    Ok(value) => quote! { (#value as #ty).ssbh_write(writer, data_ptr)?; },
+                    Err(e) => e.to_compile_error(),
+                },
+                None => quote! {},
+            };
+
             match &variant.fields {
                 Fields::Unnamed(fields) => {
                     let field_names = field_names_unnamed(fields);
                     let write_fields = write_unnamed_fields(fields, false);
                     quote! {
                         Self::#name( #(#field_names),* ) => {
+                            #write_discriminant
                             #(#write_fields)*
                             #write_pad_after
                             #write_align_after
@@ -145,6 +178,7 @@ fn write_data_calculate_size_enum(
                     let write_fields = write_named_fields(fields, false);
                     quote! {
                         Self::#name { #(#field_names),* } => {
+                            #write_discriminant
                             #(#write_fields)*
                             #write_pad_after
                             #write_align_after
@@ -196,7 +230,13 @@ fn write_data_calculate_size_enum(
         })
         .collect();
 
+    let add_discriminant = match &write_options.discriminant {
+        Some(ty) => quote! { size += std::mem::size_of::<#ty>() as u64; },
+        None => quote! {},
+    };
+
     let add_variants = quote! {
+        #add_discriminant
         size += match self {
             #(
                 #add_variants
@@ -214,6 +254,19 @@ fn write_data_calculate_size_enum(
     )
 }
 
+fn variant_discriminant(variant: &syn::Variant) -> syn::Result<&syn::Expr> {
+    match &variant.discriminant {
+        Some((_, value)) => Ok(value),
+        None => Err(syn::Error::new_spanned(
+            variant,
+            format!(
+                "Expected an explicit discriminant for variant {}",
+                variant.ident
+            ),
+        )),
+    }
+}
+
 // TODO: Reduce repetition between these two functions.
 fn write_data_calculate_size_unnamed(
     fields: &syn::FieldsUnnamed,
@@ -275,14 +328,25 @@ fn get_write_options(attrs: &[Attribute]) -> WriteOptions {
                 // This uses a different syntax than named values.
                 // ex: #[ssbhwrite(repr(u32)]
                 write_options.repr = Some(repr);
+            } else if let Some(discriminant) = get_discriminant(attr) {
+                // Write the explicit discriminant for each variant before the fields.
+                // ex: #[ssbhwrite(discriminant(u8))]
+                write_options.discriminant = Some(discriminant);
             } else if let Ok(syn::Meta::List(l)) = attr.parse_meta() {
                 for nested in l.nested {
                     // ex: #[ssbhwrite(pad_after = 16, align_after = 8)]
                     if let syn::NestedMeta::Meta(syn::Meta::NameValue(v)) = nested {
                         match v.path.get_ident().unwrap().to_string().as_str() {
-                            "pad_after" => write_options.pad_after = get_usize_arg(&v),
-                            "align_after" => write_options.align_after = get_usize_arg(&v),
-                            "alignment" => write_options.alignment = get_usize_arg(&v),
+                            "pad_after" => write_options.pad_after = Some(get_usize_arg(&v)),
+                            "align_after" => write_options.align_after = Some(get_usize_arg(&v)),
+                            "alignment" => {
+                                // Alignment is used as a divisor when calculating offsets.
+                                let alignment = get_usize_arg(&v);
+                                if alignment == 0 {
+                                    panic!("Alignment must be greater than 0");
+                                }
+                                write_options.alignment = Some(alignment);
+                            }
                             "magic" => write_options.magic = get_byte_string_arg(&v),
                             _ => panic!("Unrecognized attribute"),
                         }
@@ -352,7 +416,7 @@ fn generate_ssbh_write(
 
 fn write_pad_after(write_options: &WriteOptions) -> TokenStream2 {
     match write_options.pad_after {
-        Some(num_bytes) => quote! { writer.write_all(&[0u8; #num_bytes])?; },
+        Some(num_bytes) => quote! { ssbh_write::write_padding(writer, #num_bytes)?; },
         None => quote! {},
     }
 }
@@ -362,13 +426,10 @@ fn write_aligned_after(write_options: &WriteOptions) -> TokenStream2 {
         Some(num_bytes) => quote! {
             // Check for divide by 0.
             if #num_bytes > 0 {
-                let round_up = |value, n| ((value + n - 1) / n) * n;
                 // TODO: Is seeking from the end always correct?
                 let current_pos = writer.seek(std::io::SeekFrom::End(0))?;
-                let aligned_pos = round_up(current_pos, #num_bytes as u64);
-                for _ in 0..(aligned_pos - current_pos) {
-                    writer.write_all(&[0u8])?;
-                }
+                let aligned_pos = current_pos.div_ceil(#num_bytes as u64) * #num_bytes as u64;
+                ssbh_write::write_padding(writer, (aligned_pos - current_pos) as usize)?;
             }
 
         },